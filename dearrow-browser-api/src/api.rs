@@ -32,8 +32,16 @@ pub struct StatusResponse {
     pub warnings: usize,
     pub errors: usize,
     pub string_count: Option<usize>,
-    pub video_infos: usize,
-    pub uncut_segments: usize,
+    /// `None` while still being computed after a (re)load
+    pub video_infos: Option<usize>,
+    /// `None` while still being computed after a (re)load
+    pub uncut_segments: Option<usize>,
+    /// Submissions per UTC day over the last days with data, oldest first.
+    /// `None` while still being computed after a (re)load
+    pub daily_submissions: Option<Vec<DailySubmissions>>,
+    pub titles_missing_votes: usize,
+    pub thumbnails_missing_votes: usize,
+    pub thumbnails_missing_timestamp: usize,
     pub cached_channels: usize,
     pub fscached_channels: usize,
     pub server_version: RcStr,
@@ -41,8 +49,74 @@ pub struct StatusResponse {
     pub server_git_dirty: Option<bool>,
     pub server_build_timestamp: Option<i64>,
     pub server_startup_timestamp: i64,
+    /// API versions served under `/api/<version>/`, oldest first.
+    ///
+    /// Versioned prefixes only receive backwards-compatible changes, breaking changes land in a
+    /// new version. The unversioned `/api/` prefix is an alias of the latest version and may
+    /// break when a new version is added.
+    pub api_versions: Vec<RcStr>,
+    /// "New issue" page to link data issue reports to, accepts `title` and `body` query parameters
+    pub report_issue_url: Option<RcStr>,
+    /// Announcement from the instance operator, shown as a banner below the header
+    pub notice: Option<Notice>,
+    /// Public user IDs of contributors highlighted by the instance operator, unrelated to VIPs
+    pub trusted_users: Vec<RcStr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct DailySubmissions {
+    /// Start of the UTC day, in milliseconds
+    pub day: i64,
+    pub titles: usize,
+    pub thumbnails: usize,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Notice {
+    /// Plain text, line breaks are preserved
+    pub text: RcStr,
+    pub severity: NoticeSeverity,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum NoticeSeverity {
+    #[default]
+    Info,
+    Warning,
+    Critical,
+}
+
+/// Newest submission timestamp of each kind of object, `None` if there are none
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct FreshnessResponse {
+    pub last_updated: i64,
+    pub last_modified: i64,
+    pub titles: Option<i64>,
+    pub thumbnails: Option<i64>,
+    pub warnings: Option<i64>,
+}
+
+/// Readiness of the server, always returned with a 200 status code
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct HealthResponse {
+    /// `false` while the database could not be loaded yet, data endpoints return 503 until then
+    pub ready: bool,
+    pub updating_now: bool,
+}
+
+/// Build information of the server, a subset of [`StatusResponse`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct VersionResponse {
+    pub server_version: RcStr,
+    pub server_git_hash: Option<RcStr>,
+    pub server_git_dirty: Option<bool>,
+    pub server_build_timestamp: Option<i64>,
+    pub api_versions: Vec<RcStr>,
+}
+
+/// List endpoints can also return these as CSV, with columns in field order.
+/// Keep the order stable - new fields should only be appended at the end.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct ApiTitle {
     pub uuid: RcStr,
@@ -59,10 +133,22 @@ pub struct ApiTitle {
     pub removed: bool,
     pub votes_missing: bool,
     pub score: i8,
+    pub vote_status: VoteStatus,
     pub username: Option<RcStr>,
     pub vip: bool,
+    /// ISO 639-1 code or `unknown`, only set if language detection is enabled on the server
+    pub language: Option<RcStr>,
+    /// Amount of other titles with the same text merged into this one, only set by endpoints
+    /// deduplicating titles
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merged: Option<u32>,
+    /// Whether the title matches a known original title of the video after normalization,
+    /// `None` for original titles and videos with no known original
+    pub identical_to_original: Option<bool>,
 }
 
+/// List endpoints can also return these as CSV, with columns in field order.
+/// Keep the order stable - new fields should only be appended at the end.
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
 pub struct ApiThumbnail {
     pub uuid: RcStr,
@@ -79,6 +165,7 @@ pub struct ApiThumbnail {
     pub votes_missing: bool,
     pub timestamp_missing: bool,
     pub score: i8,
+    pub vote_status: VoteStatus,
     pub username: Option<RcStr>,
     pub vip: bool,
 }
@@ -94,12 +181,21 @@ pub struct User {
     pub active_warning_count: u64,
 }
 
+/// All titles and thumbnails of a single user, taken from the same database snapshot
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct UserSubmissions {
+    pub titles: Vec<ApiTitle>,
+    pub thumbnails: Vec<ApiThumbnail>,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
 pub struct Video {
     pub video_id: RcStr,
     pub random_thumbnail: f64,
     pub duration: Option<f64>,
     pub fraction_unmarked: f64,
+    /// If no outro is marked, the last 10% of the video is excluded from the random thumbnail
+    /// pick instead
     pub has_outro: bool,
 }
 
@@ -119,6 +215,27 @@ pub struct InnertubeChannel {
     pub total_videos: u64,
 }
 
+/// Overview of the `DeArrow` coverage of all videos of a channel
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ChannelSummary {
+    pub channel_name: RcStr,
+    pub total_videos: u64,
+    pub videos_with_titles: u64,
+    pub videos_with_thumbnails: u64,
+    pub title_submissions: u64,
+    pub thumbnail_submissions: u64,
+    /// Users with the most submissions on this channel, most submissions first
+    pub top_submitters: Vec<ChannelSubmitter>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ChannelSubmitter {
+    pub user_id: RcStr,
+    pub username: Option<RcStr>,
+    pub titles: u64,
+    pub thumbnails: u64,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Copy)]
 pub struct ChannelFetchProgress {
     pub videos: BrowseProgress,
@@ -134,6 +251,29 @@ pub struct BrowseProgress {
     pub videos_in_fscache: u64,
 }
 
+/// Describes whether a submission was hidden by its vote counts
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum VoteStatus {
+    Normal,
+    /// The submitter has voted for another submission, which sets the votes of this one to -1
+    ReplacedBySubmitter,
+    /// Upvotes minus downvotes dropped below -1
+    RemovedByDownvotes,
+}
+
+impl VoteStatus {
+    pub fn from_votes(votes: i8, downvotes: i8) -> VoteStatus {
+        if i16::from(votes) - i16::from(downvotes) < -1 {
+            VoteStatus::RemovedByDownvotes
+        } else if votes < 0 {
+            VoteStatus::ReplacedBySubmitter
+        } else {
+            VoteStatus::Normal
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 pub enum Extension {
     SponsorBlock,
@@ -151,3 +291,150 @@ pub struct ApiWarning {
     pub message: RcStr,
     pub active: bool,
 }
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum TitleCompetition {
+    /// This title is the one currently shown by the extension
+    Winning,
+    /// This title was removed, shadowhidden, downvoted or is missing vote data, so it isn't returned
+    /// by the branding endpoint and cannot be shown
+    Hidden,
+    /// The winning title is locked, while this one isn't
+    LockedOut { winner: ApiTitle },
+    /// This title needs `score_needed` more score to overtake the winning title,
+    /// or to become visible at all if there is no winning title
+    Behind { winner: Option<ApiTitle>, score_needed: i16 },
+}
+
+/// Original title of a video next to its community titles
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct OriginalTitleComparison {
+    /// Text of the newest title submitted with the `original` flag.
+    /// `None` if the original title of the video is unknown.
+    pub original_title: Option<RcStr>,
+    /// Title shown by the extension, which may be one of the original titles
+    pub winner: Option<ApiTitle>,
+    /// Every title of the video, newest first
+    pub titles: Vec<ApiTitle>,
+}
+
+/// Amount of matching objects, returned by the `/count` variants of list endpoints
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct CountResponse {
+    pub count: u64,
+}
+
+/// A video with submission counts, returned by the top videos endpoint
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TopVideo {
+    pub video_id: RcStr,
+    pub titles: usize,
+    pub thumbnails: usize,
+}
+
+/// Distribution of votes of titles and thumbnails, removed and shadowhidden entries are not counted
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct VoteStatsResponse {
+    /// Inclusive lower bound of each histogram bucket, lowest first. Each bucket ends right before
+    /// the next one starts, the last one is unbounded
+    pub buckets: Vec<i8>,
+    /// Minimum score of an unlocked entry to be counted in [`VoteHistograms::high_score_unlocked`]
+    pub high_score_threshold: i8,
+    pub titles: VoteHistograms,
+    pub thumbnails: VoteHistograms,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct VoteHistograms {
+    /// Amount of entries with their upvote count in each bucket
+    pub votes: Vec<usize>,
+    /// Amount of entries with their downvote count in each bucket
+    pub downvotes: Vec<usize>,
+    pub locked: usize,
+    pub high_score_unlocked: usize,
+}
+
+/// Resulting visibility of a title in the extension
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleVisibility {
+    /// The title is shown in place of the original title
+    Shown,
+    /// The title is returned by the branding endpoint, but can only be seen in the submission menu
+    SubmissionMenuOnly,
+    /// The title is not returned by the branding endpoint due to its votes
+    Downvoted,
+    /// The title was removed or is missing vote data
+    Removed,
+    ShadowHidden,
+}
+
+/// Reason for a title not being shown by the extension
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleHiddenReason {
+    /// Removed by a VIP
+    Removed,
+    ShadowHidden,
+    /// The title has no entry in the votes file
+    MissingVotes,
+    /// The title has less than 0 upvotes
+    NegativeVotes,
+    /// Upvotes minus downvotes are -2 or less
+    TooManyDownvotes,
+    /// The score is below 0 and the title isn't locked
+    NegativeScore,
+    /// Another title of the same video is locked, while this one isn't
+    LockedCompetitor,
+    /// Another title of the same video is shown, with the same or a higher score
+    Outscored,
+}
+
+/// Explanation of whether and why a title is shown by the extension
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct TitleStatus {
+    pub title: ApiTitle,
+    pub visibility: TitleVisibility,
+    /// Every reason preventing the title from being shown, empty if it is shown
+    pub reasons: Vec<TitleHiddenReason>,
+    /// Title shown by the extension for this video, unless it's this one or there is none
+    pub winner: Option<ApiTitle>,
+}
+
+/// A title formatted with each of the title casing rules of the extension
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TitleCasingPreview {
+    pub capitalize_words: RcStr,
+    pub title_case: RcStr,
+    pub sentence_case: RcStr,
+    pub lower_case: RcStr,
+    pub first_letter_uppercase: RcStr,
+}
+
+/// A page of the errors encountered while loading the database
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ErrorsPage<E> {
+    /// Total amount of errors, across all pages
+    pub total: usize,
+    pub offset: usize,
+    pub errors: Vec<E>,
+}
+
+/// Whether any titles or thumbnails were submitted for a video
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VideoDataPresence {
+    pub has_title: bool,
+    pub has_thumbnail: bool,
+}
+
+/// Debugging information about the `video_infos` bucket a video falls into
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct HashPrefixBucket {
+    pub video_id: RcStr,
+    pub hash_prefix: u16,
+    /// Amount of video infos sharing this bucket
+    pub bucket_size: usize,
+    /// Whether the video info for this video was found in the bucket
+    pub video_info_found: bool,
+}
@@ -51,8 +51,12 @@ pub mod sync {
                     removed: value.flags.contains(TitleFlags::Removed),
                     votes_missing: value.flags.contains(TitleFlags::MissingVotes),
                     score: value.votes - value.downvotes - i8::from(unverified),
+                    vote_status: VoteStatus::from_votes(value.votes, value.downvotes),
                     username: None,
                     vip: false,
+                    language: value.language.map(|l| l.code().into()),
+                    merged: None,
+                    identical_to_original: value.identical_to_original,
                 }
             }
         }
@@ -83,6 +87,7 @@ pub mod sync {
                     votes_missing: value.flags.contains(ThumbnailFlags::MissingVotes),
                     timestamp_missing: value.flags.contains(ThumbnailFlags::MissingTimestamp),
                     score: value.votes - value.downvotes,
+                    vote_status: VoteStatus::from_votes(value.votes, value.downvotes),
                     username: None,
                     vip: false,
                 }
@@ -97,6 +102,35 @@ pub mod sync {
             }
         }
 
+        impl From<dearrow_parser::visibility::TitleVisibility> for TitleVisibility {
+            fn from(value: dearrow_parser::visibility::TitleVisibility) -> Self {
+                use dearrow_parser::visibility::TitleVisibility as V;
+                match value {
+                    V::Shown => TitleVisibility::Shown,
+                    V::SubmissionMenuOnly => TitleVisibility::SubmissionMenuOnly,
+                    V::Downvoted => TitleVisibility::Downvoted,
+                    V::Removed => TitleVisibility::Removed,
+                    V::ShadowHidden => TitleVisibility::ShadowHidden,
+                }
+            }
+        }
+
+        impl From<dearrow_parser::visibility::HiddenReason> for TitleHiddenReason {
+            fn from(value: dearrow_parser::visibility::HiddenReason) -> Self {
+                use dearrow_parser::visibility::HiddenReason as R;
+                match value {
+                    R::Removed => TitleHiddenReason::Removed,
+                    R::ShadowHidden => TitleHiddenReason::ShadowHidden,
+                    R::MissingVotes => TitleHiddenReason::MissingVotes,
+                    R::NegativeVotes => TitleHiddenReason::NegativeVotes,
+                    R::TooManyDownvotes => TitleHiddenReason::TooManyDownvotes,
+                    R::NegativeScore => TitleHiddenReason::NegativeScore,
+                    R::LockedCompetitor => TitleHiddenReason::LockedCompetitor,
+                    R::Outscored => TitleHiddenReason::Outscored,
+                }
+            }
+        }
+
         impl IntoWithDatabase<ApiWarning> for &dearrow_parser::Warning {
             fn into_with_db(self, db: &dearrow_parser::DearrowDB) -> ApiWarning {
                 let warned_username = db
@@ -0,0 +1,125 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+#![cfg(feature = "dearrow-parser")]
+
+use std::sync::Arc;
+
+use dearrow_browser_api::sync::{ApiThumbnail, ApiTitle};
+use dearrow_parser::{Thumbnail, ThumbnailFlags, Title, TitleFlags};
+use enumflags2::BitFlags;
+
+fn title(votes: i8, downvotes: i8, flags: BitFlags<TitleFlags>) -> Title {
+    Title {
+        uuid: Arc::from("00000000-0000-0000-0000-000000000000"),
+        video_id: Arc::from("dQw4w9WgXcQ"),
+        title: Arc::from("A title"),
+        user_id: Arc::from("user"),
+        time_submitted: 1_700_000_000_000,
+        votes,
+        downvotes,
+        flags,
+        hash_prefix: 0,
+        language: None,
+        identical_to_original: None,
+    }
+}
+
+fn thumbnail(votes: i8, downvotes: i8, flags: BitFlags<ThumbnailFlags>) -> Thumbnail {
+    Thumbnail {
+        uuid: Arc::from("00000000-0000-0000-0000-000000000000"),
+        video_id: Arc::from("dQw4w9WgXcQ"),
+        user_id: Arc::from("user"),
+        time_submitted: 1_700_000_000_000,
+        timestamp: if flags.contains(ThumbnailFlags::MissingTimestamp) { None } else { Some(12.5) },
+        votes,
+        downvotes,
+        flags,
+        hash_prefix: 0,
+    }
+}
+
+#[test]
+fn title_flags_map_to_booleans() {
+    for bits in 0..=BitFlags::<TitleFlags>::all().bits() {
+        let flags = BitFlags::<TitleFlags>::from_bits_truncate(bits);
+        let api: ApiTitle = (&title(3, 1, flags)).into();
+        assert_eq!(api.original, flags.contains(TitleFlags::Original), "{flags:?}");
+        assert_eq!(api.locked, flags.contains(TitleFlags::Locked), "{flags:?}");
+        assert_eq!(api.shadow_hidden, flags.contains(TitleFlags::ShadowHidden), "{flags:?}");
+        assert_eq!(api.unverified, flags.contains(TitleFlags::Unverified), "{flags:?}");
+        assert_eq!(api.removed, flags.contains(TitleFlags::Removed), "{flags:?}");
+        assert_eq!(api.votes_missing, flags.contains(TitleFlags::MissingVotes), "{flags:?}");
+        assert_eq!((api.votes, api.downvotes), (3, 1), "{flags:?}");
+    }
+}
+
+#[test]
+fn title_score_has_unverified_penalty() {
+    for bits in 0..=BitFlags::<TitleFlags>::all().bits() {
+        let flags = BitFlags::<TitleFlags>::from_bits_truncate(bits);
+        let expected = if flags.contains(TitleFlags::Unverified) { 1 } else { 2 };
+        let api: ApiTitle = (&title(3, 1, flags)).into();
+        assert_eq!(api.score, expected, "{flags:?}");
+    }
+}
+
+#[test]
+fn title_with_missing_votes_scores_zero() {
+    // the parser zeroes the votes of titles without a titleVotes entry
+    let api: ApiTitle = (&title(0, 0, TitleFlags::MissingVotes.into())).into();
+    assert!(api.votes_missing);
+    assert!(!api.unverified);
+    assert_eq!(api.score, 0);
+}
+
+#[test]
+fn thumbnail_flags_map_to_booleans() {
+    for bits in 0..=BitFlags::<ThumbnailFlags>::all().bits() {
+        let flags = BitFlags::<ThumbnailFlags>::from_bits_truncate(bits);
+        let api: ApiThumbnail = (&thumbnail(3, 1, flags)).into();
+        assert_eq!(api.original, flags.contains(ThumbnailFlags::Original), "{flags:?}");
+        assert_eq!(api.locked, flags.contains(ThumbnailFlags::Locked), "{flags:?}");
+        assert_eq!(api.shadow_hidden, flags.contains(ThumbnailFlags::ShadowHidden), "{flags:?}");
+        assert_eq!(api.removed, flags.contains(ThumbnailFlags::Removed), "{flags:?}");
+        assert_eq!(api.votes_missing, flags.contains(ThumbnailFlags::MissingVotes), "{flags:?}");
+        assert_eq!(api.timestamp_missing, flags.contains(ThumbnailFlags::MissingTimestamp), "{flags:?}");
+        // thumbnails have no verification, the score is never penalized
+        assert_eq!(api.score, 2, "{flags:?}");
+    }
+}
+
+#[test]
+fn thumbnail_with_missing_votes_scores_zero() {
+    let api: ApiThumbnail = (&thumbnail(0, 0, ThumbnailFlags::MissingVotes.into())).into();
+    assert!(api.votes_missing);
+    assert_eq!(api.score, 0);
+}
+
+#[test]
+fn merged_count_only_serialized_when_set() {
+    let mut api: ApiTitle = (&title(3, 1, BitFlags::empty())).into();
+    assert_eq!(api.merged, None);
+    let json = serde_json::to_value(&api).unwrap();
+    assert!(json.get("merged").is_none(), "{json}");
+
+    api.merged = Some(2);
+    let json = serde_json::to_value(&api).unwrap();
+    assert_eq!(json["merged"], 2);
+    let parsed: ApiTitle = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.merged, Some(2));
+}
@@ -0,0 +1,46 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use dearrow_browser_api::sync::VoteStatus;
+
+#[test]
+fn untouched_submission_is_normal() {
+    assert_eq!(VoteStatus::from_votes(0, 0), VoteStatus::Normal);
+    assert_eq!(VoteStatus::from_votes(5, 3), VoteStatus::Normal);
+}
+
+#[test]
+fn net_score_of_minus_one_is_not_removed() {
+    // votes = -1 is set when the submitter picks another submission
+    assert_eq!(VoteStatus::from_votes(-1, 0), VoteStatus::ReplacedBySubmitter);
+    // a single downvote leaves the net score at exactly -1, which is still shown
+    assert_eq!(VoteStatus::from_votes(0, 1), VoteStatus::Normal);
+}
+
+#[test]
+fn net_score_below_minus_one_is_removed() {
+    assert_eq!(VoteStatus::from_votes(0, 2), VoteStatus::RemovedByDownvotes);
+    // downvotes take precedence over the submitter's replacement
+    assert_eq!(VoteStatus::from_votes(-1, 1), VoteStatus::RemovedByDownvotes);
+}
+
+#[test]
+fn extreme_values_do_not_overflow() {
+    assert_eq!(VoteStatus::from_votes(i8::MIN, i8::MAX), VoteStatus::RemovedByDownvotes);
+    assert_eq!(VoteStatus::from_votes(i8::MAX, i8::MIN), VoteStatus::Normal);
+}
@@ -0,0 +1,55 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*  
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct BarChartProps {
+    /// One label per bar, same length as `values`
+    pub labels: Rc<[AttrValue]>,
+    pub values: Rc<[usize]>,
+    pub title: AttrValue,
+}
+
+/// Horizontal bar chart with a labelled bar for each value, scaled to the largest one
+#[function_component]
+pub fn BarChart(props: &BarChartProps) -> Html {
+    #[allow(clippy::cast_precision_loss)]
+    let max = props.values.iter().copied().max().unwrap_or(0).max(1) as f64;
+
+    html! {
+        <figure class="bar-chart">
+            <figcaption>{props.title.clone()}</figcaption>
+            <table>
+                { for props.labels.iter().zip(props.values.iter()).map(|(label, value)| {
+                    #[allow(clippy::cast_precision_loss)]
+                    let width = *value as f64 / max * 100.;
+                    html! {
+                        <tr>
+                            <th>{label.clone()}</th>
+                            <td class="bar-cell"><div class="bar" style={format!("width: {width:.1}%")} /></td>
+                            <td>{value}</td>
+                        </tr>
+                    }
+                }) }
+            </table>
+        </figure>
+    }
+}
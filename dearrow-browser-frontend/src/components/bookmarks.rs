@@ -0,0 +1,129 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*  
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::contexts::SettingsContext;
+use crate::settings::{Bookmark, BookmarkKind};
+use crate::MainRoute;
+
+#[derive(Properties, PartialEq)]
+pub struct BookmarkButtonProps {
+    pub kind: BookmarkKind,
+    pub id: AttrValue,
+}
+
+/// A star toggling whether the given video or user is bookmarked
+#[function_component]
+pub fn BookmarkButton(props: &BookmarkButtonProps) -> Html {
+    let settings_context: SettingsContext = use_context().expect("SettingsContext should be available");
+    let bookmarked = settings_context.settings().is_bookmarked(props.kind, &props.id);
+
+    let toggle = use_callback(
+        (settings_context, props.kind, props.id.clone()),
+        |_: MouseEvent, (settings_context, kind, id)| {
+            let mut settings = settings_context.settings().clone();
+            settings.toggle_bookmark(*kind, id);
+            settings_context.update(settings);
+        },
+    );
+
+    html! {
+        <span class="clickable bookmark-button" onclick={toggle} title={if bookmarked { "Remove from bookmarks" } else { "Add to bookmarks" }}>
+            {if bookmarked { "★" } else { "☆" }}
+        </span>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct BookmarkRowProps {
+    bookmark: Bookmark,
+}
+
+#[function_component]
+fn BookmarkRow(props: &BookmarkRowProps) -> Html {
+    let settings_context: SettingsContext = use_context().expect("SettingsContext should be available");
+    let bookmark = &props.bookmark;
+
+    let save_label = use_callback(
+        (settings_context.clone(), bookmark.kind, bookmark.id.clone()),
+        |e: Event, (settings_context, kind, id)| {
+            let target: HtmlInputElement = e.target_unchecked_into();
+            let label = target.value();
+            let label = label.trim();
+            let mut settings = settings_context.settings().clone();
+            for bookmark in settings.bookmarks.iter_mut().filter(|b| b.kind == *kind && b.id == *id) {
+                bookmark.label = (!label.is_empty()).then(|| label.into());
+            }
+            settings_context.update(settings);
+        },
+    );
+    let remove = use_callback(
+        (settings_context, bookmark.kind, bookmark.id.clone()),
+        |_: MouseEvent, (settings_context, kind, id)| {
+            let mut settings = settings_context.settings().clone();
+            settings.remove_bookmark(*kind, id);
+            settings_context.update(settings);
+        },
+    );
+
+    let id = AttrValue::Rc(bookmark.id.clone());
+    let (kind_name, route) = match bookmark.kind {
+        BookmarkKind::Video => ("Video", MainRoute::Video { id: id.clone() }),
+        BookmarkKind::User => ("User", MainRoute::User { id: id.clone() }),
+    };
+
+    html! {
+        <tr>
+            <td>{kind_name}</td>
+            <td><Link<MainRoute> to={route}>{id}</Link<MainRoute>></td>
+            <td>
+                <input
+                    type="text" placeholder="Label"
+                    onchange={save_label}
+                    ~value={bookmark.label.as_deref().unwrap_or_default().to_owned()}
+                />
+            </td>
+            <td><span class="clickable" title="Remove from bookmarks" onclick={remove}>{"❌"}</span></td>
+        </tr>
+    }
+}
+
+/// A list of all bookmarked videos and users, rendered only if there are any
+#[function_component]
+pub fn BookmarksList() -> Html {
+    let settings_context: SettingsContext = use_context().expect("SettingsContext should be available");
+    let bookmarks = &settings_context.settings().bookmarks;
+
+    if bookmarks.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <details class="bookmarks" open=true>
+            <summary>{"Bookmarks"}</summary>
+            <table>
+                {for bookmarks.iter().map(|bookmark| html! {
+                    <BookmarkRow key={format!("{:?}/{}", bookmark.kind, bookmark.id)} bookmark={bookmark.clone()} />
+                })}
+            </table>
+        </details>
+    }
+}
@@ -18,6 +18,7 @@
 use std::rc::Rc;
 
 use chrono::{DateTime, Datelike, Local};
+use dearrow_browser_api::unsync::NoticeSeverity;
 use yew::prelude::*;
 use yew::virtual_dom::VList;
 use yew_router::hooks::use_navigator;
@@ -27,7 +28,8 @@ use crate::components::modals::{async_tasks::AsyncTasksModal, settings::Settings
 use crate::components::icon::*;
 use crate::{constants, contexts::*};
 use crate::pages::MainRoute;
-use crate::utils::render_datetime_with_delta;
+use crate::hooks::use_display_timezone;
+use crate::utils::{render_datetime_with_delta, sponsorblock_hash};
 
 #[function_component]
 pub fn Header() -> Html {
@@ -35,6 +37,7 @@ pub fn Header() -> Html {
     let modal_controls: ModalRendererControls = use_context().expect("Header should be placed inside a ModalRenderer");
     let user_context: UserContext = use_context().expect("Header should be placed inside a SettingsProvider");
     let async_tasks_view: AsyncTaskList = use_context().expect("Header should be placed inside an AsyncTaskList");
+    let now_playing: NowPlayingContext = use_context().expect("Header should be placed inside a NowPlayingProvider");
     let open_settings_modal = use_callback(modal_controls.clone(), |_, modal_controls| {
         modal_controls.emit(ModalMessage::Open(html! {<SettingsModal />}));
     });
@@ -79,9 +82,14 @@ pub fn Header() -> Html {
 
     html! {
         <div id="header">
-            <Link<MainRoute> to={MainRoute::Home}><img src="/icon/logo.svg" /></Link<MainRoute>>
+            <Link<MainRoute> to={MainRoute::Home}><img src="icon/logo.svg" /></Link<MainRoute>>
             <div>
                 <h1 class="undecorated-link"><Link<MainRoute> to={MainRoute::Home}>{"DeArrow Browser"}</Link<MainRoute>></h1>
+                if let Some(video_id) = now_playing {
+                    <span id="now-playing-badge" class="header-badge undecorated-link" title="Open the page of the video currently playing on YouTube">
+                        <Link<MainRoute> to={MainRoute::Video { id: video_id.clone() }}>{"▶ "}{video_id}</Link<MainRoute>>
+                    </span>
+                }
                 if !async_tasks_view.tasks.is_empty() {
                     <div id="async-tasks-badge" class="clickable header-badge" onclick={open_async_tasks_modal}>
                         {(*task_badge).clone()}
@@ -114,9 +122,61 @@ pub fn Header() -> Html {
     }
 }
 
+/// Shows the notice configured on the server until the user dismisses it.
+/// Dismissals are remembered by the hash of the notice text, so a changed notice is shown again.
+/// Offers reloading the data shown on the page after the server has loaded newer data.
+/// Not shown if auto refreshes are enabled in settings, as the data is reloaded immediately.
+#[function_component]
+pub fn NewerDataBanner() -> Html {
+    let newer_data: NewerDataContext = use_context().expect("NewerDataBanner should be placed inside a SettingsProvider");
+    let Some(refresh) = newer_data else {
+        return html! {};
+    };
+    let onclick = refresh.reform(|_: MouseEvent| ());
+    html! {
+        <div id="newer-data-banner">
+            <span>{"New data is available. "}<span class="clickable" {onclick}>{"Refresh"}</span></span>
+        </div>
+    }
+}
+
+#[function_component]
+pub fn NoticeBanner() -> Html {
+    let status: StatusContext = use_context().expect("StatusResponse should be defined");
+    let settings_context: SettingsContext = use_context().expect("NoticeBanner should be placed inside a SettingsProvider");
+    let notice = status.as_ref().and_then(|s| s.notice.clone());
+    let notice_hash: Rc<Option<Rc<str>>> = use_memo(notice.as_ref().map(|n| n.text.clone()), |text| {
+        text.as_ref().map(|text| sponsorblock_hash(text.as_bytes(), 1).into())
+    });
+    let dismiss = use_callback((settings_context.clone(), notice_hash.clone()), |_: MouseEvent, (settings_context, notice_hash)| {
+        let mut settings = settings_context.settings().clone();
+        settings.dismissed_notice.clone_from(notice_hash);
+        settings_context.update(settings);
+    });
+
+    let Some(notice) = notice else {
+        return html! {};
+    };
+    if *notice_hash == settings_context.settings().dismissed_notice {
+        return html! {};
+    }
+    let severity_class = match notice.severity {
+        NoticeSeverity::Info => "info",
+        NoticeSeverity::Warning => "warning",
+        NoticeSeverity::Critical => "critical",
+    };
+    html! {
+        <div id="notice-banner" class={severity_class}>
+            <span>{notice.text}</span>
+            <Icon r#type={IconType::Close} tooltip={"Dismiss this notice"} onclick={dismiss} />
+        </div>
+    }
+}
+
 #[function_component]
 pub fn Footer() -> Html {
     let status: StatusContext = use_context().expect("StatusResponse should be defined");
+    let tz = use_display_timezone();
     let _ = use_context::<UpdateClock>();
     let modal_controls: ModalRendererControls = use_context().expect("Footer should be placed inside a ModalRenderer");
     let open_version_modal = use_callback(modal_controls, |_, modal_controls| {
@@ -134,11 +194,11 @@ pub fn Footer() -> Html {
 
     let last_updated = match status.as_ref().and_then(|status| DateTime::from_timestamp_millis(status.last_updated)) {
         None => AttrValue::from("..."),
-        Some(time) => AttrValue::from(render_datetime_with_delta(time)),
+        Some(time) => AttrValue::from(render_datetime_with_delta(time, tz)),
     };
     let last_modified = match status.as_ref().and_then(|status| DateTime::from_timestamp_millis(status.last_modified)) {
         None => AttrValue::from("..."),
-        Some(time) => AttrValue::from(render_datetime_with_delta(time)),
+        Some(time) => AttrValue::from(render_datetime_with_delta(time, tz)),
     };
 
     html! {
@@ -33,6 +33,7 @@ pub enum IconType {
     Unverified,
     Upvote,
     VIP,
+    Trusted,
     VotesMissing,
     UpvoteAndLock,
     DownvoteAndRemove,
@@ -72,6 +73,7 @@ pub fn Icon(props: &IconProps) -> Html {
         IconType::Unverified        => classes!("icon", "icon-unverified"),
         IconType::Upvote            => classes!("icon", "icon-upvote"),
         IconType::VIP               => classes!("icon", "icon-vip"),
+        IconType::Trusted           => classes!("icon", "icon-trusted"),
         IconType::VotesMissing      => classes!("icon", "icon-votes-missing"),
         IconType::UpvoteAndLock     => classes!("icon", "icon-upvote-and-lock"),
         IconType::DownvoteAndRemove => classes!("icon", "icon-downvote-and-remove"),
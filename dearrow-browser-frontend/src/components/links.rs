@@ -15,9 +15,13 @@
 *  You should have received a copy of the GNU Affero General Public License
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
+use std::rc::Rc;
+
+use reqwest::Url;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::contexts::StatusContext;
 use crate::MainRoute;
 use super::icon::*;
 
@@ -44,3 +48,37 @@ pub fn uuid_link(uuid: AttrValue) -> Html {
         </Link<MainRoute>>
     }
 }
+
+#[derive(Properties, PartialEq)]
+pub struct ReportIssueLinkProps {
+    /// What is being reported, like `video dQw4w9WgXcQ`
+    pub subject: AttrValue,
+    /// API URL returning the reported data
+    pub api_url: Rc<Url>,
+}
+
+/// Links to the issue tracker configured on the server, prefilling the issue with the reported
+/// object and where its data came from. Renders nothing if no issue tracker is configured.
+#[function_component]
+pub fn ReportIssueLink(props: &ReportIssueLinkProps) -> Html {
+    let status: StatusContext = use_context().expect("StatusContext should be defined");
+    let report_url = status
+        .as_ref()
+        .and_then(|s| s.report_issue_url.as_deref())
+        .and_then(|url| Url::parse(url).ok());
+    let Some(mut report_url) = report_url else {
+        return html! {};
+    };
+    let page_url = web_sys::window()
+        .and_then(|w| w.location().href().ok())
+        .unwrap_or_default();
+    report_url.query_pairs_mut()
+        .append_pair("title", &format!("Data issue: {}", props.subject))
+        .append_pair("body", &format!(
+            "Subject: {}\nPage: {page_url}\nAPI URL: {}\n\nDescribe what is wrong with this data:\n",
+            props.subject, props.api_url,
+        ));
+    html! {
+        <a href={AttrValue::from(report_url.to_string())} target="_blank" rel="noopener">{"Report a data issue"}</a>
+    }
+}
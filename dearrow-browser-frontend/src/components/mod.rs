@@ -17,10 +17,13 @@
 */
 
 pub mod async_task_manager;
+pub mod bar_chart;
+pub mod bookmarks;
 pub mod header_footer;
 pub mod icon;
 pub mod links;
 pub mod modals;
 pub mod searchbar;
+pub mod sparkline;
 pub mod tables;
 pub mod youtube;
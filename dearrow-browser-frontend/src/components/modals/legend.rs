@@ -0,0 +1,55 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use yew::prelude::*;
+
+use crate::components::icon::*;
+
+/// Icons shown in the detail tables, with a human-readable explanation of each
+const LEGEND: &[(IconType, &str)] = &[
+    (IconType::Upvote,           "Upvotes"),
+    (IconType::Downvote,         "Downvotes; in the flags column: removed by downvotes or insufficient score"),
+    (IconType::Locked,           "Locked by a VIP"),
+    (IconType::Original,         "Original title or thumbnail of the video"),
+    (IconType::Replaced,         "Replaced by the submitter"),
+    (IconType::Removed,          "Removed by a VIP"),
+    (IconType::ShadowHidden,     "Submitted by a shadowbanned user"),
+    (IconType::PartiallyHidden,  "Only visible in the submission menu (score below 0)"),
+    (IconType::Unverified,       "Submitted by an unverified user (-1 score)"),
+    (IconType::VIP,              "Submitted by a VIP"),
+    (IconType::Trusted,          "Submitted by a contributor trusted by this instance"),
+    (IconType::VotesMissing,     "Broken entry: missing its votes row"),
+    (IconType::TimestampMissing, "Broken entry: custom thumbnail is missing its timestamp"),
+];
+
+#[function_component]
+pub fn IconLegendModal() -> Html {
+    html! {
+        <div id="legend-modal">
+            <h2>{"Icon legend"}</h2>
+            <table>
+                {for LEGEND.iter().map(|&(r#type, description)| html! {
+                    <tr>
+                        <td><Icon {r#type} /></td>
+                        <td>{description}</td>
+                    </tr>
+                })}
+            </table>
+        </div>
+    }
+}
@@ -15,9 +15,14 @@
 *  You should have received a copy of the GNU Affero General Public License
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
+use wasm_bindgen::closure::Closure;
+use web_sys::{window, HtmlElement, KeyboardEvent};
 use yew::prelude::*;
 
+use crate::thumbnails::utils::EventListener;
+
 pub mod async_tasks;
+pub mod legend;
 pub mod settings;
 pub mod status;
 pub mod thumbnail;
@@ -43,7 +48,7 @@ impl Reducible for ModalState {
             ModalMessage::Open(h) => modals.push(h),
             ModalMessage::CloseTop => drop(modals.pop()),
             ModalMessage::CloseAll => modals.clear(),
-        };
+        }
         Self { modals }.into()
     }
 }
@@ -79,6 +84,23 @@ struct ModalContainersProps {
 
 #[function_component]
 fn ModalContainers(props: &ModalContainersProps) -> Html {
+    // Escape closes the top modal, only listen while any modal is open
+    let any_open = !props.state.modals.is_empty();
+    {
+        let state = props.state.clone();
+        use_effect_with(any_open, move |any_open| {
+            let listener = any_open.then(window).flatten().and_then(|window| {
+                EventListener::new(&window, "keydown", Closure::<dyn Fn(KeyboardEvent)>::new(move |event: KeyboardEvent| {
+                    if event.key() == "Escape" && !event.default_prevented() {
+                        event.prevent_default();
+                        state.dispatch(ModalMessage::CloseTop);
+                    }
+                })).ok()
+            });
+            move || drop(listener)
+        });
+    }
+
     html! {
         <>
             {for (0..props.state.modals.len()).map(|index| html! { <ModalContainer state={props.state.clone()} {index} /> })}
@@ -98,11 +120,21 @@ fn ModalContainer(props: &ModalContainerProps) -> Html {
         let state = props.state.clone();
         use_callback((), move |_, ()| state.dispatch(ModalMessage::CloseTop))
     };
+    // Move focus into the modal when it's opened
+    let content_ref = use_node_ref();
+    {
+        let content_ref = content_ref.clone();
+        use_effect_with((), move |()| {
+            if let Some(content) = content_ref.cast::<HtmlElement>() {
+                let _ = content.focus();
+            }
+        });
+    }
 
     html! {
         <div class="modal-container" style={format!("z-index: {};", props.index+1)}>
             <div class="modal-background" onclick={close_top.clone()} />
-            <div class="modal-content">
+            <div class="modal-content" ref={content_ref} tabindex="-1" role="dialog" aria-modal="true">
                 {props.state.modals[props.index].clone()}
             </div>
         </div>
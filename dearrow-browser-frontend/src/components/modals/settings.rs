@@ -22,13 +22,26 @@ use gloo_console::error;
 use reqwest::Url;
 use strum::VariantNames;
 use wasm_bindgen::JsCast;
-use web_sys::{ClipboardEvent, HtmlInputElement, HtmlSelectElement};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{ClipboardEvent, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
 use yew::prelude::*;
 
-use crate::{contexts::SettingsContext, settings::TableLayout};
+use crate::{contexts::SettingsContext, settings::{TableLayout, TimezoneMode}};
 
 const DISABLE_SW_TITLE: &str = "This is meant for debugging only - this disables sharing the thumbnail cache between all open tabs and makes the current tab handle all thumbnail fetching on it's own. Changes require a refresh to apply";
+const VOTE_BREAKDOWN_TITLE: &str = "If enabled, the upvote & downvote counts will always be shown below the score in expanded tables, instead of only on hover";
+const TEXT_FLAG_LABELS_TITLE: &str = "If enabled, flags in tables (such as locked, removed or VIP) are shown as short text labels instead of icons. Hover over them for details in both modes";
+const SIZED_THUMBNAILS_TITLE: &str = "If enabled, thumbnails are requested from the thumbnail generator at a height matching where they are shown (table layout or the thumbnail modal), instead of letting the generator pick the size";
+const HIDE_ORIGINAL_TITLES_TITLE: &str = "If enabled, titles marked as the original title will be hidden from the tables on user, channel and home pages, leaving only real community submissions";
+const COMPARE_ORIGINAL_TITLE_TITLE: &str = "If enabled, the original title of the video is shown in a panel above the titles table on video pages, staying in view while scrolling through the submissions";
+const GROUP_HOMEPAGE_TITLE: &str = "If enabled, the home page groups recent submissions by video, showing each video once with its submission count and best title";
 const AUTOSEARCH_TITLE: &str = "If enabled, pasting valid query data or URLs into search fields will immediately trigger the search";
+const LAZY_EMBED_TITLE: &str = "If enabled, the YouTube embed on video pages will only be loaded after clicking on the placeholder thumbnail";
+const MIN_THUMBNAIL_SCORE_TITLE: &str = "Thumbnails with a score below this value will not be rendered in tables, click on the timestamp to view them instead. Leave empty to render all thumbnails";
+const TIMEZONE_OFFSET_TITLE: &str = "Offset from UTC in minutes, positive values are east of UTC (e.g. 120 for UTC+02:00)";
+const API_BASE_URL_TITLE: &str = "Base URL of the DeArrow Browser server to load data from, leave empty to use the server this page was loaded from. The server must allow cross-origin requests from this page. Changes require a refresh to apply";
+const AUTO_REFRESH_TITLE: &str = "If enabled, tables are reloaded as soon as the server loads new data. Otherwise, a banner offers reloading them, so the page doesn't change while you're reading it";
+const NOW_PLAYING_TITLE: &str = "If enabled, a companion userscript or extension on YouTube can tell DeArrow Browser which video is currently playing, showing a button to jump to its page";
 
 /// Generator macro for a revert callback (Esc key pressed)
 ///
@@ -37,6 +50,8 @@ macro_rules! esc_callback {
     ($name:ident, $verify_func:expr) => {
         move |e: KeyboardEvent, settings_context| {
             if e.key() == "Escape" {
+                // only revert the input, don't close the modal
+                e.prevent_default();
                 let settings = settings_context.settings();
                 let target: HtmlInputElement = e.target_unchecked_into();
                 target.set_value(&settings.$name.to_string());
@@ -193,6 +208,33 @@ verify_fn!(baseurl_verify: target -> Rc<str> => {
         },
     }
 });
+verify_fn!(opt_baseurl_verify: target -> Option<Rc<str>> => {
+    let value = target.value();
+    if value.is_empty() {
+        Ok(None)
+    } else {
+        match Url::from_str(&value) {
+            Err(e) => Err(BaseUrlVerifyError::UrlParseError(e)),
+            Ok(url) => {
+                if url.cannot_be_a_base() {
+                    Err(BaseUrlVerifyError::CannotBeABase)
+                } else if url.scheme() != "https" {
+                    Err(BaseUrlVerifyError::InvalidScheme)
+                } else {
+                    Ok(Some(url.to_string().into()))
+                }
+            },
+        }
+    }
+});
+verify_fn!(opt_score_verify: target -> Option<i8> => {
+    let value = target.value();
+    if value.is_empty() {
+        Ok(None)
+    } else {
+        value.parse().map(Some)
+    }
+});
 verify_fn!(checkbox_verify: target -> bool => {
     Result::<bool, Infallible>::Ok(target.checked())
 });
@@ -236,13 +278,23 @@ pub fn SettingsModal() -> Html {
 
     let title_table_layout_ref = use_node_ref();
     let thumbnail_table_layout_ref = use_node_ref();
+    let timezone_ref = use_node_ref();
 
     let nonzerousize_oninput = use_callback((), move |e: InputEvent, ()| {
         fromstr_verify::<NonZeroUsize>(&e.target_unchecked_into());
     });
+    let i32_oninput = use_callback((), move |e: InputEvent, ()| {
+        fromstr_verify::<i32>(&e.target_unchecked_into());
+    });
+    let opt_score_oninput = use_callback((), move |e: InputEvent, ()| {
+        opt_score_verify(&e.target_unchecked_into());
+    });
     let baseurl_oninput = use_callback((), move |e: InputEvent, ()| {
         baseurl_verify(&e.target_unchecked_into());
     });
+    let opt_baseurl_oninput = use_callback((), move |e: InputEvent, ()| {
+        opt_baseurl_verify(&e.target_unchecked_into());
+    });
     let private_user_id_oninput = use_callback((), move |e: InputEvent, ()| {
         priv_userid_verify(&e.target_unchecked_into());
     });
@@ -259,44 +311,87 @@ pub fn SettingsModal() -> Html {
     });
 
     let entries_per_page_revert           = use_callback(settings_context.clone(), esc_callback!(entries_per_page, fromstr_verify::<NonZeroUsize>));
+    let timezone_offset_minutes_revert    = use_callback(settings_context.clone(), esc_callback!(timezone_offset_minutes, fromstr_verify::<i32>));
     let thumbgen_api_base_url_revert      = use_callback(settings_context.clone(), esc_callback!(thumbgen_api_base_url, baseurl_verify));
+    let min_thumbnail_score_revert        = use_callback(settings_context.clone(), esc_callback!(min_thumbnail_score, opt_score_verify));
     let private_user_id_revert            = use_callback(settings_context.clone(), esc_callback!(private_user_id, priv_userid_verify));
     let sponsorblock_api_base_url_revert  = use_callback(settings_context.clone(), esc_callback!(sponsorblock_api_base_url, baseurl_verify));
+    let api_base_url_revert               = use_callback(settings_context.clone(), esc_callback!(api_base_url, opt_baseurl_verify));
 
     let entries_per_page_save             = use_callback(settings_context.clone(), save_callback!(entries_per_page, fromstr_verify));
     let thumbgen_api_base_url_save        = use_callback(settings_context.clone(), save_callback!(thumbgen_api_base_url, baseurl_verify));
     let title_table_layout_save           = use_callback(settings_context.clone(), save_callback!(title_table_layout, fromstr_verify));
     let thumbnail_table_layout_save       = use_callback(settings_context.clone(), save_callback!(thumbnail_table_layout, fromstr_verify));
+    let timezone_save                     = use_callback(settings_context.clone(), save_callback!(timezone, fromstr_verify));
+    let timezone_offset_minutes_save      = use_callback(settings_context.clone(), save_callback!(timezone_offset_minutes, fromstr_verify));
     let render_thumbnails_in_tables_save  = use_callback(settings_context.clone(), save_callback!(render_thumbnails_in_tables, checkbox_verify));
+    let min_thumbnail_score_save          = use_callback(settings_context.clone(), save_callback!(min_thumbnail_score, opt_score_verify));
+    let always_show_vote_breakdown_save   = use_callback(settings_context.clone(), save_callback!(always_show_vote_breakdown, checkbox_verify));
+    let text_flag_labels_save             = use_callback(settings_context.clone(), save_callback!(text_flag_labels, checkbox_verify));
+    let hide_original_titles_save         = use_callback(settings_context.clone(), save_callback!(hide_original_titles, checkbox_verify));
+    let compare_original_title_save       = use_callback(settings_context.clone(), save_callback!(compare_original_title, checkbox_verify));
+    let group_homepage_by_video_save      = use_callback(settings_context.clone(), save_callback!(group_homepage_by_video, checkbox_verify));
     let enable_autosearch_save            = use_callback(settings_context.clone(), save_callback!(enable_autosearch, checkbox_verify));
+    let lazy_video_embed_save             = use_callback(settings_context.clone(), save_callback!(lazy_video_embed, checkbox_verify));
+    let sized_thumbnail_requests_save     = use_callback(settings_context.clone(), save_callback!(sized_thumbnail_requests, checkbox_verify));
+    let auto_refresh_data_save            = use_callback(settings_context.clone(), save_callback!(auto_refresh_data, checkbox_verify));
+    let now_playing_listener_save         = use_callback(settings_context.clone(), save_callback!(now_playing_listener, checkbox_verify));
     let disable_sharedworker_save         = use_callback(settings_context.clone(), save_callback!(disable_sharedworker, checkbox_verify));
     let private_user_id_save              = use_callback(settings_context.clone(), save_callback!(private_user_id, priv_userid_verify));
     let sponsorblock_api_base_url_save    = use_callback(settings_context.clone(), save_callback!(sponsorblock_api_base_url, baseurl_verify));
+    let api_base_url_save                 = use_callback(settings_context.clone(), save_callback!(api_base_url, opt_baseurl_verify));
 
     let entries_per_page_undo             = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(entries_per_page));
     let thumbgen_api_base_url_undo        = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(thumbgen_api_base_url));
     let title_table_layout_undo           = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(title_table_layout));
     let thumbnail_table_layout_undo       = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(thumbnail_table_layout));
+    let timezone_undo                     = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(timezone));
+    let timezone_offset_minutes_undo      = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(timezone_offset_minutes));
     let render_thumbnails_in_tables_undo  = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(render_thumbnails_in_tables));
+    let min_thumbnail_score_undo          = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(min_thumbnail_score));
+    let always_show_vote_breakdown_undo   = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(always_show_vote_breakdown));
+    let text_flag_labels_undo             = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(text_flag_labels));
+    let hide_original_titles_undo         = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(hide_original_titles));
+    let compare_original_title_undo       = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(compare_original_title));
+    let group_homepage_by_video_undo      = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(group_homepage_by_video));
     let enable_autosearch_undo            = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(enable_autosearch));
+    let lazy_video_embed_undo             = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(lazy_video_embed));
+    let sized_thumbnail_requests_undo     = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(sized_thumbnail_requests));
+    let auto_refresh_data_undo            = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(auto_refresh_data));
+    let now_playing_listener_undo         = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(now_playing_listener));
     let disable_sharedworker_undo         = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(disable_sharedworker));
     let private_user_id_undo              = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(private_user_id));
     let sponsorblock_api_base_url_undo    = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(sponsorblock_api_base_url));
+    let api_base_url_undo                 = use_callback((settings_context.clone(), initial_settings.clone()), undo_callback!(api_base_url));
 
     let entries_per_page_reset            = use_callback(settings_context.clone(), reset_callback!(entries_per_page));
     let thumbgen_api_base_url_reset       = use_callback(settings_context.clone(), reset_callback!(thumbgen_api_base_url));
     let title_table_layout_reset          = use_callback(settings_context.clone(), reset_callback!(title_table_layout));
     let thumbnail_table_layout_reset      = use_callback(settings_context.clone(), reset_callback!(thumbnail_table_layout));
+    let timezone_reset                    = use_callback(settings_context.clone(), reset_callback!(timezone));
+    let timezone_offset_minutes_reset     = use_callback(settings_context.clone(), reset_callback!(timezone_offset_minutes));
     let render_thumbnails_in_tables_reset = use_callback(settings_context.clone(), reset_callback!(render_thumbnails_in_tables));
+    let min_thumbnail_score_reset         = use_callback(settings_context.clone(), reset_callback!(min_thumbnail_score));
+    let always_show_vote_breakdown_reset  = use_callback(settings_context.clone(), reset_callback!(always_show_vote_breakdown));
+    let text_flag_labels_reset            = use_callback(settings_context.clone(), reset_callback!(text_flag_labels));
+    let hide_original_titles_reset        = use_callback(settings_context.clone(), reset_callback!(hide_original_titles));
+    let compare_original_title_reset      = use_callback(settings_context.clone(), reset_callback!(compare_original_title));
+    let group_homepage_by_video_reset     = use_callback(settings_context.clone(), reset_callback!(group_homepage_by_video));
     let enable_autosearch_reset           = use_callback(settings_context.clone(), reset_callback!(enable_autosearch));
+    let lazy_video_embed_reset            = use_callback(settings_context.clone(), reset_callback!(lazy_video_embed));
+    let sized_thumbnail_requests_reset    = use_callback(settings_context.clone(), reset_callback!(sized_thumbnail_requests));
+    let auto_refresh_data_reset           = use_callback(settings_context.clone(), reset_callback!(auto_refresh_data));
+    let now_playing_listener_reset        = use_callback(settings_context.clone(), reset_callback!(now_playing_listener));
     let disable_sharedworker_reset        = use_callback(settings_context.clone(), reset_callback!(disable_sharedworker));
     let private_user_id_reset             = use_callback(settings_context.clone(), reset_callback!(private_user_id));
     let sponsorblock_api_base_url_reset   = use_callback(settings_context.clone(), reset_callback!(sponsorblock_api_base_url));
+    let api_base_url_reset                = use_callback(settings_context.clone(), reset_callback!(api_base_url));
 
 
     // ~value doesnt work for <select>
     use_effect_with((title_table_layout_ref.clone(), current_settings.title_table_layout), update_select);
     use_effect_with((thumbnail_table_layout_ref.clone(), current_settings.thumbnail_table_layout), update_select);
+    use_effect_with((timezone_ref.clone(), current_settings.timezone), update_select);
 
     html! {
         <div id="settings-modal">
@@ -396,7 +491,199 @@ pub fn SettingsModal() -> Html {
                             >{"🔄"}</span>
                         }
                     </div>
+                    if current_settings.render_thumbnails_in_tables {
+                        <label for="min_thumbnail_score" title={MIN_THUMBNAIL_SCORE_TITLE}>{"Minimum score to render thumbnails: "}</label>
+                        <input 
+                            class={setting_class!(initial_settings, current_settings, min_thumbnail_score)} 
+                            id="min_thumbnail_score" 
+                            title={MIN_THUMBNAIL_SCORE_TITLE}
+                            type="number" step=1 min="-128" max=127 placeholder="No minimum"
+                            oninput={opt_score_oninput} 
+                            onkeydown={min_thumbnail_score_revert} 
+                            onchange={min_thumbnail_score_save} 
+                            ~value={current_settings.min_thumbnail_score.to_string()} 
+                        />
+                        <div class="setting-actions">
+                            if should_show_undo!(min_thumbnail_score, current_settings, initial_settings) {
+                                <span 
+                                    class="clickable" title="Undo"
+                                    onclick={min_thumbnail_score_undo}
+                                >{"↩️"}</span>
+                            }
+                            if should_show_reset!(min_thumbnail_score, current_settings, settings_context) {
+                                <span 
+                                    class="clickable" title="Reset to default"
+                                    onclick={min_thumbnail_score_reset}
+                                >{"🔄"}</span>
+                            }
+                        </div>
+                    }
+                }
+                <label for="timezone">{"Timezone: "}</label>
+                <select 
+                    id="timezone"
+                    class={setting_class!(initial_settings, current_settings, timezone)} 
+                    onchange={timezone_save}
+                    ref={timezone_ref}
+                >
+                    {for TimezoneMode::VARIANTS.iter().map(|&name| html!{ <option key={name}>{name}</option> })}
+                </select>
+                <div class="setting-actions">
+                    if should_show_undo!(timezone, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={timezone_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(timezone, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={timezone_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
+                if current_settings.timezone == TimezoneMode::Fixed {
+                    <label for="timezone_offset_minutes" title={TIMEZONE_OFFSET_TITLE}>{"Timezone offset (minutes): "}</label>
+                    <input 
+                        class={setting_class!(initial_settings, current_settings, timezone_offset_minutes)} 
+                        id="timezone_offset_minutes" 
+                        title={TIMEZONE_OFFSET_TITLE}
+                        type="number" step=1 min="-1439" max=1439 required=true 
+                        oninput={i32_oninput} 
+                        onkeydown={timezone_offset_minutes_revert} 
+                        onchange={timezone_offset_minutes_save} 
+                        ~value={current_settings.timezone_offset_minutes.to_string()} 
+                    />
+                    <div class="setting-actions">
+                        if should_show_undo!(timezone_offset_minutes, current_settings, initial_settings) {
+                            <span 
+                                class="clickable" title="Undo"
+                                onclick={timezone_offset_minutes_undo}
+                            >{"↩️"}</span>
+                        }
+                        if should_show_reset!(timezone_offset_minutes, current_settings, settings_context) {
+                            <span 
+                                class="clickable" title="Reset to default"
+                                onclick={timezone_offset_minutes_reset}
+                            >{"🔄"}</span>
+                        }
+                    </div>
                 }
+                <label for="always_show_vote_breakdown" title={VOTE_BREAKDOWN_TITLE}>{"Always show vote breakdown: "}</label>
+                <input 
+                    class={setting_class!(initial_settings, current_settings, always_show_vote_breakdown)} 
+                    id="always_show_vote_breakdown" 
+                    title={VOTE_BREAKDOWN_TITLE}
+                    type="checkbox"
+                    onchange={always_show_vote_breakdown_save} 
+                    ~checked={current_settings.always_show_vote_breakdown} 
+                />
+                <div class="setting-actions">
+                    if should_show_undo!(always_show_vote_breakdown, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={always_show_vote_breakdown_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(always_show_vote_breakdown, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={always_show_vote_breakdown_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
+                <label for="text_flag_labels" title={TEXT_FLAG_LABELS_TITLE}>{"Show flags as text: "}</label>
+                <input 
+                    class={setting_class!(initial_settings, current_settings, text_flag_labels)} 
+                    id="text_flag_labels" 
+                    title={TEXT_FLAG_LABELS_TITLE}
+                    type="checkbox"
+                    onchange={text_flag_labels_save} 
+                    ~checked={current_settings.text_flag_labels} 
+                />
+                <div class="setting-actions">
+                    if should_show_undo!(text_flag_labels, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={text_flag_labels_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(text_flag_labels, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={text_flag_labels_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
+                <label for="hide_original_titles" title={HIDE_ORIGINAL_TITLES_TITLE}>{"Hide original titles: "}</label>
+                <input 
+                    class={setting_class!(initial_settings, current_settings, hide_original_titles)} 
+                    id="hide_original_titles" 
+                    title={HIDE_ORIGINAL_TITLES_TITLE}
+                    type="checkbox"
+                    onchange={hide_original_titles_save} 
+                    ~checked={current_settings.hide_original_titles} 
+                />
+                <div class="setting-actions">
+                    if should_show_undo!(hide_original_titles, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={hide_original_titles_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(hide_original_titles, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={hide_original_titles_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
+                <label for="compare_original_title" title={COMPARE_ORIGINAL_TITLE_TITLE}>{"Compare with the original title: "}</label>
+                <input 
+                    class={setting_class!(initial_settings, current_settings, compare_original_title)} 
+                    id="compare_original_title" 
+                    title={COMPARE_ORIGINAL_TITLE_TITLE}
+                    type="checkbox"
+                    onchange={compare_original_title_save} 
+                    ~checked={current_settings.compare_original_title} 
+                />
+                <div class="setting-actions">
+                    if should_show_undo!(compare_original_title, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={compare_original_title_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(compare_original_title, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={compare_original_title_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
+                <label for="group_homepage_by_video" title={GROUP_HOMEPAGE_TITLE}>{"Group home page by video: "}</label>
+                <input 
+                    class={setting_class!(initial_settings, current_settings, group_homepage_by_video)} 
+                    id="group_homepage_by_video" 
+                    title={GROUP_HOMEPAGE_TITLE}
+                    type="checkbox"
+                    onchange={group_homepage_by_video_save} 
+                    ~checked={current_settings.group_homepage_by_video} 
+                />
+                <div class="setting-actions">
+                    if should_show_undo!(group_homepage_by_video, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={group_homepage_by_video_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(group_homepage_by_video, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={group_homepage_by_video_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
             </fieldset>
             <fieldset>
                 <legend>{"Site behaviour"}</legend>
@@ -422,6 +709,100 @@ pub fn SettingsModal() -> Html {
                         >{"🔄"}</span>
                     }
                 </div>
+                <label for="lazy_video_embed" title={LAZY_EMBED_TITLE}>{"Load video embeds on click: "}</label>
+                <input 
+                    class={setting_class!(initial_settings, current_settings, lazy_video_embed)} 
+                    id="lazy_video_embed" 
+                    title={LAZY_EMBED_TITLE}
+                    type="checkbox"
+                    onchange={lazy_video_embed_save} 
+                    ~checked={current_settings.lazy_video_embed} 
+                />
+                <div class="setting-actions">
+                    if should_show_undo!(lazy_video_embed, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={lazy_video_embed_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(lazy_video_embed, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={lazy_video_embed_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
+                <label for="auto_refresh_data" title={AUTO_REFRESH_TITLE}>{"Reload tables automatically: "}</label>
+                <input 
+                    class={setting_class!(initial_settings, current_settings, auto_refresh_data)} 
+                    id="auto_refresh_data" 
+                    title={AUTO_REFRESH_TITLE}
+                    type="checkbox"
+                    onchange={auto_refresh_data_save} 
+                    ~checked={current_settings.auto_refresh_data} 
+                />
+                <div class="setting-actions">
+                    if should_show_undo!(auto_refresh_data, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={auto_refresh_data_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(auto_refresh_data, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={auto_refresh_data_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
+                <label for="now_playing_listener" title={NOW_PLAYING_TITLE}>{"Accept \"now playing\" messages: "}</label>
+                <input 
+                    class={setting_class!(initial_settings, current_settings, now_playing_listener)} 
+                    id="now_playing_listener" 
+                    title={NOW_PLAYING_TITLE}
+                    type="checkbox"
+                    onchange={now_playing_listener_save} 
+                    ~checked={current_settings.now_playing_listener} 
+                />
+                <div class="setting-actions">
+                    if should_show_undo!(now_playing_listener, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={now_playing_listener_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(now_playing_listener, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={now_playing_listener_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
+                <label for="api_base_url" title={API_BASE_URL_TITLE}>{"DeArrow Browser API base URL: "}</label>
+                <input 
+                    class={setting_class!(initial_settings, current_settings, api_base_url)} 
+                    id="api_base_url" 
+                    title={API_BASE_URL_TITLE}
+                    type="url" placeholder="Same as this page"
+                    oninput={opt_baseurl_oninput} 
+                    onkeydown={api_base_url_revert} 
+                    onchange={api_base_url_save} 
+                    ~value={current_settings.api_base_url.to_string()} 
+                />
+                <div class="setting-actions">
+                    if should_show_undo!(api_base_url, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={api_base_url_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(api_base_url, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={api_base_url_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
             </fieldset>
             <fieldset>
                 <legend>{"Thumbnail generator"}</legend>
@@ -449,6 +830,29 @@ pub fn SettingsModal() -> Html {
                         >{"🔄"}</span>
                     }
                 </div>
+                <label for="sized_thumbnail_requests" title={SIZED_THUMBNAILS_TITLE}>{"Request sized thumbnails: "}</label>
+                <input 
+                    class={setting_class!(initial_settings, current_settings, sized_thumbnail_requests)} 
+                    id="sized_thumbnail_requests" 
+                    title={SIZED_THUMBNAILS_TITLE}
+                    type="checkbox"
+                    onchange={sized_thumbnail_requests_save} 
+                    ~checked={current_settings.sized_thumbnail_requests} 
+                />
+                <div class="setting-actions">
+                    if should_show_undo!(sized_thumbnail_requests, current_settings, initial_settings) {
+                        <span 
+                            class="clickable" title="Undo"
+                            onclick={sized_thumbnail_requests_undo}
+                        >{"↩️"}</span>
+                    }
+                    if should_show_reset!(sized_thumbnail_requests, current_settings, settings_context) {
+                        <span 
+                            class="clickable" title="Reset to default"
+                            onclick={sized_thumbnail_requests_reset}
+                        >{"🔄"}</span>
+                    }
+                </div>
                 <label for="disable_sharedworker" title={DISABLE_SW_TITLE}>{"Disable SharedWorker implementation: "}</label>
                 <input 
                     class={setting_class!(initial_settings, current_settings, disable_sharedworker)} 
@@ -525,6 +929,66 @@ pub fn SettingsModal() -> Html {
                     }
                 </div>
             </fieldset>
+            <SettingsTransfer />
         </div>
     }
 }
+
+/// Export & import of all settings as JSON, for backups or moving to another browser
+#[function_component]
+fn SettingsTransfer() -> Html {
+    let settings_context: SettingsContext = use_context().expect("SettingsContext should be available");
+    let textarea_ref = use_node_ref();
+    let message = use_state(|| None::<AttrValue>);
+
+    let export = use_callback((settings_context.clone(), textarea_ref.clone(), message.clone()), |_: MouseEvent, (settings_context, textarea_ref, message)| {
+        let exported = settings_context.settings().export();
+        if let Some(textarea) = textarea_ref.cast::<HtmlTextAreaElement>() {
+            textarea.set_value(&exported);
+            textarea.select();
+        }
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let promise = window.navigator().clipboard().write_text(&exported);
+        let message = message.clone();
+        spawn_local(async move {
+            message.set(Some(match JsFuture::from(promise).await {
+                Ok(_) => AttrValue::Static("Settings copied to the clipboard. Your private userID is not included."),
+                Err(_) => AttrValue::Static("Could not access the clipboard, copy the settings from the text box instead. Your private userID is not included."),
+            }));
+        });
+    });
+    let import = use_callback((settings_context, textarea_ref.clone(), message.clone()), |_: MouseEvent, (settings_context, textarea_ref, message)| {
+        let Some(textarea) = textarea_ref.cast::<HtmlTextAreaElement>() else {
+            return;
+        };
+        let current = settings_context.settings();
+        match current.import(&textarea.value()) {
+            Err(err) => message.set(Some(format!("Import failed, no settings were changed: {err}").into())),
+            Ok(imported) => {
+                let changed = current.changed_fields(&imported);
+                message.set(Some(if changed.is_empty() {
+                    AttrValue::Static("Import successful, no settings were changed.")
+                } else {
+                    format!("Import successful, changed settings: {}", changed.join(", ")).into()
+                }));
+                settings_context.update(imported);
+            },
+        }
+    });
+
+    html! {
+        <fieldset id="settings-transfer">
+            <legend>{"Backup & transfer"}</legend>
+            <textarea ref={textarea_ref} rows=6 placeholder="Paste exported settings here and click Import" />
+            <div>
+                <span class="button" onclick={export}>{"Export"}</span>
+                <span class="button" onclick={import}>{"Import"}</span>
+            </div>
+            if let Some(message) = &*message {
+                <span>{message.clone()}</span>
+            }
+        </fieldset>
+    }
+}
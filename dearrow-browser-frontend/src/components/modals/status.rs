@@ -21,12 +21,15 @@ use chrono::DateTime;
 use yew::platform::spawn_local;
 use yew::prelude::*;
 use yew_hooks::{use_async, use_interval};
+use yew_router::prelude::Link;
 
 use crate::{built_info, constants};
-use crate::contexts::{StatusContext, WindowContext};
+use crate::contexts::{ModalMessage, ModalRendererControls, StatusContext};
+use crate::pages::MainRoute;
 use crate::thumbnails::components::{
     TRExt, Thumbgen, ThumbgenContext, ThumbgenContextExt, ThumbgenRefreshContext,
 };
+use crate::hooks::use_display_timezone;
 use crate::utils::{render_datetime, RenderNumber};
 
 macro_rules! number_hoverswitch {
@@ -48,18 +51,17 @@ macro_rules! number_hoverswitch {
 
 #[function_component]
 pub fn StatusModal() -> Html {
-    let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
+    let modal_controls: ModalRendererControls =
+        use_context().expect("ModalRendererControls should be available");
     let status: StatusContext = use_context().expect("StatusContext should be defined");
     let thumbgen: ThumbgenContext = use_context().expect("ThumbgenContext should be available");
     let thumbgen_refresh: ThumbgenRefreshContext =
         use_context().expect("ThumbgenRefreshContext should be available");
     let update_clock: UseStateHandle<bool> = use_state(|| false);
+    let tz = use_display_timezone();
 
-    let errors_url: Rc<AttrValue> = use_memo(window_context, |wc| {
-        wc.origin_join_segments(&["api", "errors"])
-            .as_str()
-            .to_owned()
-            .into()
+    let close_modals = use_callback((), move |_: MouseEvent, ()| {
+        modal_controls.emit(ModalMessage::CloseAll);
     });
 
     let thumbgen_impl = match &thumbgen {
@@ -141,7 +143,7 @@ pub fn StatusModal() -> Html {
                         <th>{"Build date"}</th>
                         <td>
                             if let Some(dt) = *constants::BUILD_TIME {
-                                {render_datetime(dt.into())}
+                                {render_datetime(dt.into(), tz)}
                             } else {
                                 <em>{"Unknown"}</em>
                             }
@@ -224,7 +226,7 @@ pub fn StatusModal() -> Html {
                             <th>{"Build date"}</th>
                             <td>
                                 if let Some(dt) = status.server_build_timestamp.and_then(|t| DateTime::from_timestamp(t, 0)) {
-                                    {render_datetime(dt)}
+                                    {render_datetime(dt, tz)}
                                 } else {
                                     <em>{"Unknown"}</em>
                                 }
@@ -237,7 +239,7 @@ pub fn StatusModal() -> Html {
                             <th>{"Server started at"}</th>
                             <td>
                                 if let Some(dt) = DateTime::from_timestamp(status.server_startup_timestamp, 0) {
-                                    {render_datetime(dt)}
+                                    {render_datetime(dt, tz)}
                                 } else {
                                     <em>{"Failed to parse"}</em>
                                 }
@@ -247,7 +249,7 @@ pub fn StatusModal() -> Html {
                             <th>{"Last update"}</th>
                             <td>
                                 if let Some(dt) = DateTime::from_timestamp_millis(status.last_updated) {
-                                    {render_datetime(dt)}
+                                    {render_datetime(dt, tz)}
                                     if status.updating_now {
                                         <b>{", update in progress"}</b>
                                     }
@@ -260,7 +262,7 @@ pub fn StatusModal() -> Html {
                             <th>{"DB snapshot taken at"}</th>
                             <td>
                                 if let Some(dt) = DateTime::from_timestamp_millis(status.last_modified) {
-                                    {render_datetime(dt)}
+                                    {render_datetime(dt, tz)}
                                 } else {
                                     <em>{"Failed to parse"}</em>
                                 }
@@ -296,11 +298,31 @@ pub fn StatusModal() -> Html {
                         </tr>
                         <tr class="hoverswitch-trigger">
                             <th>{"Videos with durations"}</th>
-                            {number_hoverswitch!(td, status.video_infos)}
+                            if let Some(count) = status.video_infos {
+                                {number_hoverswitch!(td, count)}
+                            } else {
+                                <td><em>{"Computing..."}</em></td>
+                            }
                         </tr>
                         <tr class="hoverswitch-trigger">
                             <th>{"Unmarked video segments"}</th>
-                            {number_hoverswitch!(td, status.uncut_segments)}
+                            if let Some(count) = status.uncut_segments {
+                                {number_hoverswitch!(td, count)}
+                            } else {
+                                <td><em>{"Computing..."}</em></td>
+                            }
+                        </tr>
+                        <tr class="hoverswitch-trigger">
+                            <th>{"Titles missing votes"}</th>
+                            {number_hoverswitch!(td, status.titles_missing_votes)}
+                        </tr>
+                        <tr class="hoverswitch-trigger">
+                            <th>{"Thumbnails missing votes"}</th>
+                            {number_hoverswitch!(td, status.thumbnails_missing_votes)}
+                        </tr>
+                        <tr class="hoverswitch-trigger">
+                            <th>{"Thumbnails missing timestamps"}</th>
+                            {number_hoverswitch!(td, status.thumbnails_missing_timestamp)}
                         </tr>
                         <tr class="hoverswitch-trigger">
                             <th>{"Cached channels"}</th>
@@ -317,7 +339,7 @@ pub fn StatusModal() -> Html {
                             <th>{"Parse errors"}</th>
                             <td>
                                 {number_hoverswitch!(span, status.errors)}{" "}
-                                <a href={(*errors_url).clone()} target="_blank">{"(view)"}</a>
+                                <span onclick={close_modals}><Link<MainRoute> to={MainRoute::Errors}>{"(view)"}</Link<MainRoute>></span>
                             </td>
                         </tr>
                     </table>
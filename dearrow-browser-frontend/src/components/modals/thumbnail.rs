@@ -20,7 +20,7 @@ use yew::prelude::*;
 
 use std::rc::Rc;
 
-use crate::thumbnails::components::{UnwrappedThumbnail, UnwrappedThumbnailProps};
+use crate::thumbnails::components::{ThumbnailSize, UnwrappedThumbnail, UnwrappedThumbnailProps};
 
 #[function_component]
 pub fn ThumbnailModal(props: &UnwrappedThumbnailProps) -> Html {
@@ -35,7 +35,7 @@ pub fn ThumbnailModal(props: &UnwrappedThumbnailProps) -> Html {
         <div id="thumbnail-modal">
             <h2>{"Thumbnail preview"}</h2>
             <h3>{(*header_text).clone()}</h3>
-            <UnwrappedThumbnail ..props.clone() />
+            <UnwrappedThumbnail size={ThumbnailSize::Modal} ..props.clone() />
         </div>
     }
 }
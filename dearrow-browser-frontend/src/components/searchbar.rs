@@ -38,9 +38,8 @@ macro_rules! search_block {
 
 fn parsed_url_last_segment(url: &Url) -> Option<String> {
     url.path_segments()
-       .and_then(|it| 
-            it.filter(|s| !s.is_empty())
-              .last()
+       .and_then(|mut it| 
+            it.rfind(|s| !s.is_empty())
        )
        .map(ToString::to_string)
 }
@@ -190,6 +189,14 @@ pub fn Searchbar() -> Html {
                 <ul>
                     <li><Link<MainRoute> to={MainRoute::Unverified}>{"Unverified titles"}</Link<MainRoute>></li>
                     <li><Link<MainRoute> to={MainRoute::Broken}>{"Broken entries"}</Link<MainRoute>></li>
+                    <li><Link<MainRoute> to={MainRoute::Warnings}>{"Warnings"}</Link<MainRoute>></li>
+                </ul>
+            </fieldset>
+            <fieldset>
+                <legend>{"Tools"}</legend>
+                <ul>
+                    <li><Link<MainRoute> to={MainRoute::TitleCasing}>{"Title casing preview"}</Link<MainRoute>></li>
+                    <li><Link<MainRoute> to={MainRoute::VoteStats}>{"Vote statistics"}</Link<MainRoute>></li>
                 </ul>
             </fieldset>
         </div>
@@ -0,0 +1,57 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*  
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::fmt::Write;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+const WIDTH: f64 = 120.;
+const HEIGHT: f64 = 24.;
+
+#[derive(Properties, PartialEq)]
+pub struct SparklineProps {
+    pub values: Rc<[usize]>,
+    /// Shown on hover and read by screen readers
+    pub title: AttrValue,
+}
+
+/// Small inline line chart of the given values, scaled to the largest one
+#[function_component]
+pub fn Sparkline(props: &SparklineProps) -> Html {
+    let points = use_memo(props.values.clone(), |values| {
+        #[allow(clippy::cast_precision_loss)]
+        let max = values.iter().copied().max().unwrap_or(0).max(1) as f64;
+        #[allow(clippy::cast_precision_loss)]
+        let step = WIDTH / (values.len().max(2) - 1) as f64;
+        let mut points = String::new();
+        for (i, value) in values.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let (x, y) = (i as f64 * step, HEIGHT - *value as f64 / max * HEIGHT);
+            let _ = write!(points, "{x:.1},{y:.1} ");
+        }
+        AttrValue::from(points)
+    });
+
+    html! {
+        <svg class="sparkline" viewBox={format!("0 -1 {WIDTH} {}", HEIGHT + 2.)} role="img" aria-label={props.title.clone()}>
+            <title>{props.title.clone()}</title>
+            <polyline points={(*points).clone()} />
+        </svg>
+    }
+}
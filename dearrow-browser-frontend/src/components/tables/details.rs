@@ -15,7 +15,7 @@
 *  You should have received a copy of the GNU Affero General Public License
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
-use std::rc::Rc;
+use std::{cmp::Reverse, rc::Rc};
 
 use chrono::DateTime;
 use dearrow_browser_api::unsync::*;
@@ -30,15 +30,15 @@ use crate::components::{
         thumbnail::ThumbnailModal,
         voting::{VotingDetail, VotingModal},
     },
-    tables::switch::PageSelect,
+    tables::switch::{PageSelect, ViewAllToggle},
     youtube::YoutubeVideoLink,
 };
 use crate::contexts::{
     ModalMessage, ModalRendererControls, SettingsContext, StatusContext, UserContext,
 };
-use crate::hooks::{use_async_suspension, use_location_state};
+use crate::hooks::{use_async_suspension, use_display_timezone, use_effective_table_layouts, use_location_state, TableLayouts};
 use crate::settings::TableLayout;
-use crate::thumbnails::components::{ContainerType, Thumbnail, ThumbnailCaption};
+use crate::thumbnails::components::{ContainerType, Thumbnail, ThumbnailCaption, ThumbnailSize};
 use crate::utils::{api_request, html_length, render_datetime, RcEq};
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -47,9 +47,51 @@ pub enum DetailType {
     Thumbnail,
 }
 
+/// Kind of entries listed in a detail table
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum DetailTableKind {
+    Titles,
+    Thumbnails,
+    /// Titles and thumbnails merged into a single table
+    All,
+}
+
+impl From<DetailType> for DetailTableKind {
+    fn from(value: DetailType) -> Self {
+        match value {
+            DetailType::Title => DetailTableKind::Titles,
+            DetailType::Thumbnail => DetailTableKind::Thumbnails,
+        }
+    }
+}
+
+/// A single entry of a merged title & thumbnail list
+#[derive(Clone, PartialEq)]
+pub enum DetailItem {
+    Title(ApiTitle),
+    Thumbnail(ApiThumbnail),
+}
+
+impl DetailItem {
+    pub fn time_submitted(&self) -> i64 {
+        match self {
+            DetailItem::Title(ref t) => t.time_submitted,
+            DetailItem::Thumbnail(ref t) => t.time_submitted,
+        }
+    }
+
+    pub fn uuid(&self) -> &Rc<str> {
+        match self {
+            DetailItem::Title(ref t) => &t.uuid,
+            DetailItem::Thumbnail(ref t) => &t.uuid,
+        }
+    }
+}
+
 pub enum DetailList {
     Thumbnails(Vec<ApiThumbnail>),
     Titles(Vec<ApiTitle>),
+    All(Vec<DetailItem>),
 }
 
 impl DetailList {
@@ -57,6 +99,7 @@ impl DetailList {
         match self {
             DetailList::Thumbnails(ref l) => l.len(),
             DetailList::Titles(ref l) => l.len(),
+            DetailList::All(ref l) => l.len(),
         }
     }
 
@@ -64,6 +107,7 @@ impl DetailList {
         match self {
             DetailList::Thumbnails(ref l) => l.is_empty(),
             DetailList::Titles(ref l) => l.is_empty(),
+            DetailList::All(ref l) => l.is_empty(),
         }
     }
 }
@@ -88,10 +132,13 @@ pub fn use_detail_download(
                 match result {
                     DetailSlice::Thumbnails(ref mut list) => Rc::get_mut(&mut list.0)
                         .expect("should be get mutable reference here")
-                        .sort_unstable_by(|a, b| b.time_submitted.cmp(&a.time_submitted)),
+                        .sort_unstable_by_key(|d| Reverse(d.time_submitted)),
                     DetailSlice::Titles(ref mut list) => Rc::get_mut(&mut list.0)
                         .expect("should be get mutable reference here")
-                        .sort_unstable_by(|a, b| b.time_submitted.cmp(&a.time_submitted)),
+                        .sort_unstable_by_key(|d| Reverse(d.time_submitted)),
+                    DetailSlice::All(ref mut list) => Rc::get_mut(&mut list.0)
+                        .expect("should be get mutable reference here")
+                        .sort_unstable_by_key(|d| Reverse(d.time_submitted())),
                 }
             }
             Ok(result)
@@ -100,6 +147,84 @@ pub fn use_detail_download(
     )
 }
 
+/// Fetches both titles and thumbnails and merges them into a single list, sorted by time
+/// submitted, most to least recent
+#[hook]
+pub fn use_merged_detail_download(
+    titles_url: Rc<Url>,
+    thumbnails_url: Rc<Url>,
+) -> SuspensionResult<Rc<Result<DetailSlice, ErrorContext>>> {
+    let status: StatusContext = use_context().expect("StatusResponse should be defined");
+    use_async_suspension(
+        |(titles_url, thumbnails_url, _)| async move {
+            let (titles, thumbnails): (Vec<ApiTitle>, Vec<ApiThumbnail>) = futures::try_join!(
+                api_request((*titles_url).clone()),
+                api_request((*thumbnails_url).clone()),
+            )?;
+            let mut merged: Vec<DetailItem> = titles
+                .into_iter()
+                .map(DetailItem::Title)
+                .chain(thumbnails.into_iter().map(DetailItem::Thumbnail))
+                .collect();
+            merged.sort_by_key(|d| Reverse(d.time_submitted()));
+            Ok(DetailSlice::All(RcEq(merged.into())))
+        },
+        (titles_url, thumbnails_url, status.map(|s| s.last_updated)),
+    )
+}
+
+/// Part of a [`UserSubmissions`] response to render
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum SubmissionsView {
+    Titles,
+    Thumbnails,
+    /// Titles and thumbnails merged into a single timeline
+    All,
+}
+
+/// Titles, thumbnails and both merged from a single [`UserSubmissions`] response
+#[derive(Clone, PartialEq)]
+pub struct SubmissionSlices {
+    pub titles: DetailSlice,
+    pub thumbnails: DetailSlice,
+    pub all: DetailSlice,
+}
+
+impl SubmissionSlices {
+    pub fn get(&self, view: SubmissionsView) -> &DetailSlice {
+        match view {
+            SubmissionsView::Titles => &self.titles,
+            SubmissionsView::Thumbnails => &self.thumbnails,
+            SubmissionsView::All => &self.all,
+        }
+    }
+}
+
+/// Fetches titles and thumbnails in a single request, so that switching between them doesn't
+/// need another request
+#[hook]
+pub fn use_submissions_download(url: Rc<Url>) -> SuspensionResult<Rc<Result<SubmissionSlices, ErrorContext>>> {
+    let status: StatusContext = use_context().expect("StatusResponse should be defined");
+    use_async_suspension(
+        |(url, _)| async move {
+            let submissions: UserSubmissions = api_request((*url).clone()).await?;
+            let mut merged: Vec<DetailItem> = submissions.titles
+                .iter()
+                .cloned()
+                .map(DetailItem::Title)
+                .chain(submissions.thumbnails.iter().cloned().map(DetailItem::Thumbnail))
+                .collect();
+            merged.sort_by_key(|d| Reverse(d.time_submitted()));
+            Ok(SubmissionSlices {
+                titles: DetailSlice::Titles(RcEq(submissions.titles.into())),
+                thumbnails: DetailSlice::Thumbnails(RcEq(submissions.thumbnails.into())),
+                all: DetailSlice::All(RcEq(merged.into())),
+            })
+        },
+        (url, status.map(|s| s.last_updated)),
+    )
+}
+
 #[derive(Properties, PartialEq)]
 pub struct BaseDetailTableRendererProps {
     pub details: DetailSlice,
@@ -109,6 +234,9 @@ pub struct BaseDetailTableRendererProps {
     pub hide_username: bool,
     #[prop_or_default]
     pub hide_videoid: bool,
+    /// Whether original titles may be hidden from this table, if enabled in settings
+    #[prop_or_default]
+    pub hideable_originals: bool,
 }
 
 #[derive(Properties, PartialEq)]
@@ -123,14 +251,53 @@ pub struct DetailTableRendererProps {
     pub hide_username: bool,
     #[prop_or_default]
     pub hide_videoid: bool,
+    /// Whether original titles may be hidden from this table, if enabled in settings
+    #[prop_or_default]
+    pub hideable_originals: bool,
     #[prop_or(true)]
     pub sort: bool,
 }
 
+#[derive(Properties, PartialEq)]
+pub struct MergedDetailTableRendererProps {
+    pub titles_url: Rc<Url>,
+    pub thumbnails_url: Rc<Url>,
+    #[prop_or_default]
+    pub entry_count: Option<UseStateSetter<Option<usize>>>,
+    #[prop_or_default]
+    pub hide_userid: bool,
+    #[prop_or_default]
+    pub hide_username: bool,
+    #[prop_or_default]
+    pub hide_videoid: bool,
+    /// Whether original titles may be hidden from this table, if enabled in settings
+    #[prop_or_default]
+    pub hideable_originals: bool,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SubmissionsDetailTableRendererProps {
+    /// URL of an endpoint returning [`UserSubmissions`]
+    pub url: Rc<Url>,
+    pub view: SubmissionsView,
+    #[prop_or_default]
+    pub entry_count: Option<UseStateSetter<Option<usize>>>,
+    #[prop_or_default]
+    pub hide_userid: bool,
+    #[prop_or_default]
+    pub hide_username: bool,
+    #[prop_or_default]
+    pub hide_videoid: bool,
+    /// Whether original titles may be hidden from this table, if enabled in settings
+    #[prop_or_default]
+    pub hideable_originals: bool,
+}
+
 #[derive(Clone, PartialEq)]
 pub enum DetailSlice {
     Thumbnails(RcEq<[ApiThumbnail]>),
     Titles(RcEq<[ApiTitle]>),
+    All(RcEq<[DetailItem]>),
 }
 
 impl DetailSlice {
@@ -138,6 +305,7 @@ impl DetailSlice {
         match self {
             DetailSlice::Thumbnails(ref l) => l.len(),
             DetailSlice::Titles(ref l) => l.len(),
+            DetailSlice::All(ref l) => l.len(),
         }
     }
 
@@ -145,10 +313,28 @@ impl DetailSlice {
         match self {
             DetailSlice::Thumbnails(ref l) => l.is_empty(),
             DetailSlice::Titles(ref l) => l.is_empty(),
+            DetailSlice::All(ref l) => l.is_empty(),
+        }
+    }
+
+    fn get(&self, index: usize) -> DetailRef<'_> {
+        match self {
+            DetailSlice::Thumbnails(ref l) => DetailRef::Thumbnail(&l[index]),
+            DetailSlice::Titles(ref l) => DetailRef::Title(&l[index]),
+            DetailSlice::All(ref l) => match l[index] {
+                DetailItem::Title(ref t) => DetailRef::Title(t),
+                DetailItem::Thumbnail(ref t) => DetailRef::Thumbnail(t),
+            },
         }
     }
 }
 
+#[derive(Clone, Copy)]
+enum DetailRef<'a> {
+    Title(&'a ApiTitle),
+    Thumbnail(&'a ApiThumbnail),
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum DetailIndex {
     Page { size: usize, index: usize },
@@ -190,74 +376,141 @@ pub fn use_detail_slice(details: Option<DetailSlice>, index: DetailIndex) -> Det
             (Some(DetailSlice::Titles(ref titles)), DetailIndex::All) => {
                 DetailSlice::Titles((&**titles).into())
             }
+
+            (Some(DetailSlice::All(ref items)), DetailIndex::Page { size, index }) => {
+                DetailSlice::All(
+                    if size * (index + 1) > items.len() {
+                        items.get(size * index..)
+                    } else {
+                        items.get(size * index..size * (index + 1))
+                    }
+                    .unwrap_or(&[])
+                    .into(),
+                )
+            }
+            (Some(DetailSlice::All(ref items)), DetailIndex::All) => {
+                DetailSlice::All((&**items).into())
+            }
+        }
+    }))
+    .clone()
+}
+
+/// Filters out original titles from the slice if `enabled` and the `hide_original_titles` setting
+/// is on, returning the remaining details and the amount of hidden rows
+#[hook]
+pub fn use_original_title_filter(details: DetailSlice, enabled: bool) -> (DetailSlice, usize) {
+    let settings_context: SettingsContext =
+        use_context().expect("SettingsContext should be available");
+    let enabled = enabled && settings_context.settings().hide_original_titles;
+    (*use_memo((details, enabled), |(details, enabled)| match details {
+        DetailSlice::Titles(ref titles) if *enabled => {
+            let remaining: Rc<[ApiTitle]> = titles.iter().filter(|t| !t.original).cloned().collect();
+            let hidden = titles.len() - remaining.len();
+            (DetailSlice::Titles(RcEq(remaining)), hidden)
+        }
+        DetailSlice::All(ref items) if *enabled => {
+            let remaining: Rc<[DetailItem]> = items
+                .iter()
+                .filter(|d| !matches!(d, DetailItem::Title(t) if t.original))
+                .cloned()
+                .collect();
+            let hidden = items.len() - remaining.len();
+            (DetailSlice::All(RcEq(remaining)), hidden)
         }
+        _ => (details.clone(), 0),
     }))
     .clone()
 }
 
-fn title_flags(title: &ApiTitle) -> Html {
+fn hidden_originals_notice(hidden: usize) -> Html {
+    match hidden {
+        0 => html! {},
+        1 => html! { <center class="hidden-rows-notice"><em>{"1 original title hidden"}</em></center> },
+        n => html! { <center class="hidden-rows-notice"><em>{format!("{n} original titles hidden")}</em></center> },
+    }
+}
+
+/// Renders a flag as an icon, or as a short text label if `text` is set
+fn flag(r#type: IconType, label: &'static str, tooltip: &'static str, text: bool) -> Html {
+    if text {
+        html! {<span class="flag-label" title={tooltip}>{label}</span>}
+    } else {
+        html! {<Icon r#type={r#type} tooltip={tooltip} />}
+    }
+}
+
+fn title_flags(title: &ApiTitle, text: bool, trusted: bool) -> Html {
     html! {
         <>
             if title.votes_missing {
-                <Icon r#type={IconType::VotesMissing} tooltip="Vote data is missing for this title - this title is hidden from the extension, attempting to vote on this title may cause server errors. DAB assumes the value of 0 or false for the missing fields." />
+                {flag(IconType::VotesMissing, "no votes", "Vote data is missing for this title - this title is hidden from the extension, attempting to vote on this title may cause server errors. DAB assumes the value of 0 or false for the missing fields.", text)}
             }
             if title.removed || title.shadow_hidden {
                 if title.removed {
-                    <Icon r#type={IconType::Removed} tooltip="This title was removed by a VIP" />
+                    {flag(IconType::Removed, "removed", "This title was removed by a VIP", text)}
                 }
                 if title.shadow_hidden {
-                    <Icon r#type={IconType::ShadowHidden} tooltip="This title is shadowhidden" />
+                    {flag(IconType::ShadowHidden, "shadowhidden", "This title is shadowhidden", text)}
                 }
-            } else if title.votes - title.downvotes < -1 {
-                <Icon r#type={IconType::Downvote} tooltip="This title was removed by the downvotes" />
-            } else if title.votes < 0 {
-                <Icon r#type={IconType::Replaced} tooltip="This title was replaced by the submitter" />
+            } else if title.vote_status == VoteStatus::RemovedByDownvotes {
+                {flag(IconType::Downvote, "downvoted", "This title was removed by the downvotes", text)}
+            } else if title.vote_status == VoteStatus::ReplacedBySubmitter {
+                {flag(IconType::Replaced, "replaced", "This title was replaced by the submitter", text)}
             } else if !title.locked && title.score < 0 {
-                <Icon r#type={IconType::PartiallyHidden} tooltip="This title should only appear in submission menus (score below 0)" />
+                {flag(IconType::PartiallyHidden, "hidden", "This title should only appear in submission menus (score below 0)", text)}
             }
             if title.unverified {
-                <Icon r#type={IconType::Unverified} tooltip="This title was submitted by an unverified user (-1 score)" />
+                {flag(IconType::Unverified, "unverified", "This title was submitted by an unverified user (-1 score)", text)}
             }
             if title.locked {
-                <Icon r#type={IconType::Locked} tooltip="This title was locked by a VIP" />
+                {flag(IconType::Locked, "locked", "This title was locked by a VIP", text)}
             }
             if title.vip {
-                <Icon r#type={IconType::VIP} tooltip="This title was submitted by a VIP" />
+                {flag(IconType::VIP, "VIP", "This title was submitted by a VIP", text)}
+            }
+            if trusted {
+                {flag(IconType::Trusted, "trusted", "This title was submitted by a contributor trusted by this instance", text)}
             }
         </>
     }
 }
 
-fn thumbnail_flags(thumb: &ApiThumbnail) -> Html {
+fn thumbnail_flags(thumb: &ApiThumbnail, text: bool, trusted: bool) -> Html {
     html! {
         <>
             if thumb.votes_missing {
-                <Icon r#type={IconType::VotesMissing} tooltip="Vote data is missing for this thumbnail - this thumbnail is hidden from the extension, attempting to vote on this thumbnail may cause server errors. DAB assumes the value of 0 or false for the missing fields." />
+                {flag(IconType::VotesMissing, "no votes", "Vote data is missing for this thumbnail - this thumbnail is hidden from the extension, attempting to vote on this thumbnail may cause server errors. DAB assumes the value of 0 or false for the missing fields.", text)}
             }
             if thumb.timestamp_missing {
-                <Icon r#type={IconType::TimestampMissing} tooltip="This thumbnail is missing a timestamp despite being a custom thumbnail - this thumbnail will appear glitched in the voting menu of the extension." />
+                {flag(IconType::TimestampMissing, "no timestamp", "This thumbnail is missing a timestamp despite being a custom thumbnail - this thumbnail will appear glitched in the voting menu of the extension.", text)}
             }
             if thumb.removed || thumb.shadow_hidden {
                 if thumb.removed {
-                    <Icon r#type={IconType::Removed} tooltip="This thumbnail was removed by a VIP" />
+                    {flag(IconType::Removed, "removed", "This thumbnail was removed by a VIP", text)}
                 }
                 if thumb.shadow_hidden {
-                    <Icon r#type={IconType::ShadowHidden} tooltip="This thumbnail is shadowhidden" />
+                    {flag(IconType::ShadowHidden, "shadowhidden", "This thumbnail is shadowhidden", text)}
                 }
-            } else if thumb.votes - thumb.downvotes < -1 {
-                <Icon r#type={IconType::Downvote} tooltip="This thumbnail was removed by the downvotes" />
+            } else if thumb.vote_status == VoteStatus::RemovedByDownvotes {
+                {flag(IconType::Downvote, "downvoted", "This thumbnail was removed by the downvotes", text)}
+            } else if thumb.vote_status == VoteStatus::ReplacedBySubmitter {
+                {flag(IconType::Replaced, "replaced", "This thumbnail was replaced by the submitter", text)}
             } else if !thumb.locked {
                 if thumb.original && thumb.score < 1 {
-                    <Icon r#type={IconType::Downvote} tooltip="This original thumbnail has insufficient score to be shown (requires >= 1 or lock)" />
+                    {flag(IconType::Downvote, "low score", "This original thumbnail has insufficient score to be shown (requires >= 1 or lock)", text)}
                 } else if thumb.score < 0 {
-                    <Icon r#type={IconType::PartiallyHidden} tooltip="This thumbnail should only appear in submission menus (score below 0)" />
+                    {flag(IconType::PartiallyHidden, "hidden", "This thumbnail should only appear in submission menus (score below 0)", text)}
                 }
             }
             if thumb.locked {
-                <Icon r#type={IconType::Locked} tooltip="This thumbnail was locked by a VIP" />
+                {flag(IconType::Locked, "locked", "This thumbnail was locked by a VIP", text)}
             }
             if thumb.vip {
-                <Icon r#type={IconType::VIP} tooltip="This thumbnail was submitted by a VIP" />
+                {flag(IconType::VIP, "VIP", "This thumbnail was submitted by a VIP", text)}
+            }
+            if trusted {
+                {flag(IconType::Trusted, "trusted", "This thumbnail was submitted by a contributor trusted by this instance", text)}
             }
         </>
     }
@@ -282,22 +535,32 @@ fn bar_prepender_if_not_empty(html: Html) -> Html {
 }
 
 macro_rules! detail_flags {
-    (thumb, $detail:expr) => {
-        thumbnail_flags($detail)
+    (thumb, $detail:expr, $text:expr, $trusted:expr) => {
+        thumbnail_flags($detail, $text, $trusted)
     };
-    (title, $detail:expr) => {
-        title_flags($detail)
+    (title, $detail:expr, $text:expr, $trusted:expr) => {
+        title_flags($detail, $text, $trusted)
     };
 }
 
 macro_rules! score_col {
-    ($type:tt, $detail:expr, $expanded:expr) => {
+    ($type:tt, $detail:expr, $expanded:expr, $show_breakdown:expr, $text_flags:expr, $trusted:expr) => {
         if $detail.votes_missing {
             html! {
                 <>
                     <em>{"No data"}</em>
                     if $expanded {<br />} else {{" | "}}
-                    {detail_flags!($type, $detail)}
+                    {detail_flags!($type, $detail, $text_flags, $trusted)}
+                </>
+            }
+        } else if $expanded && $show_breakdown {
+            html! {
+                <>
+                    {$detail.score}
+                    <br />
+                    <Icon r#type={IconType::Upvote} />{format!(" {} | {} ", $detail.votes, $detail.downvotes)}<Icon r#type={IconType::Downvote} />
+                    <br />
+                    {detail_flags!($type, $detail, $text_flags, $trusted)}
                 </>
             }
         } else if $expanded {
@@ -308,7 +571,7 @@ macro_rules! score_col {
                         <span><Icon r#type={IconType::Upvote} />{format!(" {} | {} ", $detail.votes, $detail.downvotes)}<Icon r#type={IconType::Downvote} /></span>
                     </span>
                     <br />
-                    {detail_flags!($type, $detail)}
+                    {detail_flags!($type, $detail, $text_flags, $trusted)}
                 </>
             }
 
@@ -316,7 +579,7 @@ macro_rules! score_col {
             html! {
                 <>
                     {format!("{} | ", $detail.score)}<Icon r#type={IconType::Upvote} />{format!(" {} | {} ", $detail.votes, $detail.downvotes)}<Icon r#type={IconType::Downvote} />
-                    {bar_prepender_if_not_empty(detail_flags!($type, $detail))}
+                    {bar_prepender_if_not_empty(detail_flags!($type, $detail, $text_flags, $trusted))}
                 </>
             }
         }
@@ -361,6 +624,7 @@ macro_rules! username_cell {
 struct DetailTableRowProps {
     details: DetailSlice,
     index: usize,
+    layouts: TableLayouts,
     #[prop_or_default]
     pub hide_userid: bool,
     #[prop_or_default]
@@ -377,14 +641,16 @@ fn DetailTableRow(props: &DetailTableRowProps) -> Html {
         use_context().expect("SettingsContext should be available");
     let settings = settings_context.settings();
     let user_context: UserContext = use_context().expect("UserContext should be available");
+    let status: StatusContext = use_context().expect("StatusResponse should be defined");
+    let is_trusted = |user_id: &str| status.as_ref().is_some_and(|s| s.trusted_users.iter().any(|u| &**u == user_id));
+    let tz = use_display_timezone();
     let original_thumb_indicator = html! {
         <Icon r#type={IconType::Original} tooltip="This is the original video thumbnail" />
     };
     let thumb_caption = use_memo((props.details.clone(), props.index), |(details, index)| {
-        let DetailSlice::Thumbnails(ref thumbs) = details else {
+        let DetailRef::Thumbnail(thumb) = details.get(*index) else {
             return ThumbnailCaption::None;
         };
-        let thumb = &thumbs[*index];
         if let Some(timestamp) = thumb.timestamp {
             ThumbnailCaption::Text(format!("{timestamp}").into())
         } else {
@@ -396,11 +662,9 @@ fn DetailTableRow(props: &DetailTableRowProps) -> Html {
         use_callback(
             (props.details.clone(), props.index),
             move |_: MouseEvent, (details, index)| {
-                let detail = match details {
-                    DetailSlice::Titles(ref list) => VotingDetail::Title(list[*index].clone()),
-                    DetailSlice::Thumbnails(ref list) => {
-                        VotingDetail::Thumbnail(list[*index].clone())
-                    }
+                let detail = match details.get(*index) {
+                    DetailRef::Title(t) => VotingDetail::Title(t.clone()),
+                    DetailRef::Thumbnail(t) => VotingDetail::Thumbnail(t.clone()),
                 };
                 modal_controls.emit(ModalMessage::Open(html! {
                     <VotingModal {detail} />
@@ -415,29 +679,37 @@ fn DetailTableRow(props: &DetailTableRowProps) -> Html {
         user_context.is_some().then_some("clickable")
     );
 
-    match props.details {
-        DetailSlice::Titles(ref list) => {
-            let t = &list[props.index];
-            let expanded_layout = settings.title_table_layout == TableLayout::Expanded;
-            let compressed_layout = settings.title_table_layout == TableLayout::Compressed;
+    // merged tables have an extra column indicating the type of each row
+    let merged = matches!(props.details, DetailSlice::All(_));
+
+    match props.details.get(props.index) {
+        DetailRef::Title(t) => {
+            let expanded_layout = props.layouts.title == TableLayout::Expanded;
+            let compressed_layout = props.layouts.title == TableLayout::Compressed;
             let rows = if compressed_layout { "1" } else { "2" };
             let title_column_classes =
                 classes!("title-col", compressed_layout.then_some("compressed"));
             html! {
                 <tr>
-                    <td>{DateTime::from_timestamp_millis(t.time_submitted).map_or_else(|| t.time_submitted.to_string(), render_datetime)}</td>
+                    <td>{DateTime::from_timestamp_millis(t.time_submitted).map_or_else(|| t.time_submitted.to_string(), |dt| render_datetime(dt, tz))}</td>
                     if !props.hide_videoid {
                         <td class="monospaced"><YoutubeVideoLink videoid={t.video_id.clone()} multiline={expanded_layout} /></td>
                     }
+                    if merged {
+                        <td>{"Title"}</td>
+                    }
                     <td class={title_column_classes}>
                         {t.title.clone()}
+                        if let Some(language) = t.language.as_deref().filter(|l| *l != "unknown") {
+                            {" "}<span class="language-tag" title="Detected language of this title">{language}</span>
+                        }
                         if t.original {
                             if expanded_layout { <br /> } else {{""}}
                             <Icon r#type={IconType::Original} tooltip="This is the original video title" />
                         }
                     </td>
                     <td class={score_col_class} onclick={voting_modal_trigger}>
-                        {score_col!(title, t, expanded_layout)}
+                        {score_col!(title, t, expanded_layout, settings.always_show_vote_breakdown, settings.text_flag_labels, is_trusted(&t.user_id))}
                     </td>
                     <td class="monospaced">{uuid_cell!(t.uuid, expanded_layout)}</td>
                     if !props.hide_username {
@@ -449,37 +721,39 @@ fn DetailTableRow(props: &DetailTableRowProps) -> Html {
                 </tr>
             }
         }
-        DetailSlice::Thumbnails(ref list) => {
-            let t = &list[props.index];
-            let expanded_layout = settings.thumbnail_table_layout == TableLayout::Expanded;
-            let compressed_layout = settings.thumbnail_table_layout == TableLayout::Compressed;
+        DetailRef::Thumbnail(t) => {
+            let expanded_layout = props.layouts.thumbnail == TableLayout::Expanded;
+            let compressed_layout = props.layouts.thumbnail == TableLayout::Compressed;
             let rows = if compressed_layout { "1" } else { "2" };
-            let render_thumbnails = settings.render_thumbnails_in_tables && expanded_layout;
+            let render_thumbnails = settings.render_thumbnails_in_tables && expanded_layout
+                && settings.min_thumbnail_score.is_none_or(|min| t.score >= min);
             let onclick = {
-                let list = list.clone();
-                let index = props.index;
+                let video_id = t.video_id.clone();
+                let timestamp = t.timestamp;
                 Callback::from(move |_| {
-                    let t = &list[index];
                     modal_controls.emit(ModalMessage::Open(html! {
-                        <ThumbnailModal video_id={t.video_id.clone()} timestamp={t.timestamp} />
+                        <ThumbnailModal video_id={video_id.clone()} {timestamp} />
                     }));
                 })
             };
             html! {
                 <tr>
-                    <td>{DateTime::from_timestamp_millis(t.time_submitted).map_or_else(|| t.time_submitted.to_string(), render_datetime)}</td>
+                    <td>{DateTime::from_timestamp_millis(t.time_submitted).map_or_else(|| t.time_submitted.to_string(), |dt| render_datetime(dt, tz))}</td>
                     if !props.hide_videoid {
                         <td class="monospaced"><YoutubeVideoLink videoid={t.video_id.clone()} multiline={expanded_layout} /></td>
                     }
+                    if merged {
+                        <td>{"Thumbnail"}</td>
+                    }
                     if t.timestamp_missing {
                         <td><Icon r#type={IconType::TimestampMissing} tooltip="This thumbnail entry is missing a timestamp and cannot be rendered" /></td>
                     } else if render_thumbnails {
-                        <Thumbnail video_id={t.video_id.clone()} timestamp={t.timestamp} caption={(*thumb_caption).clone()} container_type={ContainerType::td} />
+                        <Thumbnail video_id={t.video_id.clone()} timestamp={t.timestamp} caption={(*thumb_caption).clone()} container_type={ContainerType::td} lazy=true size={ThumbnailSize::Table} />
                     } else {
                         <td {onclick} class="clickable">{t.timestamp.map_or(original_thumb_indicator, |ts| html! {{ts.to_string()}})}</td>
                     }
                     <td class={score_col_class} onclick={voting_modal_trigger}>
-                        {score_col!(thumb, t, expanded_layout)}
+                        {score_col!(thumb, t, expanded_layout, settings.always_show_vote_breakdown, settings.text_flag_labels, is_trusted(&t.user_id))}
                     </td>
                     <td class="monospaced">{uuid_cell!(t.uuid, expanded_layout)}</td>
                     if !props.hide_username {
@@ -494,38 +768,147 @@ fn DetailTableRow(props: &DetailTableRowProps) -> Html {
     }
 }
 
+/// Columns shown in a detail table, shared between the table and its [`DetailTableSkeleton`]
+struct DetailTableColumns {
+    kind: DetailTableKind,
+    rendering_thumbnails: bool,
+    hide_userid: bool,
+    hide_username: bool,
+    hide_videoid: bool,
+}
+
+impl DetailTableColumns {
+    fn count(&self) -> usize {
+        4 + usize::from(self.kind == DetailTableKind::All)
+            + usize::from(!self.hide_videoid)
+            + usize::from(!self.hide_username)
+            + usize::from(!self.hide_userid)
+    }
+}
+
+fn detail_table_header(columns: &DetailTableColumns) -> Html {
+    html! {
+        <tr class="header">
+            <th>{"Submitted"}</th>
+            if !columns.hide_videoid {
+                <th>{"Video ID"}</th>
+            }
+            {match columns.kind {
+                DetailTableKind::Titles => html! {
+                    <th class="title-col">{"Title"}</th>
+                },
+                DetailTableKind::Thumbnails => html! {
+                    if columns.rendering_thumbnails {
+                        <th class="thumbnail-header">{"Thumbnail"}</th>
+                    } else {
+                        <th>{"Timestamp"}</th>
+                    }
+                },
+                DetailTableKind::All => html! {
+                    <>
+                        <th>{"Type"}</th>
+                        if columns.rendering_thumbnails {
+                            <th class="thumbnail-header">{"Title / Thumbnail"}</th>
+                        } else {
+                            <th>{"Title / Timestamp"}</th>
+                        }
+                    </>
+                },
+            }}
+            <th class="score-col">{"Score"}</th>
+            <th>{"UUID"}</th>
+            if !columns.hide_username {
+                <th>{"Username"}</th>
+            }
+            if !columns.hide_userid {
+                <th>{"User ID"}</th>
+            }
+        </tr>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DetailTableSkeletonProps {
+    pub kind: DetailTableKind,
+    /// Number of placeholder rows to show
+    #[prop_or(10)]
+    pub rows: usize,
+    #[prop_or_default]
+    pub hide_userid: bool,
+    #[prop_or_default]
+    pub hide_username: bool,
+    #[prop_or_default]
+    pub hide_videoid: bool,
+}
+
+/// Placeholder with the same columns as the detail table being loaded, for use as a `Suspense` fallback
+#[function_component]
+pub fn DetailTableSkeleton(props: &DetailTableSkeletonProps) -> Html {
+    let settings_context: SettingsContext =
+        use_context().expect("SettingsContext should be available");
+    let settings = settings_context.settings();
+    let layouts = use_effective_table_layouts();
+    let columns = DetailTableColumns {
+        kind: props.kind,
+        rendering_thumbnails: settings.render_thumbnails_in_tables
+            && layouts.thumbnail == TableLayout::Expanded,
+        hide_userid: props.hide_userid,
+        hide_username: props.hide_username,
+        hide_videoid: props.hide_videoid,
+    };
+    let (class, layout) = match props.kind {
+        DetailTableKind::Titles => ("titles", layouts.title),
+        DetailTableKind::Thumbnails => ("thumbnails", layouts.thumbnail),
+        DetailTableKind::All => ("merged", layouts.title),
+    };
+    let row_class = classes!(
+        "skeleton-row",
+        (columns.rendering_thumbnails && props.kind != DetailTableKind::Titles).then_some("with-thumbnails"),
+    );
+    let column_count = columns.count();
+
+    html! {
+        <table class={classes!("detail-table", "skeleton", class)} data-layout={AttrValue::Static(layout.into())} aria-busy="true">
+            {detail_table_header(&columns)}
+            { for (0..props.rows).map(|_| html! {
+                <tr class={row_class.clone()}>
+                    { for (0..column_count).map(|_| html! {<td><span class="skeleton-bar" /></td>}) }
+                </tr>
+            }) }
+        </table>
+    }
+}
+
 #[function_component]
 pub fn BaseDetailTableRenderer(props: &BaseDetailTableRendererProps) -> Html {
     let settings_context: SettingsContext =
         use_context().expect("SettingsContext should be available");
     let settings = settings_context.settings();
-    let rendering_thumbnails = settings.render_thumbnails_in_tables
-        && settings.thumbnail_table_layout == TableLayout::Expanded;
+    let layouts = use_effective_table_layouts();
+    let columns = DetailTableColumns {
+        kind: match props.details {
+            DetailSlice::Titles(..) => DetailTableKind::Titles,
+            DetailSlice::Thumbnails(..) => DetailTableKind::Thumbnails,
+            DetailSlice::All(..) => DetailTableKind::All,
+        },
+        rendering_thumbnails: settings.render_thumbnails_in_tables
+            && layouts.thumbnail == TableLayout::Expanded,
+        hide_userid: props.hide_userid,
+        hide_username: props.hide_username,
+        hide_videoid: props.hide_videoid,
+    };
     let row_props = DetailTableRowProps {
         details: props.details.clone(),
         index: 0,
+        layouts,
         hide_userid: props.hide_userid,
         hide_username: props.hide_username,
         hide_videoid: props.hide_videoid,
     };
     match props.details {
         DetailSlice::Titles(ref list) => html! {
-            <table class="detail-table titles" data-layout={AttrValue::Static(settings.title_table_layout.into())}>
-                <tr class="header">
-                    <th>{"Submitted"}</th>
-                    if !props.hide_videoid {
-                        <th>{"Video ID"}</th>
-                    }
-                    <th class="title-col">{"Title"}</th>
-                    <th class="score-col">{"Score"}</th>
-                    <th>{"UUID"}</th>
-                    if !props.hide_username {
-                        <th>{"Username"}</th>
-                    }
-                    if !props.hide_userid {
-                        <th>{"User ID"}</th>
-                    }
-                </tr>
+            <table class="detail-table titles" data-layout={AttrValue::Static(layouts.title.into())}>
+                {detail_table_header(&columns)}
                 { for list.iter().enumerate().map(|(i, t)| {
                     let mut row_props = row_props.clone();
                     row_props.index = i;
@@ -534,26 +917,8 @@ pub fn BaseDetailTableRenderer(props: &BaseDetailTableRendererProps) -> Html {
             </table>
         },
         DetailSlice::Thumbnails(ref list) => html! {
-            <table class="detail-table thumbnails" data-layout={AttrValue::Static(settings.thumbnail_table_layout.into())}>
-                <tr class="header">
-                    <th>{"Submitted"}</th>
-                    if !props.hide_videoid {
-                        <th>{"Video ID"}</th>
-                    }
-                    if rendering_thumbnails {
-                        <th class="thumbnail-header">{"Thumbnail"}</th>
-                    } else {
-                        <th>{"Timestamp"}</th>
-                    }
-                    <th class="score-col">{"Score"}</th>
-                    <th>{"UUID"}</th>
-                    if !props.hide_username {
-                        <th>{"Username"}</th>
-                    }
-                    if !props.hide_userid {
-                        <th>{"User ID"}</th>
-                    }
-                </tr>
+            <table class="detail-table thumbnails" data-layout={AttrValue::Static(layouts.thumbnail.into())}>
+                {detail_table_header(&columns)}
                 { for list.iter().enumerate().map(|(i, t)| {
                     let mut row_props = row_props.clone();
                     row_props.index = i;
@@ -561,6 +926,16 @@ pub fn BaseDetailTableRenderer(props: &BaseDetailTableRendererProps) -> Html {
                 }) }
             </table>
         },
+        DetailSlice::All(ref list) => html! {
+            <table class="detail-table merged" data-layout={AttrValue::Static(layouts.title.into())}>
+                {detail_table_header(&columns)}
+                { for list.iter().enumerate().map(|(i, d)| {
+                    let mut row_props = row_props.clone();
+                    row_props.index = i;
+                    html! { <DetailTableRow key={d.uuid().clone()} ..row_props />}
+                }) }
+            </table>
+        },
     }
 }
 
@@ -568,6 +943,7 @@ pub fn BaseDetailTableRenderer(props: &BaseDetailTableRendererProps) -> Html {
 pub fn UnpaginatedDetailTableRenderer(props: &DetailTableRendererProps) -> HtmlResult {
     let details = use_detail_download(props.url.clone(), props.mode, props.sort)?;
     let detail_slice = use_detail_slice((*details).as_ref().ok().cloned(), DetailIndex::All);
+    let (detail_slice, hidden) = use_original_title_filter(detail_slice, props.hideable_originals);
 
     if let Some(entry_count) = &props.entry_count {
         if let Ok(ref list) = *details {
@@ -587,7 +963,10 @@ pub fn UnpaginatedDetailTableRenderer(props: &DetailTableRendererProps) -> HtmlR
     }
 
     Ok(html! {
-        <BaseDetailTableRenderer details={detail_slice} hide_videoid={props.hide_videoid} hide_userid={props.hide_userid} hide_username={props.hide_username} />
+        <>
+            {hidden_originals_notice(hidden)}
+            <BaseDetailTableRenderer details={detail_slice} hide_videoid={props.hide_videoid} hide_userid={props.hide_userid} hide_username={props.hide_username} />
+        </>
     })
 }
 
@@ -598,15 +977,20 @@ pub fn BasePaginatedDetailTableRenderer(props: &BaseDetailTableRendererProps) ->
     let settings = settings_context.settings();
     let entries_per_page: usize = settings.entries_per_page.into();
     let state = use_location_state().get_state();
+    let (details, hidden) = use_original_title_filter(props.details.clone(), props.hideable_originals);
     let detail_slice = use_detail_slice(
-        Some(props.details.clone()),
-        DetailIndex::Page {
-            size: entries_per_page,
-            index: state.detail_table_page,
+        Some(details.clone()),
+        if state.detail_table_view_all {
+            DetailIndex::All
+        } else {
+            DetailIndex::Page {
+                size: entries_per_page,
+                index: state.detail_table_page,
+            }
         },
     );
 
-    let detail_count = props.details.len();
+    let detail_count = details.len();
     let page_count = detail_count.div_ceil(entries_per_page);
 
     let inner_props = BaseDetailTableRendererProps {
@@ -616,10 +1000,14 @@ pub fn BasePaginatedDetailTableRenderer(props: &BaseDetailTableRendererProps) ->
 
     html! {
         <>
+            {hidden_originals_notice(hidden)}
             <BaseDetailTableRenderer ..{inner_props} />
-            if page_count > 1 {
+            if page_count > 1 && !state.detail_table_view_all {
                 <PageSelect {page_count} />
             }
+            if page_count > 1 || state.detail_table_view_all {
+                <ViewAllToggle entry_count={detail_count} />
+            }
         </>
     }
 }
@@ -647,6 +1035,60 @@ pub fn PaginatedDetailTableRenderer(props: &DetailTableRendererProps) -> HtmlRes
     }
 
     Ok(html! {
-        <BasePaginatedDetailTableRenderer details={detail_slice} hide_videoid={props.hide_videoid} hide_userid={props.hide_userid} hide_username={props.hide_username} />
+        <BasePaginatedDetailTableRenderer details={detail_slice} hide_videoid={props.hide_videoid} hide_userid={props.hide_userid} hide_username={props.hide_username} hideable_originals={props.hideable_originals} />
+    })
+}
+
+#[function_component]
+pub fn PaginatedMergedDetailTableRenderer(props: &MergedDetailTableRendererProps) -> HtmlResult {
+    let details = use_merged_detail_download(props.titles_url.clone(), props.thumbnails_url.clone())?;
+    let detail_slice = use_detail_slice((*details).as_ref().ok().cloned(), DetailIndex::All);
+
+    if let Some(entry_count) = &props.entry_count {
+        if let Ok(ref list) = *details {
+            entry_count.set(Some(list.len()));
+        } else {
+            entry_count.set(None);
+        }
+    }
+
+    if let Err(ref e) = *details {
+        return Ok(html! {
+            <center>
+                <b>{"Failed to fetch details from the API :/"}</b>
+                <pre>{format!("{e:?}")}</pre>
+            </center>
+        });
+    }
+
+    Ok(html! {
+        <BasePaginatedDetailTableRenderer details={detail_slice} hide_videoid={props.hide_videoid} hide_userid={props.hide_userid} hide_username={props.hide_username} hideable_originals={props.hideable_originals} />
+    })
+}
+
+#[function_component]
+pub fn PaginatedSubmissionsDetailTableRenderer(props: &SubmissionsDetailTableRendererProps) -> HtmlResult {
+    let submissions = use_submissions_download(props.url.clone())?;
+
+    if let Some(entry_count) = &props.entry_count {
+        if let Ok(ref slices) = *submissions {
+            entry_count.set(Some(slices.get(props.view).len()));
+        } else {
+            entry_count.set(None);
+        }
+    }
+
+    let slices = match *submissions {
+        Ok(ref slices) => slices,
+        Err(ref e) => return Ok(html! {
+            <center>
+                <b>{"Failed to fetch details from the API :/"}</b>
+                <pre>{format!("{e:?}")}</pre>
+            </center>
+        }),
+    };
+
+    Ok(html! {
+        <BasePaginatedDetailTableRenderer details={slices.get(props.view).clone()} hide_videoid={props.hide_videoid} hide_userid={props.hide_userid} hide_username={props.hide_username} hideable_originals={props.hideable_originals} />
     })
 }
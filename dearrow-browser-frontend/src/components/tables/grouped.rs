@@ -0,0 +1,191 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*  
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::{collections::{HashMap, HashSet}, rc::Rc};
+
+use chrono::DateTime;
+use reqwest::Url;
+use yew::prelude::*;
+
+use crate::components::tables::{details::*, switch::PageSelect};
+use crate::components::youtube::YoutubeVideoLink;
+use crate::contexts::SettingsContext;
+use crate::hooks::{use_display_timezone, use_location_state};
+use crate::utils::{render_datetime, RcEq};
+
+/// Submissions for a single video, in the order they were fetched in
+#[derive(Clone, PartialEq)]
+struct VideoGroup {
+    video_id: Rc<str>,
+    /// Time of the most recent submission in this group
+    latest: i64,
+    /// Highest scoring title, only for title groups
+    best_title: Option<Rc<str>>,
+    details: DetailSlice,
+}
+
+/// Splits a list into per-video lists, ordered by the first occurrence of each video
+fn group_by_video<T: Clone>(list: &[T], video_id: impl Fn(&T) -> &Rc<str>) -> Vec<(Rc<str>, Vec<T>)> {
+    let mut indexes: HashMap<Rc<str>, usize> = HashMap::new();
+    let mut groups: Vec<(Rc<str>, Vec<T>)> = Vec::new();
+    for item in list {
+        let id = video_id(item);
+        let index = *indexes.entry(id.clone()).or_insert_with(|| {
+            groups.push((id.clone(), Vec::new()));
+            groups.len() - 1
+        });
+        groups[index].1.push(item.clone());
+    }
+    groups
+}
+
+fn group_details(details: &DetailSlice) -> Vec<VideoGroup> {
+    match details {
+        DetailSlice::Titles(ref titles) => group_by_video(titles, |t| &t.video_id)
+            .into_iter()
+            .map(|(video_id, titles)| VideoGroup {
+                video_id,
+                latest: titles.iter().map(|t| t.time_submitted).max().unwrap_or_default(),
+                best_title: titles.iter().max_by_key(|t| (t.score, t.time_submitted)).map(|t| t.title.clone()),
+                details: DetailSlice::Titles(RcEq(titles.into())),
+            })
+            .collect(),
+        DetailSlice::Thumbnails(ref thumbs) => group_by_video(thumbs, |t| &t.video_id)
+            .into_iter()
+            .map(|(video_id, thumbs)| VideoGroup {
+                video_id,
+                latest: thumbs.iter().map(|t| t.time_submitted).max().unwrap_or_default(),
+                best_title: None,
+                details: DetailSlice::Thumbnails(RcEq(thumbs.into())),
+            })
+            .collect(),
+        DetailSlice::All(ref items) => group_by_video(items, |d| match d {
+                DetailItem::Title(ref t) => &t.video_id,
+                DetailItem::Thumbnail(ref t) => &t.video_id,
+            })
+            .into_iter()
+            .map(|(video_id, items)| VideoGroup {
+                video_id,
+                latest: items.iter().map(DetailItem::time_submitted).max().unwrap_or_default(),
+                best_title: items.iter()
+                    .filter_map(|d| match d {
+                        DetailItem::Title(ref t) => Some(t),
+                        DetailItem::Thumbnail(_) => None,
+                    })
+                    .max_by_key(|t| (t.score, t.time_submitted))
+                    .map(|t| t.title.clone()),
+                details: DetailSlice::All(RcEq(items.into())),
+            })
+            .collect(),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct GroupedDetailTableRendererProps {
+    pub url: Rc<Url>,
+    pub mode: DetailType,
+    /// Whether original titles may be hidden from this table, if enabled in settings
+    #[prop_or_default]
+    pub hideable_originals: bool,
+}
+
+/// Renders the fetched details grouped by video, one row per video with the submission count and
+/// the best title, expandable to the individual submissions. Paginated by video.
+#[function_component]
+pub fn GroupedDetailTableRenderer(props: &GroupedDetailTableRendererProps) -> HtmlResult {
+    let settings_context: SettingsContext =
+        use_context().expect("SettingsContext should be available");
+    let entries_per_page: usize = settings_context.settings().entries_per_page.into();
+    let state = use_location_state().get_state();
+    let tz = use_display_timezone();
+    let expanded = use_state(HashSet::<Rc<str>>::new);
+
+    let details = use_detail_download(props.url.clone(), props.mode, true)?;
+    let detail_slice = use_detail_slice((*details).as_ref().ok().cloned(), DetailIndex::All);
+    let (detail_slice, _) = use_original_title_filter(detail_slice, props.hideable_originals);
+    let groups = use_memo(detail_slice, group_details);
+
+    if let Err(ref e) = *details {
+        return Ok(html! {
+            <center>
+                <b>{"Failed to fetch details from the API :/"}</b>
+                <pre>{format!("{e:?}")}</pre>
+            </center>
+        });
+    }
+
+    let page_count = groups.len().div_ceil(entries_per_page);
+    let page = groups
+        .iter()
+        .skip(state.detail_table_page * entries_per_page)
+        .take(entries_per_page);
+    let show_titles = props.mode == DetailType::Title;
+
+    Ok(html! {
+        <>
+            <table class="detail-table grouped">
+                <tr class="header">
+                    <th>{"Last submission"}</th>
+                    <th>{"Video ID"}</th>
+                    <th>{"Submissions"}</th>
+                    if show_titles {
+                        <th class="title-col">{"Best title"}</th>
+                    }
+                    <th></th>
+                </tr>
+                { for page.map(|group| {
+                    let is_expanded = expanded.contains(&group.video_id);
+                    let toggle = {
+                        let expanded = expanded.clone();
+                        let video_id = group.video_id.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            let mut new = (*expanded).clone();
+                            if !new.remove(&video_id) {
+                                new.insert(video_id.clone());
+                            }
+                            expanded.set(new);
+                        })
+                    };
+                    html! {
+                        <>
+                            <tr>
+                                <td>{DateTime::from_timestamp_millis(group.latest).map_or_else(|| group.latest.to_string(), |dt| render_datetime(dt, tz))}</td>
+                                <td class="monospaced"><YoutubeVideoLink videoid={group.video_id.clone()} multiline=false /></td>
+                                <td>{group.details.len()}</td>
+                                if show_titles {
+                                    <td class="title-col">{group.best_title.clone().unwrap_or_else(|| "-".into())}</td>
+                                }
+                                <td><span class="clickable" onclick={toggle}>{if is_expanded { "▲ Hide" } else { "▼ Show" }}</span></td>
+                            </tr>
+                            if is_expanded {
+                                <tr class="group-details">
+                                    <td colspan={if show_titles { "5" } else { "4" }}>
+                                        <BaseDetailTableRenderer details={group.details.clone()} hide_videoid=true />
+                                    </td>
+                                </tr>
+                            }
+                        </>
+                    }
+                }) }
+            </table>
+            if page_count > 1 {
+                <PageSelect {page_count} />
+            }
+        </>
+    })
+}
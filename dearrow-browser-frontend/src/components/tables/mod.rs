@@ -17,5 +17,6 @@
 */
 
 pub mod details;
+pub mod grouped;
 pub mod switch;
 pub mod warnings;
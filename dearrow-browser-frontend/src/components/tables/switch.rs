@@ -21,10 +21,12 @@ use std::str::FromStr;
 
 use enumflags2::{bitflags, BitFlags};
 use html::IntoPropValue;
-use web_sys::HtmlInputElement;
+use web_sys::{window, HtmlInputElement};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::components::modals::{legend::IconLegendModal, ModalMessage, ModalRendererControls};
+use crate::constants::VIEW_ALL_WARNING_THRESHOLD;
 use crate::hooks::use_location_state;
 use crate::pages::LocationState;
 use crate::pages::MainRoute;
@@ -36,6 +38,8 @@ pub enum TableMode {
     #[default]
     Titles,
     Thumbnails,
+    /// Titles and thumbnails merged into a single timeline
+    All,
     WarningsReceived,
     WarningsIssued,
 }
@@ -55,6 +59,8 @@ impl TryFrom<TableMode> for DetailType {
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub enum ModeSubtype {
     Details,
+    /// Enables the "All" tab, merging titles and thumbnails
+    Combined,
     Warnings,
 }
 
@@ -63,6 +69,7 @@ pub enum ModeSubtype {
 #[derive(PartialEq, Eq, Clone, Copy)]
 enum InternalModeSubtype {
     Details,
+    Combined,
     Warnings,
 }
 
@@ -75,6 +82,9 @@ impl EnabledSubtypes {
     fn details(self) -> bool {
         self.inner.contains(InternalModeSubtype::Details)
     }
+    fn combined(self) -> bool {
+        self.inner.contains(InternalModeSubtype::Combined)
+    }
     fn warnings(self) -> bool {
         self.inner.contains(InternalModeSubtype::Warnings)
     }
@@ -86,6 +96,9 @@ impl From<ModeSubtype> for EnabledSubtypes {
             ModeSubtype::Details => Self {
                 inner: InternalModeSubtype::Details.into(),
             },
+            ModeSubtype::Combined => Self {
+                inner: InternalModeSubtype::Combined.into(),
+            },
             ModeSubtype::Warnings => Self {
                 inner: InternalModeSubtype::Warnings.into(),
             },
@@ -146,8 +159,10 @@ pub struct TableModeSwitch {
 
     set_titles_mode_cb: Callback<MouseEvent>,
     set_thumbs_mode_cb: Callback<MouseEvent>,
+    set_all_mode_cb: Callback<MouseEvent>,
     set_warnings_received_mode_cb: Callback<MouseEvent>,
     set_warnings_issued_mode_cb: Callback<MouseEvent>,
+    open_legend_cb: Callback<MouseEvent>,
 
     _location_handle: LocationHandle,
 }
@@ -155,6 +170,7 @@ pub struct TableModeSwitch {
 pub enum TableModeSwitchMessage {
     UpdateMode(TableMode),
     LocationUpdated(Location),
+    OpenLegend,
 }
 
 impl TableModeSwitch {
@@ -163,6 +179,7 @@ impl TableModeSwitch {
         let scope = ctx.link();
         match state.detail_table_mode {
             TableMode::Titles | TableMode::Thumbnails if props.types.details() => state,
+            TableMode::All if props.types.details() && props.types.combined() => state,
             TableMode::WarningsReceived | TableMode::WarningsIssued if props.types.warnings() => {
                 state
             }
@@ -211,10 +228,13 @@ impl Component for TableModeSwitch {
                 .callback(|_| TableModeSwitchMessage::UpdateMode(TableMode::Titles)),
             set_thumbs_mode_cb: scope
                 .callback(|_| TableModeSwitchMessage::UpdateMode(TableMode::Thumbnails)),
+            set_all_mode_cb: scope
+                .callback(|_| TableModeSwitchMessage::UpdateMode(TableMode::All)),
             set_warnings_received_mode_cb: scope
                 .callback(|_| TableModeSwitchMessage::UpdateMode(TableMode::WarningsReceived)),
             set_warnings_issued_mode_cb: scope
                 .callback(|_| TableModeSwitchMessage::UpdateMode(TableMode::WarningsIssued)),
+            open_legend_cb: scope.callback(|_| TableModeSwitchMessage::OpenLegend),
 
             _location_handle: scope
                 .add_location_listener(scope.callback(TableModeSwitchMessage::LocationUpdated))
@@ -228,6 +248,9 @@ impl Component for TableModeSwitch {
                 if ctx.props().types.details() {
                     <span class="table-mode button" onclick={&self.set_titles_mode_cb} selected={self.current_mode == TableMode::Titles}>{"Titles"}</span>
                     <span class="table-mode button" onclick={&self.set_thumbs_mode_cb} selected={self.current_mode == TableMode::Thumbnails}>{"Thumbnails"}</span>
+                    if ctx.props().types.combined() {
+                        <span class="table-mode button" onclick={&self.set_all_mode_cb} selected={self.current_mode == TableMode::All}>{"All"}</span>
+                    }
                 }
                 if ctx.props().types.warnings() {
                     <span class="table-mode button" onclick={&self.set_warnings_received_mode_cb} selected={self.current_mode == TableMode::WarningsReceived}>{"Warnings received"}</span>
@@ -242,6 +265,9 @@ impl Component for TableModeSwitch {
                         }
                     </span>
                 }
+                if ctx.props().types.details() {
+                    <span class="legend-button button" onclick={&self.open_legend_cb} title="Explain the icons used in this table">{"?"}</span>
+                }
             </div>
         }
     }
@@ -283,6 +309,8 @@ impl Component for TableModeSwitch {
                     .copied()
                     .unwrap_or_default();
                 state.detail_table_mode = new_mode;
+                // the other table may be much larger, don't render all of it without asking
+                state.detail_table_view_all = false;
                 scope
                     .navigator()
                     .unwrap()
@@ -290,6 +318,12 @@ impl Component for TableModeSwitch {
                 true
             }
             TableModeSwitchMessage::UpdateMode(..) => false,
+            TableModeSwitchMessage::OpenLegend => {
+                if let Some((modal_controls, _)) = ctx.link().context::<ModalRendererControls>(Callback::noop()) {
+                    modal_controls.emit(ModalMessage::Open(html! { <IconLegendModal /> }));
+                }
+                false
+            }
         }
     }
 }
@@ -333,7 +367,7 @@ pub fn PageSelect(props: &PageSelectProps) -> Html {
                     state.detail_table_page = new_page.clamp(1, page_count) - 1;
                     state_handle.replace_state(state);
                 }
-            };
+            }
             input.set_value(&format!("{}", state.detail_table_page + 1));
         })
     };
@@ -350,3 +384,44 @@ pub fn PageSelect(props: &PageSelectProps) -> Html {
         </div>
     }
 }
+
+#[derive(Properties, PartialEq)]
+pub struct ViewAllToggleProps {
+    pub entry_count: usize,
+}
+
+/// Switches a paginated table between showing a single page and all entries at once, for
+/// printing or saving the whole table
+#[function_component]
+pub fn ViewAllToggle(props: &ViewAllToggleProps) -> Html {
+    let state_handle = use_location_state();
+    let state = state_handle.get_state();
+
+    let onclick = {
+        let entry_count = props.entry_count;
+        Callback::from(move |_| {
+            let view_all = !state.detail_table_view_all;
+            if view_all && entry_count > VIEW_ALL_WARNING_THRESHOLD {
+                let message = format!("This table has {entry_count} entries, rendering all of them at once may make the page slow or unresponsive. Continue?");
+                let confirmed = window().and_then(|w| w.confirm_with_message(&message).ok()).unwrap_or(false);
+                if !confirmed {
+                    return;
+                }
+            }
+            state_handle.replace_state(LocationState {
+                detail_table_view_all: view_all,
+                ..state
+            });
+        })
+    };
+
+    html! {
+        <div class="view-all-toggle">
+            if state.detail_table_view_all {
+                <div class="button" {onclick}>{"Show pages"}</div>
+            } else {
+                <div class="button" {onclick} title="Show all entries on a single page, for printing or saving the whole table">{"View all"}</div>
+            }
+        </div>
+    }
+}
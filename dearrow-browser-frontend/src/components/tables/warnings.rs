@@ -16,7 +16,7 @@
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::{num::NonZeroUsize, rc::Rc};
+use std::{cmp::Reverse, num::NonZeroUsize, rc::Rc};
 
 use chrono::DateTime;
 use dearrow_browser_api::unsync::{ApiWarning, Extension};
@@ -28,6 +28,7 @@ use yew_router::prelude::*;
 use crate::{
     components::{links::userid_link, tables::switch::PageSelect},
     contexts::SettingsContext,
+    hooks::{use_async_suspension, use_display_timezone, use_location_state},
     pages::LocationState,
     utils::{api_request, render_datetime},
 };
@@ -46,13 +47,14 @@ struct WarningRowProps {
 #[function_component]
 fn WarningRow(props: &WarningRowProps) -> Html {
     let warning = &props.warnings[props.index];
-    let timestamp = use_memo(warning.time_issued, |timestamp| {
+    let tz = use_display_timezone();
+    let timestamp = use_memo((warning.time_issued, tz), |(timestamp, tz)| {
         DateTime::from_timestamp_millis(*timestamp)
-            .map_or_else(|| timestamp.to_string(), render_datetime)
+            .map_or_else(|| timestamp.to_string(), |dt| render_datetime(dt, *tz))
     });
-    let extension = match warning.extension {
-        Extension::DeArrow => "for DeArrow",
-        Extension::SponsorBlock => "for SponsorBlock",
+    let (extension, extension_class) = match warning.extension {
+        Extension::DeArrow => ("for DeArrow", "dearrow"),
+        Extension::SponsorBlock => ("for SponsorBlock", "sponsorblock"),
     };
     let status = if warning.active {
         "Active"
@@ -60,10 +62,10 @@ fn WarningRow(props: &WarningRowProps) -> Html {
         "Acknowledged"
     };
     html! {
-        <tr>
+        <tr class={extension_class}>
             <td>
                 {timestamp}<br/>
-                {extension}<br/>
+                <span class="warning-extension">{extension}</span><br/>
                 {status}
             </td>
             <td class="warning-message-col"><pre>{warning.message.clone()}</pre></td>
@@ -141,14 +143,92 @@ pub struct WarningsTableProps {
     pub hide_receiver: bool,
 }
 
+#[derive(Clone, Copy, PartialEq, Default)]
+struct ExtensionCounts {
+    dearrow: usize,
+    sponsorblock: usize,
+}
+
 #[derive(Clone)]
 struct PaginatedWarnings {
     full: Rc<[ApiWarning]>,
+    /// Warnings matching the extension filter
+    filtered: Rc<[ApiWarning]>,
     page: Rc<[ApiWarning]>,
+    counts: ExtensionCounts,
+}
+
+impl PaginatedWarnings {
+    fn new(full: Rc<[ApiWarning]>, extension: Option<Extension>, entries_per_page: NonZeroUsize, current_page: usize) -> Self {
+        let filtered: Rc<[ApiWarning]> = match extension {
+            None => full.clone(),
+            Some(extension) => full.iter().filter(|w| w.extension == extension).cloned().collect(),
+        };
+        let dearrow = full.iter().filter(|w| w.extension == Extension::DeArrow).count();
+        let entries: usize = entries_per_page.into();
+        let start = (current_page * entries).min(filtered.len());
+        let end = (start + entries).min(filtered.len());
+        PaginatedWarnings {
+            page: filtered[start..end].into(),
+            counts: ExtensionCounts { dearrow, sponsorblock: full.len() - dearrow },
+            filtered,
+            full,
+        }
+    }
+
+    fn page_count(&self, entries_per_page: NonZeroUsize) -> usize {
+        self.filtered.len().div_ceil(entries_per_page.into())
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ExtensionFilterProps {
+    counts: ExtensionCounts,
+    current: Option<Extension>,
+    onchange: Callback<Option<Extension>>,
+}
+
+/// Buttons for filtering warnings by extension, with the amount of warnings for each
+#[function_component]
+fn ExtensionFilter(props: &ExtensionFilterProps) -> Html {
+    let location_state = use_location_state();
+    let button = |extension: Option<Extension>, label: &str, count: usize| {
+        let onclick = {
+            let location_state = location_state.clone();
+            let onchange = props.onchange.clone();
+            let changed = props.current != extension;
+            Callback::from(move |_: MouseEvent| {
+                if changed {
+                    // the current page may not exist anymore
+                    let state = location_state.get_state();
+                    location_state.replace_state(LocationState { detail_table_page: 0, ..state });
+                    onchange.emit(extension);
+                }
+            })
+        };
+        let class = match extension {
+            None => "all",
+            Some(Extension::DeArrow) => "dearrow",
+            Some(Extension::SponsorBlock) => "sponsorblock",
+        };
+        html! {
+            <button class={classes!(class, (props.current == extension).then_some("selected"))} {onclick}>
+                {format!("{label} ({count})")}
+            </button>
+        }
+    };
+    html! {
+        <div class="warning-extension-filter">
+            {button(None, "All", props.counts.dearrow + props.counts.sponsorblock)}
+            {button(Some(Extension::DeArrow), "DeArrow", props.counts.dearrow)}
+            {button(Some(Extension::SponsorBlock), "SponsorBlock", props.counts.sponsorblock)}
+        </div>
+    }
 }
 
 pub struct PaginatedWarningsTable {
     warnings: Option<Result<PaginatedWarnings, ErrorContext>>,
+    extension: Option<Extension>,
     entries_per_page: NonZeroUsize,
     current_page: usize,
 
@@ -167,9 +247,18 @@ pub enum PaginatedWarningsTableMessage {
     LocationStateUpdated {
         current_page: usize,
     },
+    ExtensionFilterUpdated {
+        extension: Option<Extension>,
+    },
 }
 
 impl PaginatedWarningsTable {
+    fn repaginate(&mut self) {
+        if let Some(Ok(ref mut warnings)) = self.warnings {
+            *warnings = PaginatedWarnings::new(warnings.full.clone(), self.extension, self.entries_per_page, self.current_page);
+        }
+    }
+
     async fn download_warnings(url: Rc<Url>) -> PaginatedWarningsTableMessage {
         PaginatedWarningsTableMessage::WarningsFetched {
             url: url.clone(),
@@ -213,6 +302,7 @@ impl Component for PaginatedWarningsTable {
 
         Self {
             warnings: None,
+            extension: None,
             entries_per_page: settings.settings().entries_per_page,
             current_page,
 
@@ -240,10 +330,12 @@ impl Component for PaginatedWarningsTable {
                 </center>
             },
             Some(Ok(warnings)) => {
-                let page_count = (warnings.full.len() / self.entries_per_page) + 1;
+                let page_count = warnings.page_count(self.entries_per_page);
                 let props = ctx.props();
+                let onchange = ctx.link().callback(|extension| PaginatedWarningsTableMessage::ExtensionFilterUpdated { extension });
                 html! {
                     <>
+                        <ExtensionFilter counts={warnings.counts} current={self.extension} {onchange} />
                         <BaseWarningsTable warnings={warnings.page.clone()} hide_issuer={props.hide_issuer} hide_receiver={props.hide_receiver} />
                         if page_count > 1 {
                             <PageSelect {page_count} />
@@ -274,6 +366,7 @@ impl Component for PaginatedWarningsTable {
                     false
                 } else {
                     self.entries_per_page = entries_per_page;
+                    self.repaginate();
                     true
                 }
             }
@@ -283,41 +376,109 @@ impl Component for PaginatedWarningsTable {
                     return false;
                 }
                 self.warnings = Some(warnings.map(|warnings| {
+                    let warnings = PaginatedWarnings::new(warnings, self.extension, self.entries_per_page, self.current_page);
                     if let Some(handle) = &props.entry_count {
-                        handle.set(Some(warnings.len()));
-                    }
-                    let entries: usize = self.entries_per_page.into();
-                    let page: usize = self.current_page;
-                    if warnings.len() >= entries {
-                        PaginatedWarnings {
-                            full: warnings.clone(),
-                            page: warnings,
-                        }
-                    } else if warnings.len() <= (page + 1) * entries {
-                        PaginatedWarnings {
-                            page: warnings.get(page * entries..).unwrap_or_default().into(),
-                            full: warnings,
-                        }
-                    } else {
-                        PaginatedWarnings {
-                            page: warnings
-                                .get(page * entries..(page + 1) * entries)
-                                .unwrap_or_default()
-                                .into(),
-                            full: warnings,
-                        }
+                        handle.set(Some(warnings.filtered.len()));
                     }
+                    warnings
                 }));
                 true
             }
+            PaginatedWarningsTableMessage::ExtensionFilterUpdated { extension } => {
+                if extension == self.extension {
+                    return false;
+                }
+                self.extension = extension;
+                self.repaginate();
+                if let (Some(Ok(warnings)), Some(handle)) = (&self.warnings, &ctx.props().entry_count) {
+                    handle.set(Some(warnings.filtered.len()));
+                }
+                true
+            }
             PaginatedWarningsTableMessage::LocationStateUpdated { current_page } => {
                 if current_page == self.current_page {
                     false
                 } else {
                     self.current_page = current_page;
+                    self.repaginate();
                     true
                 }
             }
         }
     }
 }
+
+fn render_gap(millis: i64) -> String {
+    let minutes = millis / 60_000;
+    match minutes {
+        ..1 => "less than a minute".to_owned(),
+        1..60 => format!("{minutes} min"),
+        60..1440 => format!("{} h", minutes / 60),
+        _ => format!("{} days", minutes / 1440),
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct WarningsTimelineProps {
+    pub url: Rc<Url>,
+}
+
+/// Renders warnings as a timeline, most recently issued first, with the time between them.
+///
+/// The database dumps don't include acknowledgement times, so warnings can only be told apart
+/// as still active (unacknowledged) or not.
+#[function_component]
+pub fn WarningsTimeline(props: &WarningsTimelineProps) -> HtmlResult {
+    let tz = use_display_timezone();
+    let warnings = use_async_suspension(
+        |url| async move {
+            let mut warnings: Vec<ApiWarning> = api_request((*url).clone()).await?;
+            warnings.sort_unstable_by_key(|w| Reverse(w.time_issued));
+            Ok::<_, ErrorContext>(warnings)
+        },
+        props.url.clone(),
+    )?;
+
+    let warnings = match *warnings {
+        Err(ref e) => {
+            return Ok(html! {
+                <center>
+                    <b>{"Failed to fetch warnings from the API :/"}</b>
+                    <pre>{format!("{e:?}")}</pre>
+                </center>
+            })
+        }
+        Ok(ref warnings) if warnings.is_empty() => return Ok(html! {}),
+        Ok(ref warnings) => warnings,
+    };
+    let active_count = warnings.iter().filter(|w| w.active).count();
+
+    Ok(html! {
+        <details class="warnings-timeline">
+            <summary>{format!("Timeline: {active_count} active, {} acknowledged", warnings.len() - active_count)}</summary>
+            <ol>
+                { for warnings.iter().enumerate().map(|(i, warning)| {
+                    let (extension, extension_class) = match warning.extension {
+                        Extension::DeArrow => ("DeArrow", "dearrow"),
+                        Extension::SponsorBlock => ("SponsorBlock", "sponsorblock"),
+                    };
+                    let gap = warnings.get(i + 1).map(|previous| warning.time_issued - previous.time_issued);
+                    html! {
+                        <li key={warning.time_issued} class={classes!(extension_class, warning.active.then_some("active"))}>
+                            <span class="timeline-extension">{extension}</span>
+                            {DateTime::from_timestamp_millis(warning.time_issued).map_or_else(|| warning.time_issued.to_string(), |dt| render_datetime(dt, tz))}
+                            if warning.active {
+                                <b>{" - active, not acknowledged yet"}</b>
+                            } else {
+                                {" - acknowledged"}
+                            }
+                            if let Some(gap) = gap {
+                                <br/><em>{format!("{} after the previous warning", render_gap(gap))}</em>
+                            }
+                        </li>
+                    }
+                }) }
+            </ol>
+        </details>
+    })
+}
@@ -22,8 +22,10 @@ use yew::prelude::*;
 
 use crate::components::links::videoid_link;
 use crate::constants::YOUTUBE_EMBED_URL;
+use crate::contexts::SettingsContext;
 use crate::hooks::use_async_suspension;
 use crate::innertube::{self, youtu_be_link};
+use crate::thumbnails::components::{ThumbnailSize, UnwrappedThumbnail};
 use crate::utils::ReqwestUrlExt;
 
 #[derive(Properties, PartialEq, Clone)]
@@ -33,13 +35,31 @@ pub struct YoutubeProps {
 
 #[function_component]
 pub fn YoutubeIframe(props: &YoutubeProps) -> Html {
+    let settings_context: SettingsContext = use_context().expect("SettingsContext should be defined");
+    // remember which video the embed was requested for, so that navigating to another video
+    // doesn't load its embed right away
+    let clicked_video = use_state_eq(|| None::<AttrValue>);
     let embed_url: Rc<AttrValue> = use_memo(props.videoid.clone(), |vid| {
         let mut url = YOUTUBE_EMBED_URL.clone();
         url.extend_segments(&[vid]).unwrap();
         AttrValue::Rc(url.as_str().into())
     });
+    let video_id: Rc<Rc<str>> = use_memo(props.videoid.clone(), |vid| vid.as_str().into());
+    let load_embed = {
+        let clicked_video = clicked_video.clone();
+        use_callback(props.videoid.clone(), move |_: MouseEvent, vid| clicked_video.set(Some(vid.clone())))
+    };
 
-    html! {<iframe src={&*embed_url} allowfullscreen=true />}
+    if !settings_context.settings().lazy_video_embed || clicked_video.as_ref() == Some(&props.videoid) {
+        html! {<iframe src={&*embed_url} allowfullscreen=true />}
+    } else {
+        html! {
+            <div class="thumbnail-container clickable" onclick={load_embed} title="Click to load the YouTube embed">
+                <UnwrappedThumbnail video_id={(*video_id).clone()} timestamp={None} size={ThumbnailSize::Page} />
+                <span class="thumbnail-caption"><span>{"Click to load the video"}</span></span>
+            </div>
+        }
+    }
 }
 
 #[function_component]
@@ -25,6 +25,12 @@ use crate::built_info;
 
 pub static REQWEST_CLIENT: LazyLock<Client> = LazyLock::new(Client::new);
 pub const ASYNC_TASK_AUTO_DISMISS_DELAY: Duration = Duration::from_secs(15);
+/// Media query matching viewports narrow enough to force the compressed table layout
+pub const MOBILE_LAYOUT_QUERY: &str = "(max-width: 800px)";
+/// Amount of recent submissions fetched for the home page when grouping by video
+pub const GROUPED_HOMEPAGE_FETCH_COUNT: usize = 1000;
+/// Tables with more entries ask for confirmation before rendering all of them at once
+pub const VIEW_ALL_WARNING_THRESHOLD: usize = 2000;
 
 // Data based on build-time constants
 
@@ -41,6 +47,12 @@ pub static THUMBNAIL_URL:      LazyLock<Url> = LazyLock::new(|| Url::parse("http
 pub static SBB_BASE:           LazyLock<Url> = LazyLock::new(|| Url::parse("https://sb.ltn.fi/").expect("should be able to parse sb.ltn.fi base URL"));
 pub const SBS_BRANDING_ENDPOINT: &[&str]     = &["api", "branding"];
 
+// "Now playing" messages
+
+pub const NOW_PLAYING_MESSAGE_TYPE: &str = "dearrow-browser-now-playing";
+/// Origins allowed to send "now playing" messages, in addition to the origin of the app itself
+pub const NOW_PLAYING_TRUSTED_ORIGINS: &[&str] = &["https://www.youtube.com", "https://m.youtube.com"];
+
 // Regexes
 
 pub static UUID_REGEX:     LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9a-f]{8}\-(?:[0-9a-f]{4}-){3}[0-9a-f]{12}$").expect("UUID_REGEX should be valid"));
@@ -21,25 +21,31 @@ use dearrow_browser_api::unsync::{StatusResponse, User};
 use cloneable_errors::ErrorContext;
 use gloo_console::error;
 use reqwest::Url;
+use wasm_bindgen::closure::Closure;
+use web_sys::{js_sys::Reflect, window, MessageEvent};
 use yew::prelude::*;
 use yew_hooks::{use_local_storage, UseLocalStorageHandle};
 
 pub use crate::components::modals::{ModalRendererControls, ModalMessage};
 pub use crate::components::async_task_manager::{AsyncTaskControl, AsyncTaskList};
 use crate::{settings::Settings, utils::{api_request, sponsorblock_hash, ReqwestUrlExt}};
+use crate::constants::{NOW_PLAYING_MESSAGE_TYPE, NOW_PLAYING_TRUSTED_ORIGINS, VIDEO_ID_REGEX};
+use crate::thumbnails::utils::EventListener;
 
 #[derive(Clone, PartialEq)]
 pub struct WindowContext {
     pub origin: Url,
+    /// Base URL of the server to load data from, same as `origin` unless overridden in settings
+    pub api_base: Url,
 }
 
 impl WindowContext {
     #[must_use]
-    pub fn origin_join_segments<I>(&self, segments: I) -> Url
+    pub fn api_join_segments<I>(&self, segments: I) -> Url
     where I: IntoIterator,
     I::Item: AsRef<str>,
     {
-        self.origin.join_segments(segments).expect("WindowContext.origin should be a valid base")
+        self.api_base.join_segments(segments).expect("WindowContext.api_base should be a valid base")
     }
 }
 
@@ -48,6 +54,10 @@ pub struct UpdateClock(pub bool);
 
 pub type StatusContext = Option<Rc<StatusResponse>>;
 
+/// Refreshes the [`StatusContext`] snapshot when emitted, set only if the server has loaded newer
+/// data than the snapshot
+pub type NewerDataContext = Option<Callback<()>>;
+
 #[derive(Clone, PartialEq)]
 pub struct SettingsContext {
     pub storage: UseLocalStorageHandle<Settings>,
@@ -80,13 +90,109 @@ pub fn SettingsProvider(props: &ContextProviderProps) -> Html {
 
     html! {
         <ContextProvider<SettingsContext> {context}>
-            <UserContextProvider>
-                {props.children.clone()}
-            </UserContextProvider>
+            <StatusSnapshotProvider>
+                <UserContextProvider>
+                    {props.children.clone()}
+                </UserContextProvider>
+            </StatusSnapshotProvider>
         </ContextProvider<SettingsContext>>
     }
 }
 
+/// Replaces the [`StatusContext`] with a snapshot that only follows the latest status until the
+/// server loads new data, unless auto refreshes are enabled in settings.
+///
+/// Everything keyed on `last_updated` is then only refetched after emitting the [`NewerDataContext`].
+#[function_component]
+fn StatusSnapshotProvider(props: &ContextProviderProps) -> Html {
+    let latest: StatusContext = use_context().expect("StatusContext should be defined");
+    let settings_context: SettingsContext = use_context().expect("StatusSnapshotProvider should be placed inside a SettingsProvider");
+    let auto_refresh = settings_context.settings().auto_refresh_data;
+    let snapshot = use_mut_ref(|| None::<Rc<StatusResponse>>);
+    let force_update = use_force_update();
+
+    let refresh = use_callback((snapshot.clone(), latest.clone()), move |(): (), (snapshot, latest)| {
+        snapshot.borrow_mut().clone_from(latest);
+        force_update.force_update();
+    });
+    {
+        let mut snapshot = snapshot.borrow_mut();
+        let same_data = snapshot.as_ref().zip(latest.as_ref()).is_none_or(|(s, l)| s.last_updated == l.last_updated);
+        if auto_refresh || same_data {
+            snapshot.clone_from(&latest);
+        }
+    }
+    let snapshot = snapshot.borrow().clone();
+    let newer_data = snapshot.as_ref().zip(latest.as_ref())
+        .is_some_and(|(s, l)| s.last_updated != l.last_updated)
+        .then_some(refresh);
+
+    html! {
+        <ContextProvider<StatusContext> context={snapshot}>
+        <ContextProvider<NewerDataContext> context={newer_data}>
+            {props.children.clone()}
+        </ContextProvider<NewerDataContext>>
+        </ContextProvider<StatusContext>>
+    }
+}
+
+/// ID of the video currently playing in a companion userscript or extension
+pub type NowPlayingContext = Option<AttrValue>;
+
+/// Extracts the video ID from a "now playing" message, if it came from a trusted origin
+fn parse_now_playing_message(event: &MessageEvent, own_origin: &str) -> Option<String> {
+    let origin = event.origin();
+    if origin != own_origin && !NOW_PLAYING_TRUSTED_ORIGINS.contains(&origin.as_str()) {
+        return None;
+    }
+    let data = event.data();
+    if Reflect::get(&data, &"type".into()).ok()?.as_string()? != NOW_PLAYING_MESSAGE_TYPE {
+        return None;
+    }
+    let video_id = Reflect::get(&data, &"videoId".into()).ok()?.as_string()?;
+    VIDEO_ID_REGEX.is_match(&video_id).then_some(video_id)
+}
+
+/// Listens for "now playing" messages sent via `window.postMessage()`, if enabled in settings.
+///
+/// The message data must be an object of the following shape:
+/// ```js
+/// { type: "dearrow-browser-now-playing", videoId: "dQw4w9WgXcQ" }
+/// ```
+/// Messages are only accepted from the origin of the app itself and the origins
+/// listed in [`NOW_PLAYING_TRUSTED_ORIGINS`]. Anything else is silently ignored.
+#[function_component]
+pub fn NowPlayingProvider(props: &ContextProviderProps) -> Html {
+    let settings_context: SettingsContext = use_context().expect("NowPlayingProvider should be placed inside a SettingsProvider");
+    let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
+    let enabled = settings_context.settings().now_playing_listener;
+    let now_playing = use_state_eq(|| None);
+    {
+        let now_playing = now_playing.clone();
+        use_effect_with((enabled, window_context), move |(enabled, window_context)| {
+            let listener = if *enabled {
+                let own_origin = window_context.origin.origin().ascii_serialization();
+                let now_playing = now_playing.clone();
+                window().and_then(|window| EventListener::new(&window, "message", Closure::<dyn Fn(MessageEvent)>::new(move |event: MessageEvent| {
+                    if let Some(video_id) = parse_now_playing_message(&event, &own_origin) {
+                        now_playing.set(Some(AttrValue::from(video_id)));
+                    }
+                })).ok())
+            } else {
+                now_playing.set(None);
+                None
+            };
+            move || drop(listener)
+        });
+    }
+
+    html! {
+        <ContextProvider<NowPlayingContext> context={(*now_playing).clone()}>
+            {props.children.clone()}
+        </ContextProvider<NowPlayingContext>>
+    }
+}
+
 pub type UserContext = Option<UserContextData>;
 #[derive(Clone, PartialEq)]
 pub struct UserContextData {
@@ -112,7 +218,7 @@ enum UserContextProviderMessage {
 }
 
 async fn fetch_user_data(window_context: Rc<WindowContext>, public_id: Rc<str>) -> UserContextProviderMessage {
-    let url = window_context.origin_join_segments(&["api", "users", "user_id", &public_id]);
+    let url = window_context.api_join_segments(&["api", "users", "user_id", &public_id]);
     UserContextProviderMessage::UserLookupFinished { 
         public_user_id: public_id,
         result: api_request::<_, User>(url).await
@@ -16,12 +16,19 @@
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 use std::{future::Future, cell::{RefCell, Cell}, rc::Rc};
+use wasm_bindgen::closure::Closure;
+use web_sys::window;
 use yew::prelude::*;
 use yew::platform::spawn_local;
 use yew::suspense::{SuspensionResult, Suspension};
 use yew_router::prelude::*;
 
+use crate::constants::MOBILE_LAYOUT_QUERY;
+use crate::contexts::SettingsContext;
 use crate::pages::{LocationState, MainRoute};
+use crate::settings::TableLayout;
+use crate::utils::DisplayTimezone;
+use crate::thumbnails::utils::EventListener;
 
 
 enum UseAsyncSuspensionState<R>
@@ -74,7 +81,7 @@ pub struct IterationResult<R, S> {
 
 /// A hook that runs an async function in a loop.
 ///
-/// The function will keep getting called until either it returns LoopControl::Terminate, or the
+/// The function will keep getting called until either it returns `LoopControl::Terminate`, or the
 /// dependencies change.
 #[hook]
 pub fn use_async_loop<FF, F, D, R, S>(future: FF, deps: D) -> UseStateHandle<R>
@@ -170,3 +177,56 @@ pub fn use_location_state() -> LocationStateHandle {
         navigator, route, location
     }
 }
+
+/// A hook that tracks whether a CSS media query currently matches the viewport.
+#[hook]
+pub fn use_media_query(query: &'static str) -> bool {
+    let media_query_list = use_memo(query, |query| window().and_then(|w| w.match_media(query).ok().flatten()));
+    let matches = use_state_eq(|| media_query_list.as_ref().as_ref().is_some_and(web_sys::MediaQueryList::matches));
+    {
+        let matches = matches.clone();
+        use_effect_with(media_query_list, move |media_query_list| {
+            let listener = media_query_list.as_ref().as_ref().and_then(|mql| {
+                matches.set(mql.matches());
+                let mql_clone = mql.clone();
+                EventListener::new(mql, "change", Closure::<dyn Fn()>::new(move || matches.set(mql_clone.matches()))).ok()
+            });
+            move || drop(listener)
+        });
+    }
+    *matches
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TableLayouts {
+    pub title: TableLayout,
+    pub thumbnail: TableLayout,
+}
+
+/// Returns the timezone in which timestamps should be rendered
+#[hook]
+pub fn use_display_timezone() -> DisplayTimezone {
+    let settings_context: SettingsContext = use_context().expect("SettingsContext should be available");
+    settings_context.settings().display_timezone()
+}
+
+/// Returns the table layouts that should be used for rendering.
+///
+/// These match the user's settings, except on narrow viewports (see [`MOBILE_LAYOUT_QUERY`]),
+/// where the compressed layout is always used.
+#[hook]
+pub fn use_effective_table_layouts() -> TableLayouts {
+    let settings_context: SettingsContext = use_context().expect("SettingsContext should be available");
+    let settings = settings_context.settings();
+    if use_media_query(MOBILE_LAYOUT_QUERY) {
+        TableLayouts {
+            title: TableLayout::Compressed,
+            thumbnail: TableLayout::Compressed,
+        }
+    } else {
+        TableLayouts {
+            title: settings.title_table_layout,
+            thumbnail: settings.thumbnail_table_layout,
+        }
+    }
+}
@@ -24,7 +24,7 @@ use reqwest::Url;
 use thumbnails::components::ThumbgenProvider;
 use utils::api_request;
 use yew::prelude::*;
-use yew_hooks::{use_async_with_options, use_interval, UseAsyncOptions};
+use yew_hooks::{use_async_with_options, use_interval, use_local_storage, UseAsyncOptions};
 use yew_router::prelude::*;
 use web_sys::window;
 
@@ -43,18 +43,35 @@ use pages::*;
 
 #[function_component]
 fn App() -> Html {
+    // the SettingsContext is not available yet, changes to the API base URL require a refresh
+    let stored_settings = use_local_storage::<settings::Settings>("settings".into());
     let window_context = use_memo((), |()| {
         let window = window().expect("window should exist");
-        WindowContext {
-            origin: Url::parse(
-                window.location().origin().expect("window.location.origin should exist").as_str()
-            ).expect("window.location.origin should be a valid URL"),
-        }
+        let document = window.document().expect("document should exist");
+        // the <base> element in index.html points at the base path the app is served under
+        let mut origin = Url::parse(
+            &document.base_uri().expect("document.baseURI should be accessible").expect("document.baseURI should exist")
+        ).expect("document.baseURI should be a valid URL");
+        origin.set_query(None);
+        origin.set_fragment(None);
+        origin.path_segments_mut().expect("document.baseURI should be a valid base").pop_if_empty();
+        let api_base = stored_settings
+            .as_ref()
+            .and_then(|s| s.api_base_url.as_deref())
+            .and_then(|url| Url::parse(url).ok())
+            .filter(|url| !url.cannot_be_a_base())
+            .map_or_else(|| origin.clone(), |mut url| {
+                url.set_query(None);
+                url.set_fragment(None);
+                url.path_segments_mut().expect("checked above").pop_if_empty();
+                url
+            });
+        WindowContext { origin, api_base }
     });
     let update_clock = use_state(|| UpdateClock(false));
 
     let status = {
-        let status_url = use_memo(window_context.clone(), |wc| wc.origin_join_segments(&["api", "status"]));
+        let status_url = use_memo(window_context.clone(), |wc| wc.api_join_segments(&["api", "status"]));
         use_async_with_options::<_, Rc<StatusResponse>, ErrorContext>(async move { 
             api_request::<_, StatusResponse>((*status_url).clone()).await
                 .map(Rc::new)
@@ -74,6 +91,7 @@ fn App() -> Html {
         <ContextProvider<Rc<WindowContext>> context={window_context}>
         <ContextProvider<StatusContext> context={status.data.clone()}>
         <SettingsProvider>
+        <NowPlayingProvider>
         <ThumbgenProvider>
         <ContextProvider<UpdateClock> context={*update_clock}>
         <AsyncTaskManager>
@@ -83,6 +101,7 @@ fn App() -> Html {
         </AsyncTaskManager>
         </ContextProvider<UpdateClock>>
         </ThumbgenProvider>
+        </NowPlayingProvider>
         </SettingsProvider>
         </ContextProvider<StatusContext>>
         </ContextProvider<Rc<WindowContext>>>
@@ -93,6 +112,7 @@ fn main() {
     yew::Renderer::<App>::new().render();
 }
 
+#[allow(clippy::needless_raw_string_hashes, clippy::doc_markdown)]
 pub mod built_info {
     // Contents generated by buildscript, using built
     include!(concat!(env!("OUT_DIR"), "/built.rs"));
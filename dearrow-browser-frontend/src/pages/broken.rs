@@ -18,12 +18,139 @@
 
 use std::rc::Rc;
 
+use dearrow_browser_api::unsync::{ApiThumbnail, ApiTitle};
+use reqwest::Url;
 use yew::prelude::*;
 
-use crate::components::tables::details::{DetailType, PaginatedDetailTableRenderer};
+use crate::components::tables::details::*;
 use crate::components::tables::switch::{ModeSubtype, TableModeSwitch};
 use crate::contexts::WindowContext;
 use crate::hooks::use_location_state;
+use crate::pages::LocationState;
+use crate::utils::RcEq;
+
+/// Which kind of broken entries to show
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BrokenFilter {
+    All,
+    MissingVotes,
+    MissingTimestamp,
+}
+
+impl BrokenFilter {
+    fn matches_title(self, title: &ApiTitle) -> bool {
+        match self {
+            BrokenFilter::All => true,
+            BrokenFilter::MissingVotes => title.votes_missing,
+            BrokenFilter::MissingTimestamp => false,
+        }
+    }
+
+    fn matches_thumbnail(self, thumb: &ApiThumbnail) -> bool {
+        match self {
+            BrokenFilter::All => true,
+            BrokenFilter::MissingVotes => thumb.votes_missing,
+            BrokenFilter::MissingTimestamp => thumb.timestamp_missing,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+struct ProblemCounts {
+    total: usize,
+    missing_votes: usize,
+    missing_timestamp: usize,
+}
+
+fn count_problems(details: &DetailSlice) -> ProblemCounts {
+    match details {
+        DetailSlice::Titles(ref titles) => ProblemCounts {
+            total: titles.len(),
+            missing_votes: titles.iter().filter(|t| t.votes_missing).count(),
+            missing_timestamp: 0,
+        },
+        DetailSlice::Thumbnails(ref thumbs) => ProblemCounts {
+            total: thumbs.len(),
+            missing_votes: thumbs.iter().filter(|t| t.votes_missing).count(),
+            missing_timestamp: thumbs.iter().filter(|t| t.timestamp_missing).count(),
+        },
+        DetailSlice::All(_) => ProblemCounts::default(),
+    }
+}
+
+fn filter_details(details: &DetailSlice, filter: BrokenFilter) -> DetailSlice {
+    match details {
+        _ if filter == BrokenFilter::All => details.clone(),
+        DetailSlice::Titles(ref titles) => DetailSlice::Titles(RcEq(
+            titles.iter().filter(|t| filter.matches_title(t)).cloned().collect(),
+        )),
+        DetailSlice::Thumbnails(ref thumbs) => DetailSlice::Thumbnails(RcEq(
+            thumbs.iter().filter(|t| filter.matches_thumbnail(t)).cloned().collect(),
+        )),
+        DetailSlice::All(_) => details.clone(),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct BrokenDetailTableProps {
+    url: Rc<Url>,
+    mode: DetailType,
+    entry_count: UseStateSetter<Option<usize>>,
+}
+
+/// Table of broken entries, with toggles for filtering by the specific problem
+#[function_component]
+fn BrokenDetailTable(props: &BrokenDetailTableProps) -> HtmlResult {
+    let location_state = use_location_state();
+    let filter = use_state_eq(|| BrokenFilter::All);
+    let details = use_detail_download(props.url.clone(), props.mode, true)?;
+    let detail_slice = use_detail_slice((*details).as_ref().ok().cloned(), DetailIndex::All);
+    let counts = *use_memo(detail_slice.clone(), count_problems);
+    let filtered = (*use_memo((detail_slice, *filter), |(details, filter)| filter_details(details, *filter))).clone();
+
+    if let Err(ref e) = *details {
+        props.entry_count.set(None);
+        return Ok(html! {
+            <center>
+                <b>{"Failed to fetch details from the API :/"}</b>
+                <pre>{format!("{e:?}")}</pre>
+            </center>
+        });
+    }
+    props.entry_count.set(Some(filtered.len()));
+
+    let set_filter = |new_filter: BrokenFilter| {
+        let filter = filter.clone();
+        let location_state = location_state.clone();
+        Callback::from(move |_: MouseEvent| {
+            if *filter != new_filter {
+                // the current page may not exist anymore
+                let state = location_state.get_state();
+                location_state.replace_state(LocationState { detail_table_page: 0, ..state });
+                filter.set(new_filter);
+            }
+        })
+    };
+
+    Ok(html! {
+        <>
+            <div class="broken-filters">
+                <span class="button" selected={*filter == BrokenFilter::All} onclick={set_filter(BrokenFilter::All)}>
+                    {format!("All problems ({})", counts.total)}
+                </span>
+                <span class="button" selected={*filter == BrokenFilter::MissingVotes} onclick={set_filter(BrokenFilter::MissingVotes)}>
+                    {format!("Missing votes ({})", counts.missing_votes)}
+                </span>
+                if props.mode == DetailType::Thumbnail {
+                    <span class="button" selected={*filter == BrokenFilter::MissingTimestamp} onclick={set_filter(BrokenFilter::MissingTimestamp)}>
+                        {format!("Missing timestamp ({})", counts.missing_timestamp)}
+                    </span>
+                }
+            </div>
+            <BasePaginatedDetailTableRenderer details={filtered} />
+        </>
+    })
+}
 
 #[function_component]
 pub fn BrokenPage() -> Html {
@@ -34,18 +161,19 @@ pub fn BrokenPage() -> Html {
     let url_and_mode = use_memo(state.detail_table_mode, |dtm| {
         DetailType::try_from(*dtm).ok().map(|dtm| match dtm {
             DetailType::Title => (
-                Rc::new(window_context.origin_join_segments(&["api", "titles", "broken"])),
+                Rc::new(window_context.api_join_segments(&["api", "titles", "broken"])),
                 dtm,
             ),
             DetailType::Thumbnail => (
-                Rc::new(window_context.origin_join_segments(&["api", "thumbnails", "broken"])),
+                Rc::new(window_context.api_join_segments(&["api", "thumbnails", "broken"])),
                 dtm,
             ),
         })
     });
 
-    let table_fallback = html! {
-        <center><b>{"Loading..."}</b></center>
+    let table_fallback = match url_and_mode.as_ref() {
+        Some((_, mode)) => html! {<DetailTableSkeleton kind={DetailTableKind::from(*mode)} />},
+        None => html! {<center><b>{"Loading..."}</b></center>},
     };
 
     html! {
@@ -54,7 +182,7 @@ pub fn BrokenPage() -> Html {
             <TableModeSwitch entry_count={*entry_count} types={ModeSubtype::Details} />
             if let Some((url, mode)) = url_and_mode.as_ref() {
                 <Suspense fallback={table_fallback}>
-                    <PaginatedDetailTableRenderer mode={*mode} url={url.clone()} entry_count={entry_count.setter()} />
+                    <BrokenDetailTable key={if *mode == DetailType::Title { "titles" } else { "thumbnails" }} mode={*mode} url={url.clone()} entry_count={entry_count.setter()} />
                 </Suspense>
             } else {
                 {table_fallback}
@@ -15,7 +15,7 @@
 *  You should have received a copy of the GNU Affero General Public License
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
-use std::rc::Rc;
+use std::{cmp::Reverse, rc::Rc};
 
 use dearrow_browser_api::unsync::{ChannelFetchProgress, InnertubeChannel};
 use cloneable_errors::{bail, ErrorContext, ResContext};
@@ -36,7 +36,7 @@ fn ChannelDetails(props: &ChannelPageProps) -> HtmlResult {
     let status: StatusContext = use_context().expect("StatusResponse should be defined");
     let result: Rc<Result<InnertubeChannel, ErrorContext>> = use_async_suspension(
         |(channel, _)| async move {
-            let url = window_context.origin_join_segments(&["innertube", "channel", &channel]);
+            let url = window_context.api_join_segments(&["innertube", "channel", &channel]);
             loop {
                 let resp = REQWEST_CLIENT
                     .get(url.clone())
@@ -99,12 +99,12 @@ pub fn ChannelPage(props: &ChannelPageProps) -> Html {
             DetailType::try_from(*dtm).ok().map(|dtm| match dtm {
                 DetailType::Title => (
                     Rc::new(
-                        window_context.origin_join_segments(&["api", "titles", "channel", channel]),
+                        window_context.api_join_segments(&["api", "titles", "channel", channel]),
                     ),
                     dtm,
                 ),
                 DetailType::Thumbnail => (
-                    Rc::new(window_context.origin_join_segments(&[
+                    Rc::new(window_context.api_join_segments(&[
                         "api",
                         "thumbnails",
                         "channel",
@@ -161,10 +161,13 @@ pub fn ChannelPage(props: &ChannelPageProps) -> Html {
                     match slice {
                         DetailSlice::Thumbnails(ref mut list) => Rc::get_mut(&mut list.0)
                             .expect("should be get mutable reference here")
-                            .sort_unstable_by(|a, b| b.time_submitted.cmp(&a.time_submitted)),
+                            .sort_unstable_by_key(|d| Reverse(d.time_submitted)),
                         DetailSlice::Titles(ref mut list) => Rc::get_mut(&mut list.0)
                             .expect("should be get mutable reference here")
-                            .sort_unstable_by(|a, b| b.time_submitted.cmp(&a.time_submitted)),
+                            .sort_unstable_by_key(|d| Reverse(d.time_submitted)),
+                        DetailSlice::All(ref mut list) => Rc::get_mut(&mut list.0)
+                            .expect("should be get mutable reference here")
+                            .sort_unstable_by_key(|d| Reverse(d.time_submitted())),
                     }
                     Ok(IterationResult {
                         result: ChannelLoadingStatus::Ready(slice),
@@ -209,7 +212,7 @@ pub fn ChannelPage(props: &ChannelPageProps) -> Html {
             </center>
         },
         ChannelLoadingStatus::Ready(ref details) => html! {
-            <BasePaginatedDetailTableRenderer details={details.clone()} />
+            <BasePaginatedDetailTableRenderer details={details.clone()} hideable_originals=true />
         },
     };
 
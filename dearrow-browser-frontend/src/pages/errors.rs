@@ -0,0 +1,114 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::rc::Rc;
+
+use cloneable_errors::SerializableError;
+use dearrow_browser_api::unsync::ErrorsPage as ApiErrorsPage;
+use yew::prelude::*;
+
+use crate::contexts::{SettingsContext, StatusContext, WindowContext};
+use crate::hooks::use_async_suspension;
+use crate::utils::api_request;
+
+#[derive(Properties, PartialEq)]
+struct ErrorsChunkProps {
+    offset: usize,
+    count: usize,
+    total: UseStateSetter<Option<usize>>,
+}
+
+#[function_component]
+fn ErrorsChunk(props: &ErrorsChunkProps) -> HtmlResult {
+    let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
+    let status: StatusContext = use_context().expect("StatusContext should be defined");
+    let chunk = use_async_suspension(
+        |(offset, count, _)| async move {
+            let mut url = window_context.api_join_segments(&["api", "errors"]);
+            url.query_pairs_mut()
+                .append_pair("offset", &offset.to_string())
+                .append_pair("count", &count.to_string());
+            api_request::<_, ApiErrorsPage<SerializableError>>(url).await
+        },
+        (props.offset, props.count, status.map(|s| s.last_updated)),
+    )?;
+
+    Ok(match *chunk {
+        Err(ref e) => html! {
+            <tr>
+                <td colspan=2>
+                    <b>{"Failed to fetch errors from the API :/"}</b>
+                    <pre>{format!("{e:?}")}</pre>
+                </td>
+            </tr>
+        },
+        Ok(ref chunk) => {
+            props.total.set(Some(chunk.total));
+            html! {
+                { for chunk.errors.iter().enumerate().map(|(i, e)| html! {
+                    <tr>
+                        <td>{chunk.offset + i + 1}</td>
+                        <td class="warning-message-col"><pre>{format!("{e:?}")}</pre></td>
+                    </tr>
+                }) }
+            }
+        }
+    })
+}
+
+#[function_component]
+pub fn ErrorsPage() -> Html {
+    let settings_context: SettingsContext =
+        use_context().expect("SettingsContext should be available");
+    let chunk_size: usize = settings_context.settings().entries_per_page.into();
+    let chunks = use_state_eq(|| 1usize);
+    let total: UseStateHandle<Option<usize>> = use_state_eq(|| None);
+
+    let load_more = {
+        let chunks = chunks.clone();
+        Callback::from(move |_: MouseEvent| chunks.set(*chunks + 1))
+    };
+    let loaded = total.map_or(0, |total| total.min(*chunks * chunk_size));
+
+    let fallback = html! {
+        <tr><td colspan=2><b>{"Loading..."}</b></td></tr>
+    };
+
+    html! {
+        <>
+            <h2>{"Database parse errors"}</h2>
+            if let Some(total) = *total {
+                <span>{format!("Showing {loaded} of {total} errors")}</span>
+            }
+            <table>
+                <tr class="header">
+                    <th>{"#"}</th>
+                    <th>{"Error"}</th>
+                </tr>
+                { for (0..*chunks).map(|i| html! {
+                    <Suspense key={i} fallback={fallback.clone()}>
+                        <ErrorsChunk offset={i * chunk_size} count={chunk_size} total={total.setter()} />
+                    </Suspense>
+                }) }
+            </table>
+            if total.is_some_and(|total| loaded < total) {
+                <center><button onclick={load_more}>{"Load more"}</button></center>
+            }
+        </>
+    }
+}
@@ -19,11 +19,36 @@ use std::rc::Rc;
 
 use yew::prelude::*;
 
+use crate::components::bookmarks::BookmarksList;
 use crate::components::searchbar::Searchbar;
-use crate::components::tables::{details::*, switch::*};
+use crate::components::sparkline::Sparkline;
+use crate::components::tables::{details::*, grouped::GroupedDetailTableRenderer, switch::*};
+use crate::constants::GROUPED_HOMEPAGE_FETCH_COUNT;
 use crate::contexts::{SettingsContext, StatusContext, WindowContext};
 use crate::hooks::use_location_state;
 
+/// Sparkline of the submissions per day reported by the server
+#[function_component]
+fn RecentActivity() -> Html {
+    let status: StatusContext = use_context().expect("StatusContext should be defined");
+    let Some(days) = status.as_ref().and_then(|s| s.daily_submissions.as_ref()).filter(|d| !d.is_empty()) else {
+        return html! {};
+    };
+    let values: Rc<[usize]> = days.iter().map(|d| d.titles + d.thumbnails).collect();
+    let titles: usize = days.iter().map(|d| d.titles).sum();
+    let thumbnails: usize = days.iter().map(|d| d.thumbnails).sum();
+    let title = AttrValue::from(format!(
+        "Submissions per day over the last {} days: {titles} titles, {thumbnails} thumbnails in total",
+        days.len(),
+    ));
+    html! {
+        <div class="recent-activity">
+            {"Recent activity: "}
+            <Sparkline {values} {title} />
+        </div>
+    }
+}
+
 #[function_component]
 pub fn HomePage() -> Html {
     let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
@@ -32,6 +57,7 @@ pub fn HomePage() -> Html {
         use_context().expect("SettingsContext should be available");
     let settings = settings_context.settings();
     let entries_per_page: usize = settings.entries_per_page.into();
+    let grouped = settings.group_homepage_by_video;
     let state = use_location_state().get_state();
 
     let url_and_mode = use_memo(
@@ -39,28 +65,36 @@ pub fn HomePage() -> Html {
             state.detail_table_mode,
             entries_per_page,
             state.detail_table_page,
+            grouped,
         ),
-        |(dtm, entries_per_page, page)| {
+        |(dtm, entries_per_page, page, grouped)| {
             DetailType::try_from(*dtm).ok().map(|dtm| {
                 let mut url = match dtm {
-                    DetailType::Title => window_context.origin_join_segments(&["api", "titles"]),
+                    DetailType::Title => window_context.api_join_segments(&["api", "titles"]),
                     DetailType::Thumbnail => {
-                        window_context.origin_join_segments(&["api", "thumbnails"])
+                        window_context.api_join_segments(&["api", "thumbnails"])
                     }
                 };
-                url.query_pairs_mut()
-                    .append_pair("offset", &(page * entries_per_page).to_string())
-                    .append_pair("count", &entries_per_page.to_string());
+                if *grouped {
+                    // grouped pages are paginated client-side, by video
+                    url.query_pairs_mut()
+                        .append_pair("count", &GROUPED_HOMEPAGE_FETCH_COUNT.to_string());
+                } else {
+                    url.query_pairs_mut()
+                        .append_pair("offset", &(page * entries_per_page).to_string())
+                        .append_pair("count", &entries_per_page.to_string());
+                }
                 (Rc::new(url), dtm)
             })
         },
     );
 
-    let fallback = html! {
-        <center><b>{"Loading..."}</b></center>
+    let fallback = match url_and_mode.as_ref() {
+        Some((_, mode)) if !grouped => html! {<DetailTableSkeleton kind={DetailTableKind::from(*mode)} />},
+        _ => html! {<center><b>{"Loading..."}</b></center>},
     };
 
-    let detail_count = status_context.and_then(|status_context| {
+    let detail_count = status_context.filter(|_| !grouped).and_then(|status_context| {
         url_and_mode.as_ref().as_ref().map(|(_, dtm)| match dtm {
             DetailType::Thumbnail => status_context.thumbnails,
             DetailType::Title => status_context.titles,
@@ -73,11 +107,17 @@ pub fn HomePage() -> Html {
         <>
             <div class="page-details">
                 <Searchbar />
+                <BookmarksList />
+                <RecentActivity />
             </div>
             <TableModeSwitch entry_count={detail_count} types={ModeSubtype::Details} />
             if let Some((url, mode)) = url_and_mode.as_ref() {
                 <Suspense {fallback}>
-                    <UnpaginatedDetailTableRenderer mode={*mode} url={url.clone()} sort=false />
+                    if grouped {
+                        <GroupedDetailTableRenderer mode={*mode} url={url.clone()} hideable_originals=true />
+                    } else {
+                        <UnpaginatedDetailTableRenderer mode={*mode} url={url.clone()} sort=false hideable_originals=true />
+                    }
                 </Suspense>
             } else {
                 {fallback}
@@ -26,19 +26,27 @@ use crate::components::tables::switch::TableMode;
 
 mod broken;
 mod channel;
+mod errors;
 mod home;
+mod title_casing;
 mod unverified;
 mod user;
 mod uuid;
 mod video;
+mod vote_stats;
+mod warnings;
 
 use broken::BrokenPage;
 use channel::ChannelPage;
+use errors::ErrorsPage;
 use home::HomePage;
+use title_casing::TitleCasingPage;
 use unverified::UnverifiedPage;
 use user::UserPage;
 use uuid::UUIDPage;
 use video::VideoPage;
+use vote_stats::VoteStatsPage;
+use warnings::WarningsPage;
 
 #[derive(Clone, Routable, PartialEq, IntoStaticStr)]
 pub enum MainRoute {
@@ -48,6 +56,14 @@ pub enum MainRoute {
     Unverified,
     #[at("/broken")]
     Broken,
+    #[at("/warnings")]
+    Warnings,
+    #[at("/errors")]
+    Errors,
+    #[at("/tools/title_casing")]
+    TitleCasing,
+    #[at("/stats/votes")]
+    VoteStats,
     #[at("/video_id/:id")]
     Video { id: AttrValue },
     #[at("/channel/:id")]
@@ -67,6 +83,8 @@ pub enum MainRoute {
 pub struct LocationState {
     pub detail_table_mode: TableMode,
     pub detail_table_page: usize,
+    /// Render all entries of the table at once, instead of a single page
+    pub detail_table_view_all: bool,
 }
 
 #[allow(clippy::needless_pass_by_value)]
@@ -80,6 +98,10 @@ pub fn render_main_route(route: MainRoute) -> Html {
             MainRoute::Home => "DeArrow Browser".to_string(),
             MainRoute::Unverified => "Unverified titles - DeArrow Browser".to_string(),
             MainRoute::Broken => "Broken entries - DeArrow Browser".to_string(),
+            MainRoute::Warnings => "Warnings - DeArrow Browser".to_string(),
+            MainRoute::Errors => "Parse errors - DeArrow Browser".to_string(),
+            MainRoute::TitleCasing => "Title casing preview - DeArrow Browser".to_string(),
+            MainRoute::VoteStats => "Vote statistics - DeArrow Browser".to_string(),
             MainRoute::NotFound => "Page not found - DeArrow Browser".to_string(),
             MainRoute::NotImplemented => "Not implemented - DeArrow Browser".to_string(),
             MainRoute::Video { ref id } => format!("VideoID {id} - DeArrow Browser"),
@@ -93,6 +115,10 @@ pub fn render_main_route(route: MainRoute) -> Html {
         MainRoute::Home => html! {<HomePage/>},
         MainRoute::Unverified => html! {<UnverifiedPage/>},
         MainRoute::Broken => html! {<BrokenPage/>},
+        MainRoute::Warnings => html! {<WarningsPage/>},
+        MainRoute::Errors => html! {<ErrorsPage/>},
+        MainRoute::TitleCasing => html! {<TitleCasingPage/>},
+        MainRoute::VoteStats => html! {<VoteStatsPage/>},
         MainRoute::Video { ref id } => html! {<VideoPage videoid={id.clone()} />},
         MainRoute::Channel { ref id } => html! {<ChannelPage channel={id.clone()} />},
         MainRoute::User { ref id } => html! {<UserPage userid={id.clone()} />},
@@ -116,6 +142,8 @@ pub fn render_main_route(route: MainRoute) -> Html {
     html! {
         <ModalRenderer>
             <Header />
+            <NoticeBanner />
+            <NewerDataBanner />
             <div id="content" data-route={route_name}>
                 {route_html}
             </div>
@@ -0,0 +1,108 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+
+use std::rc::Rc;
+
+use dearrow_browser_api::unsync::TitleCasingPreview;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::contexts::WindowContext;
+use crate::hooks::use_async_suspension;
+use crate::utils::api_request;
+
+#[derive(Properties, PartialEq)]
+struct PreviewTableProps {
+    title: AttrValue,
+    original: bool,
+}
+
+#[function_component]
+fn PreviewTable(props: &PreviewTableProps) -> HtmlResult {
+    let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
+    let preview = use_async_suspension(|(title, original)| async move {
+        let mut url = window_context.api_join_segments(&["api", "title_casing"]);
+        url.query_pairs_mut()
+            .append_pair("title", &title)
+            .append_pair("original", if original { "true" } else { "false" });
+        api_request::<_, TitleCasingPreview>(url).await
+    }, (props.title.clone(), props.original))?;
+
+    Ok(match *preview {
+        Err(ref e) => html! {
+            <center>
+                <b>{"Failed to fetch the preview from the API :/"}</b>
+                <pre>{format!("{e:?}")}</pre>
+            </center>
+        },
+        Ok(ref preview) => html! {
+            <table class="info-table">
+                <tr><th>{"Capitalize Words"}</th><td>{preview.capitalize_words.clone()}</td></tr>
+                <tr><th>{"Title Case"}</th><td>{preview.title_case.clone()}</td></tr>
+                <tr><th>{"Sentence case"}</th><td>{preview.sentence_case.clone()}</td></tr>
+                <tr><th>{"lower case"}</th><td>{preview.lower_case.clone()}</td></tr>
+                <tr><th>{"First letter uppercase"}</th><td>{preview.first_letter_uppercase.clone()}</td></tr>
+            </table>
+        },
+    })
+}
+
+#[function_component]
+pub fn TitleCasingPage() -> Html {
+    let title = use_state_eq(AttrValue::default);
+    let onchange = {
+        let title = title.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            title.set(input.value().into());
+        })
+    };
+    let original = use_state_eq(|| false);
+    let original_onchange = {
+        let original = original.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            original.set(input.checked());
+        })
+    };
+
+    let fallback = html! {
+        <center><b>{"Loading..."}</b></center>
+    };
+
+    html! {
+        <>
+            <h2>{"Title casing preview"}</h2>
+            <p>{"Shows how a title would look with each of the title formatting options of the extension. Links, acronyms and words with custom capitalization are left as-is. Custom capitalization is only trusted in original titles if the word isn't in all caps."}</p>
+            <div>
+                <label for="title_casing_input">{"Title: "}</label>
+                <input id="title_casing_input" type="text" size=80 {onchange} />
+            </div>
+            <div>
+                <label for="title_casing_original">{"Original title: "}</label>
+                <input id="title_casing_original" type="checkbox" onchange={original_onchange} checked={*original} />
+            </div>
+            if !title.is_empty() {
+                <Suspense {fallback}>
+                    <PreviewTable title={(*title).clone()} original={*original} />
+                </Suspense>
+            }
+        </>
+    }
+}
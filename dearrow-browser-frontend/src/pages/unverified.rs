@@ -28,7 +28,7 @@ pub fn UnverifiedPage() -> Html {
     let entry_count = use_state_eq(|| None);
 
     let url = use_memo((), |()| {
-        window_context.origin_join_segments(&["api", "titles", "unverified"])
+        window_context.api_join_segments(&["api", "titles", "unverified"])
     });
 
     let fallback = html! {
@@ -17,17 +17,82 @@
 */
 use std::rc::Rc;
 
-use dearrow_browser_api::unsync::User;
-use cloneable_errors::ErrorContext;
+use chrono::{DateTime, Utc};
+use dearrow_browser_api::unsync::{ApiThumbnail, ApiTitle, User, UserSubmissions};
+use cloneable_errors::{ErrorContext, ResContext};
+use futures::FutureExt;
+use gloo_console::error;
+use reqwest::Url;
+use serde::Serialize;
 use yew::prelude::*;
 
+use crate::components::async_task_manager::{AsyncTaskFuture, AsyncTaskResult};
+use crate::components::bookmarks::BookmarkButton;
 use crate::components::icon::*;
 use crate::components::tables::details::*;
 use crate::components::tables::switch::{ModeSubtype, TableMode, TableModeSwitch};
-use crate::components::tables::warnings::PaginatedWarningsTable;
-use crate::contexts::{StatusContext, WindowContext};
+use crate::components::tables::warnings::{PaginatedWarningsTable, WarningsTimeline};
+use crate::contexts::{AsyncTaskControl, StatusContext, WindowContext};
 use crate::hooks::{use_async_suspension, use_location_state};
-use crate::utils::{api_request, sbb_userid_link};
+use crate::settings::BookmarkKind;
+use crate::utils::{api_request, download_file, sbb_userid_link};
+
+#[derive(Serialize)]
+struct UserDataExport {
+    user_id: Rc<str>,
+    snapshot_timestamp: i64,
+    user: User,
+    titles: Vec<ApiTitle>,
+    thumbnails: Vec<ApiThumbnail>,
+}
+
+#[derive(Clone)]
+struct UserExportParams {
+    user_id: AttrValue,
+    snapshot_timestamp: i64,
+    user_url: Url,
+    submissions_url: Url,
+}
+
+impl UserExportParams {
+    fn filename(&self) -> String {
+        let snapshot = DateTime::<Utc>::from_timestamp_millis(self.snapshot_timestamp).unwrap_or_default();
+        format!("dearrow-user-{}-{}.json", self.user_id, snapshot.format("%Y%m%d-%H%M%S"))
+    }
+
+    async fn export(&self) -> Result<(), ErrorContext> {
+        let (user, submissions): (User, UserSubmissions) = futures::try_join!(
+            api_request(self.user_url.clone()),
+            api_request(self.submissions_url.clone()),
+        )?;
+        let json = serde_json::to_string_pretty(&UserDataExport {
+            user_id: self.user_id.as_str().into(),
+            snapshot_timestamp: self.snapshot_timestamp,
+            user,
+            titles: submissions.titles,
+            thumbnails: submissions.thumbnails,
+        }).context("Failed to serialize user data")?;
+        download_file(&json, "application/json", &self.filename())
+    }
+}
+
+fn create_user_export_task(params: UserExportParams) -> (AsyncTaskFuture, Html) {
+    (async move {
+        match params.export().await {
+            Ok(()) => (
+                AsyncTaskResult::AutoDismiss { success: true },
+                html! {{"Download started"}},
+            ),
+            Err(e) => {
+                error!(format!("Failed to export user data: {e:?}"));
+                (
+                    AsyncTaskResult::DismissOrRetry { success: false, retry: Box::new(move || create_user_export_task(params)) },
+                    html! {{"Failed to export user data. Check console for details."}},
+                )
+            },
+        }
+    }.boxed_local(), html! {{"Fetching user data..."}})
+}
 
 #[derive(Properties, PartialEq)]
 struct UserDetailsProps {
@@ -38,22 +103,40 @@ struct UserDetailsProps {
 fn UserDetails(props: &UserDetailsProps) -> HtmlResult {
     let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
     let status: StatusContext = use_context().expect("StatusResponse should be defined");
-    let url = window_context.origin_join_segments(&["api", "users", "user_id", &props.userid]);
+    let url = window_context.api_join_segments(&["api", "users", "user_id", &props.userid]);
     let result: Rc<Result<User, ErrorContext>> = use_async_suspension(
         |(url, _)| async move { api_request(url.clone()).await },
-        (url, status.map(|s| s.last_updated)),
+        (url, status.as_ref().map(|s| s.last_updated)),
     )?;
     let sbb_url: Rc<AttrValue> = use_memo(props.userid.clone(), |uid| {
         AttrValue::Rc(sbb_userid_link(uid).as_str().into())
     });
+    let async_task_control: AsyncTaskControl = use_context().expect("AsyncTaskControl should be defined");
+    let download_json = use_callback(
+        (props.userid.clone(), status.as_ref().map(|s| s.last_updated)),
+        move |_: MouseEvent, (userid, last_updated)| {
+            let params = UserExportParams {
+                user_id: userid.clone(),
+                snapshot_timestamp: last_updated.unwrap_or_else(|| Utc::now().timestamp_millis()),
+                user_url: window_context.api_join_segments(&["api", "users", "user_id", userid]),
+                submissions_url: window_context.api_join_segments(&["api", "users", "user_id", userid, "submissions"]),
+            };
+            let (task, summary) = create_user_export_task(params);
+            async_task_control.submit_task(format!("Exporting data of user {userid}").into(), summary, task);
+        },
+    );
 
     Ok(match *result {
         Ok(ref user) => html! {
             <>
-                <div>{format!("UserID: {}", props.userid.clone())}
+                <div>{format!("UserID: {} ", props.userid.clone())}
+                <BookmarkButton kind={BookmarkKind::User} id={props.userid.clone()} />
                 if user.vip {
                     <Icon r#type={IconType::VIP} tooltip="This user is a VIP" />
                 }
+                if status.as_ref().is_some_and(|s| s.trusted_users.contains(&user.user_id)) {
+                    <Icon r#type={IconType::Trusted} tooltip="This user is trusted by this instance" />
+                }
                 if user.active_warning_count > 0 {
                     <Icon r#type={IconType::Warning} tooltip="This user has an active warning" />
                 } else if user.warning_count > 0 {
@@ -73,6 +156,7 @@ fn UserDetails(props: &UserDetailsProps) -> HtmlResult {
                 <div>{format!("Titles: {}", user.title_count)}</div>
                 <div>{format!("Thumbnails: {}", user.thumbnail_count)}</div>
                 <div><a href={&*sbb_url}>{"View on SB Browser"}</a></div>
+                <div><button onclick={download_json}>{"Download as JSON"}</button></div>
             </>
         },
         Err(ref e) => html! {
@@ -105,33 +189,37 @@ pub fn UserPage(props: &UserPageProps) -> Html {
     let table_html = use_memo(
         (state.detail_table_mode, props.userid.clone()),
         |(dtm, userid)| match dtm {
-            TableMode::Titles => {
-                let url = Rc::new(
-                    window_context.origin_join_segments(&["api", "titles", "user_id", userid]),
-                );
-                html! {
-                    <Suspense fallback={table_fallback.clone()}>
-                        <PaginatedDetailTableRenderer mode={DetailType::Title} {url} entry_count={entry_count.setter()} hide_userid=true hide_username=true />
-                    </Suspense>
-                }
-            }
-            TableMode::Thumbnails => {
-                let url = Rc::new(window_context.origin_join_segments(&[
+            TableMode::Titles | TableMode::Thumbnails | TableMode::All => {
+                let url = Rc::new(window_context.api_join_segments(&[
                     "api",
-                    "thumbnails",
+                    "users",
                     "user_id",
                     userid,
+                    "submissions",
                 ]));
+                let view = match dtm {
+                    TableMode::Titles => SubmissionsView::Titles,
+                    TableMode::Thumbnails => SubmissionsView::Thumbnails,
+                    _ => SubmissionsView::All,
+                };
+                let fallback = html! {
+                    <DetailTableSkeleton hide_userid=true hide_username=true kind={match view {
+                        SubmissionsView::Titles => DetailTableKind::Titles,
+                        SubmissionsView::Thumbnails => DetailTableKind::Thumbnails,
+                        SubmissionsView::All => DetailTableKind::All,
+                    }} />
+                };
+                // the same renderer is kept across these modes, switching doesn't refetch
                 html! {
-                    <Suspense fallback={table_fallback.clone()}>
-                        <PaginatedDetailTableRenderer mode={DetailType::Thumbnail} {url} entry_count={entry_count.setter()} hide_userid=true hide_username=true />
+                    <Suspense {fallback}>
+                        <PaginatedSubmissionsDetailTableRenderer {url} {view} entry_count={entry_count.setter()} hide_userid=true hide_username=true hideable_originals=true />
                     </Suspense>
                 }
             }
             TableMode::WarningsIssued => {
                 let url = Rc::new(
                     window_context
-                        .origin_join_segments(&["api", "warnings", "user_id", userid, "issued"]),
+                        .api_join_segments(&["api", "warnings", "user_id", userid, "issued"]),
                 );
                 html! {
                     <PaginatedWarningsTable {url} entry_count={entry_count.setter()} hide_issuer=true />
@@ -140,10 +228,15 @@ pub fn UserPage(props: &UserPageProps) -> Html {
             TableMode::WarningsReceived => {
                 let url = Rc::new(
                     window_context
-                        .origin_join_segments(&["api", "warnings", "user_id", userid, "received"]),
+                        .api_join_segments(&["api", "warnings", "user_id", userid, "received"]),
                 );
                 html! {
-                    <PaginatedWarningsTable {url} entry_count={entry_count.setter()} hide_receiver=true />
+                    <>
+                        <Suspense fallback={table_fallback.clone()}>
+                            <WarningsTimeline url={url.clone()} />
+                        </Suspense>
+                        <PaginatedWarningsTable {url} entry_count={entry_count.setter()} hide_receiver=true />
+                    </>
                 }
             }
         },
@@ -156,7 +249,7 @@ pub fn UserPage(props: &UserPageProps) -> Html {
                     <Suspense fallback={details_fallback}><UserDetails userid={props.userid.clone()} /></Suspense>
                 </div>
             </div>
-            <TableModeSwitch entry_count={*entry_count} types={ModeSubtype::Details | ModeSubtype::Warnings} />
+            <TableModeSwitch entry_count={*entry_count} types={ModeSubtype::Details | ModeSubtype::Combined | ModeSubtype::Warnings} />
             {(*table_html).clone()}
         </>
     }
@@ -19,17 +19,17 @@
 use std::rc::Rc;
 
 use chrono::DateTime;
-use dearrow_browser_api::unsync::{ApiThumbnail, ApiTitle};
+use dearrow_browser_api::unsync::{ApiThumbnail, ApiTitle, TitleCompetition, VoteStatus};
 use cloneable_errors::ResContext;
 use reqwest::StatusCode;
 use yew::prelude::*;
 
 use crate::components::icon::*;
-use crate::components::links::userid_link;
+use crate::components::links::{userid_link, uuid_link, ReportIssueLink};
 use crate::components::youtube::{OriginalTitle, YoutubeIframe, YoutubeVideoLink};
 use crate::constants::REQWEST_CLIENT;
-use crate::hooks::use_async_suspension;
-use crate::thumbnails::components::{Thumbnail, ThumbnailCaption};
+use crate::hooks::{use_async_suspension, use_display_timezone};
+use crate::thumbnails::components::{Thumbnail, ThumbnailCaption, ThumbnailSize};
 use crate::utils::{html_length, render_datetime, RcEq, ReqwestResponseExt};
 use crate::WindowContext;
 
@@ -68,15 +68,16 @@ fn flags_entry(html: Html) -> Html {
 #[function_component]
 fn UUIDTitle(props: &UUIDPageProps) -> HtmlResult {
     let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
-    let title = use_async_suspension(|(wc, uuid)| async move {
-        let url = wc.origin_join_segments(&["api", "titles", "uuid", &uuid]);
-        let resp = REQWEST_CLIENT.get(url).send().await.context("API request failed")?;
+    let api_url = use_memo(props.uuid.clone(), |uuid| window_context.api_join_segments(&["api", "titles", "uuid", uuid]));
+    let tz = use_display_timezone();
+    let title = use_async_suspension(|url| async move {
+        let resp = REQWEST_CLIENT.get((*url).clone()).send().await.context("API request failed")?;
         if resp.status() == StatusCode::NOT_FOUND {
             return Ok(None);
         }
         resp.check_status().await?
             .json::<ApiTitle>().await.context("Failed to deserialize API response").map(Some)
-    }, (window_context, props.uuid.clone()))?;
+    }, api_url.clone())?;
 
     let inline_placeholder = html! {<span>{"Loading..."}</span>};
 
@@ -95,7 +96,7 @@ fn UUIDTitle(props: &UUIDPageProps) -> HtmlResult {
                 <div class="info-table">
                     <div>{"Video ID: "}<YoutubeVideoLink videoid={title.video_id.clone()} multiline={false} /></div>
                     <div>{"Title: "}{title.title.clone()}</div>
-                    <div>{"Original title: "}<Suspense fallback={inline_placeholder}><OriginalTitle videoid={title.video_id.clone()} /></Suspense></div>
+                    <div>{"Original title: "}<Suspense fallback={inline_placeholder.clone()}><OriginalTitle videoid={title.video_id.clone()} /></Suspense></div>
                     <div>
                         if title.votes_missing {
                             {"Score: No data"}
@@ -131,9 +132,9 @@ fn UUIDTitle(props: &UUIDPageProps) -> HtmlResult {
                             <Icon r#type={IconType::Removed} />{" Removed by VIP"}
                         } else if title.shadow_hidden {
                             <Icon r#type={IconType::ShadowHidden} />{" Hidden by VIP using batch actions (shadowhidden)"}
-                        } else if title.votes - title.downvotes < -1 {
+                        } else if title.vote_status == VoteStatus::RemovedByDownvotes {
                             <Icon r#type={IconType::Downvote} />{" Removed by downvotes"}
-                        } else if title.votes < 0 {
+                        } else if title.vote_status == VoteStatus::ReplacedBySubmitter {
                             <Icon r#type={IconType::Replaced} />{" Replaced by submitter"}
                         } else if !title.locked && title.score < 0 {
                             <Icon r#type={IconType::PartiallyHidden} />{" Partially hidden - Only visible in submission menus"}
@@ -141,7 +142,8 @@ fn UUIDTitle(props: &UUIDPageProps) -> HtmlResult {
                             <Icon r#type={IconType::Upvote} />{" Fully visible"}
                         }
                     </div>
-                    <div>{"Submitted at: "}{DateTime::from_timestamp_millis(title.time_submitted).map_or(title.time_submitted.to_string(), render_datetime)}</div>
+                    <div>{"Competition: "}<Suspense fallback={inline_placeholder.clone()}><TitleCompetitionInfo uuid={props.uuid.clone()} /></Suspense></div>
+                    <div>{"Submitted at: "}{DateTime::from_timestamp_millis(title.time_submitted).map_or(title.time_submitted.to_string(), |dt| render_datetime(dt, tz))}</div>
                     <div>{"User ID: "}{title.user_id.clone()}{" "}{userid_link(title.user_id.clone().into())}</div>
                     <div>
                         {"Username: "}
@@ -151,6 +153,7 @@ fn UUIDTitle(props: &UUIDPageProps) -> HtmlResult {
                             <em>{"No username set"}</em>
                         }
                     </div>
+                    <div><ReportIssueLink subject={format!("title {}", props.uuid)} api_url={api_url.clone()} /></div>
                 </div>
                 <YoutubeIframe videoid={title.video_id.clone()} />
             </div>
@@ -158,18 +161,55 @@ fn UUIDTitle(props: &UUIDPageProps) -> HtmlResult {
     })
 }
 
+#[function_component]
+fn TitleCompetitionInfo(props: &UUIDPageProps) -> HtmlResult {
+    let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
+    let competition = use_async_suspension(|(wc, uuid)| async move {
+        let url = wc.api_join_segments(&["api", "titles", "uuid", &uuid, "competition"]);
+        REQWEST_CLIENT.get(url).send().await.context("API request failed")?
+            .check_status().await?
+            .json::<TitleCompetition>().await.context("Failed to deserialize API response")
+    }, (window_context, props.uuid.clone()))?;
+
+    Ok(match *competition {
+        Err(..) => html! {<em>{"Failed to fetch competition data"}</em>},
+        Ok(TitleCompetition::Winning) => html! {
+            <><Icon r#type={IconType::Upvote} />{" This title is currently shown by the extension"}</>
+        },
+        Ok(TitleCompetition::Hidden) => html! {
+            <><Icon r#type={IconType::Removed} />{" This title is hidden and cannot win"}</>
+        },
+        Ok(TitleCompetition::LockedOut { ref winner }) => html! {
+            <>
+                <Icon r#type={IconType::Locked} />{" Cannot win - another title is locked: "}
+                {winner.title.clone()}{" "}{uuid_link(winner.uuid.clone().into())}
+            </>
+        },
+        Ok(TitleCompetition::Behind { winner: Some(ref winner), score_needed }) => html! {
+            <>
+                {format!("Needs {score_needed} more score to overtake the current winner: ")}
+                {winner.title.clone()}{" "}{uuid_link(winner.uuid.clone().into())}
+            </>
+        },
+        Ok(TitleCompetition::Behind { winner: None, score_needed }) => html! {
+            {format!("Needs {score_needed} more score to be shown (no title is currently shown)")}
+        },
+    })
+}
+
 #[function_component]
 fn UUIDThumbnail(props: &UUIDPageProps) -> HtmlResult {
     let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
-    let thumbnail = use_async_suspension(|(wc, uuid)| async move {
-        let url = wc.origin_join_segments(&["api", "thumbnails", "uuid", &uuid]);
-        let resp = REQWEST_CLIENT.get(url).send().await.context("API request failed")?;
+    let api_url = use_memo(props.uuid.clone(), |uuid| window_context.api_join_segments(&["api", "thumbnails", "uuid", uuid]));
+    let tz = use_display_timezone();
+    let thumbnail = use_async_suspension(|url| async move {
+        let resp = REQWEST_CLIENT.get((*url).clone()).send().await.context("API request failed")?;
         if resp.status() == StatusCode::NOT_FOUND {
             return Ok(None);
         }
         resp.check_status().await?
             .json::<ApiThumbnail>().await.context("Failed to deserialize API response").map(Some)
-    }, (window_context, props.uuid.clone()))?;
+    }, api_url.clone())?;
     let caption: Rc<ThumbnailCaption> = use_memo(RcEq(thumbnail.clone()), |thumbnail| {
         if let Ok(Some(ref thumbnail)) = **thumbnail {
             if let Some(timestamp) = thumbnail.timestamp {
@@ -243,8 +283,10 @@ fn UUIDThumbnail(props: &UUIDPageProps) -> HtmlResult {
                             <Icon r#type={IconType::Removed} />{" Removed by VIP"}
                         } else if thumbnail.shadow_hidden {
                             <Icon r#type={IconType::ShadowHidden} />{" Hidden by VIP using batch actions (shadowhidden)"}
-                        } else if thumbnail.score < -1 {
+                        } else if thumbnail.vote_status == VoteStatus::RemovedByDownvotes {
                             <Icon r#type={IconType::Downvote} />{" Removed by downvotes"}
+                        } else if thumbnail.vote_status == VoteStatus::ReplacedBySubmitter {
+                            <Icon r#type={IconType::Replaced} />{" Replaced by submitter"}
                         } else if thumbnail.original && !thumbnail.locked && thumbnail.score < 1 {
                             <Icon r#type={IconType::Downvote} />{" Original thumbnail with insufficient score to be shown (requires >= 1 or lock)"}
                         } else if thumbnail.score < 0 {
@@ -253,7 +295,7 @@ fn UUIDThumbnail(props: &UUIDPageProps) -> HtmlResult {
                             <Icon r#type={IconType::Upvote} />{" Fully visible"}
                         }
                     </div>
-                    <div>{"Submitted at: "}{DateTime::from_timestamp_millis(thumbnail.time_submitted).map_or(thumbnail.time_submitted.to_string(), render_datetime)}</div>
+                    <div>{"Submitted at: "}{DateTime::from_timestamp_millis(thumbnail.time_submitted).map_or(thumbnail.time_submitted.to_string(), |dt| render_datetime(dt, tz))}</div>
                     <div>{"User ID: "}{thumbnail.user_id.clone()}{" "}{userid_link(thumbnail.user_id.clone().into())}</div>
                     <div>
                         {"Username: "}
@@ -263,9 +305,10 @@ fn UUIDThumbnail(props: &UUIDPageProps) -> HtmlResult {
                             <em>{"No username set"}</em>
                         }
                     </div>
+                    <div><ReportIssueLink subject={format!("thumbnail {}", props.uuid)} api_url={api_url.clone()} /></div>
                 </div>
                 if !thumbnail.timestamp_missing {
-                    <Thumbnail video_id={thumbnail.video_id.clone()} timestamp={thumbnail.timestamp} caption={(*caption).clone()} />
+                    <Thumbnail video_id={thumbnail.video_id.clone()} timestamp={thumbnail.timestamp} caption={(*caption).clone()} size={ThumbnailSize::Page} />
                 }
                 <YoutubeIframe videoid={thumbnail.video_id.clone()} />
             </div>
@@ -20,26 +20,34 @@ use std::rc::Rc;
 use dearrow_browser_api::unsync::{InnertubeVideo, Video};
 use cloneable_errors::{anyhow, ErrContext, ErrorContext, ResContext};
 use gloo_console::error;
+use reqwest::Url;
 use yew::prelude::*;
 use yew_hooks::{use_async_with_options, UseAsyncHandle, UseAsyncOptions};
 use yew_router::prelude::Link;
 
+use crate::components::bookmarks::BookmarkButton;
 use crate::components::icon::{Icon, IconType};
+use crate::components::links::ReportIssueLink;
 use crate::components::tables::details::*;
 use crate::components::tables::switch::{ModeSubtype, TableMode, TableModeSwitch};
 use crate::components::youtube::{OriginalTitle, YoutubeIframe};
-use crate::contexts::WindowContext;
+use crate::contexts::{SettingsContext, WindowContext};
 use crate::hooks::{use_async_suspension, use_location_state};
 use crate::innertube::{self, youtu_be_link};
 use crate::pages::MainRoute;
-use crate::thumbnails::components::{Thumbnail, ThumbnailCaption};
+use crate::settings::BookmarkKind;
+use crate::thumbnails::components::{Thumbnail, ThumbnailCaption, ThumbnailSize};
 use crate::utils::{api_request, sbb_video_link, RcEq};
 
 #[derive(Properties, PartialEq)]
 struct VideoDetailsTableProps {
     videoid: AttrValue,
     mode: TableMode,
+    /// The original title is shown in an [`OriginalTitlePanel`] instead
+    hide_original_title: bool,
     metadata: UseAsyncHandle<Rc<Video>, RcEq<ErrorContext>>,
+    /// API URL of the currently displayed details, included in issue reports
+    api_url: Rc<Url>,
 }
 
 #[function_component]
@@ -55,12 +63,15 @@ fn VideoDetailsTable(props: &VideoDetailsTableProps) -> Html {
     };
     html! {
         <div class="info-table">
-            <div>{format!("Video ID: {}", props.videoid)}</div>
+            <div>
+                {format!("Video ID: {} ", props.videoid)}
+                <BookmarkButton kind={BookmarkKind::Video} id={props.videoid.clone()} />
+            </div>
             <div>
                 {"Channel: "}
                 <Suspense fallback={fallback.clone()}><ChannelLink videoid={props.videoid.clone()} /></Suspense>
             </div>
-            <div hidden={props.mode != TableMode::Titles}>
+            <div hidden={props.hide_original_title || !matches!(props.mode, TableMode::Titles | TableMode::All)}>
                 {"Original title: "}
                 <Suspense {fallback}><OriginalTitle videoid={props.videoid.clone()} /></Suspense>
             </div>
@@ -68,12 +79,12 @@ fn VideoDetailsTable(props: &VideoDetailsTableProps) -> Html {
                 <div><em>{"Loading extra metadata..."}</em></div>
             } else if let Some(ref data) = props.metadata.data {
                 if let Some(duration) = data.duration {
-                    if props.mode == TableMode::Thumbnails {
+                    if matches!(props.mode, TableMode::Thumbnails | TableMode::All) {
                         <div>{format!("Random thumbnail timestamp: {}", duration*data.random_thumbnail)}</div>
                     }
                     <div>{format!("Video duration: {duration}")}</div>
                 } else {
-                    if props.mode == TableMode::Thumbnails {
+                    if matches!(props.mode, TableMode::Thumbnails | TableMode::All) {
                         <div>{format!("Random thumbnail: {}%", data.random_thumbnail*100.)}</div>
                     }
                     <div>{"Video duration: "}<em>{"Unknown"}</em></div>
@@ -89,12 +100,37 @@ fn VideoDetailsTable(props: &VideoDetailsTableProps) -> Html {
                         {"No"}
                     }
                 </div>
+                if matches!(props.mode, TableMode::Thumbnails | TableMode::All) {
+                    <div title="The random thumbnail is never picked from the outro of the video">
+                        {"Random thumbnail excludes: "}
+                        if data.has_outro {
+                            {"the marked outro"}
+                        } else {
+                            {"the last 10% of the video"}
+                        }
+                    </div>
+                }
             } else {
                 <div><em>{"Failed to fetch extra metadata."}</em></div>
             }
 
             <div><a href={&*youtube_url}>{"View on YouTube"}</a></div>
             <div><a href={&*sbb_url}>{"View on SB Browser"}</a></div>
+            <div><ReportIssueLink subject={format!("video {}", props.videoid)} api_url={props.api_url.clone()} /></div>
+        </div>
+    }
+}
+
+/// Keeps the original title in view above the titles table, for comparing it with submissions
+#[function_component]
+fn OriginalTitlePanel(props: &VideoPageProps) -> Html {
+    let fallback = html! {
+        <span><em>{"Loading..."}</em></span>
+    };
+    html! {
+        <div class="original-title-panel">
+            <span class="original-title-label">{"Original title: "}</span>
+            <Suspense {fallback}><OriginalTitle videoid={props.videoid.clone()} /></Suspense>
         </div>
     }
 }
@@ -121,7 +157,7 @@ fn ChannelLink(props: &VideoPageProps) -> HtmlResult {
             };
             match url
                 .path_segments()
-                .and_then(|ps| ps.filter(|s| !s.is_empty()).last())
+                .and_then(|mut ps| ps.rfind(|s| !s.is_empty()))
             {
                 Some(handle) => Ok(AttrValue::from(handle.to_owned())),
                 None => {
@@ -155,7 +191,10 @@ pub struct VideoPageProps {
 #[function_component]
 pub fn VideoPage(props: &VideoPageProps) -> Html {
     let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
+    let settings_context: SettingsContext = use_context().expect("SettingsContext should be available");
     let state = use_location_state().get_state();
+    let compare_original_title = settings_context.settings().compare_original_title
+        && matches!(state.detail_table_mode, TableMode::Titles | TableMode::All);
     let entry_count = use_state_eq(|| None);
 
     let metadata: UseAsyncHandle<Rc<Video>, RcEq<ErrorContext>> = {
@@ -165,14 +204,14 @@ pub fn VideoPage(props: &VideoPageProps) -> Html {
             async move {
                 async move {
                     let dab_api_url =
-                        window_context.origin_join_segments(&["api", "videos", &video_id]);
+                        window_context.api_join_segments(&["api", "videos", &video_id]);
                     let mut video: Video = api_request(dab_api_url)
                         .await
                         .context("Metadata request failed")?;
 
                     if video.duration.is_none() {
                         let it_duration: Result<u64, ErrorContext> = async move {
-                            let it_dab_url = window_context.origin_join_segments(&[
+                            let it_dab_url = window_context.api_join_segments(&[
                                 "innertube",
                                 "video",
                                 &video_id,
@@ -202,28 +241,12 @@ pub fn VideoPage(props: &VideoPageProps) -> Html {
         )
     };
 
-    let url_and_mode = use_memo(
-        (state.detail_table_mode, props.videoid.clone()),
-        |(dtm, vid)| {
-            DetailType::try_from(*dtm).ok().map(|dtm| match dtm {
-                DetailType::Title => (
-                    Rc::new(
-                        window_context.origin_join_segments(&["api", "titles", "video_id", vid]),
-                    ),
-                    dtm,
-                ),
-                DetailType::Thumbnail => (
-                    Rc::new(window_context.origin_join_segments(&[
-                        "api",
-                        "thumbnails",
-                        "video_id",
-                        vid,
-                    ])),
-                    dtm,
-                ),
-            })
-        },
-    );
+    let urls = use_memo(props.videoid.clone(), |vid| {
+        (
+            Rc::new(window_context.api_join_segments(&["api", "titles", "video_id", vid])),
+            Rc::new(window_context.api_join_segments(&["api", "thumbnails", "video_id", vid])),
+        )
+    });
 
     let rc_videoid = use_memo(props.videoid.clone(), |videoid| match videoid {
         AttrValue::Rc(ref rc) => rc.clone(),
@@ -231,31 +254,44 @@ pub fn VideoPage(props: &VideoPageProps) -> Html {
     });
 
     let fallback = html! {
-        <center><b>{"Loading..."}</b></center>
+        <DetailTableSkeleton hide_videoid=true kind={match state.detail_table_mode {
+            TableMode::All => DetailTableKind::All,
+            TableMode::Thumbnails => DetailTableKind::Thumbnails,
+            _ => DetailTableKind::Titles,
+        }} />
     };
 
     html! {
         <>
             <div class="page-details">
                 <YoutubeIframe videoid={props.videoid.clone()} />
-                if state.detail_table_mode == TableMode::Thumbnails {
-                    <Thumbnail video_id={(*rc_videoid).clone()} timestamp={None} caption={ThumbnailCaption::Text("Original thumbnail".into())} />
+                if matches!(state.detail_table_mode, TableMode::Thumbnails | TableMode::All) {
+                    <Thumbnail video_id={(*rc_videoid).clone()} timestamp={None} caption={ThumbnailCaption::Text("Original thumbnail".into())} size={ThumbnailSize::Page} />
                     if let Some(ref metadata) = metadata.data {
                         if let Some(duration) = metadata.duration {
-                            <Thumbnail video_id={(*rc_videoid).clone()} timestamp={Some(duration*metadata.random_thumbnail)} caption={ThumbnailCaption::Text("Random thumbnail".into())} />
+                            <Thumbnail video_id={(*rc_videoid).clone()} timestamp={Some(duration*metadata.random_thumbnail)} caption={ThumbnailCaption::Text("Random thumbnail".into())} size={ThumbnailSize::Page} />
                         }
                     }
                 }
-                <VideoDetailsTable videoid={props.videoid.clone()} mode={state.detail_table_mode} {metadata} />
+                <VideoDetailsTable videoid={props.videoid.clone()} mode={state.detail_table_mode} hide_original_title={compare_original_title} {metadata} api_url={if state.detail_table_mode == TableMode::Thumbnails { urls.1.clone() } else { urls.0.clone() }} />
             </div>
-            <TableModeSwitch entry_count={*entry_count} types={ModeSubtype::Details} />
-            if let Some((url, mode)) = url_and_mode.as_ref() {
-                <Suspense {fallback}>
-                    <PaginatedDetailTableRenderer mode={*mode} url={url.clone()} entry_count={entry_count.setter()} hide_videoid=true />
-                </Suspense>
-            } else {
-                {fallback}
+            <TableModeSwitch entry_count={*entry_count} types={ModeSubtype::Details | ModeSubtype::Combined} />
+            if compare_original_title {
+                <OriginalTitlePanel videoid={props.videoid.clone()} />
             }
+            <Suspense {fallback}>
+                {match state.detail_table_mode {
+                    TableMode::All => html! {
+                        <PaginatedMergedDetailTableRenderer titles_url={urls.0.clone()} thumbnails_url={urls.1.clone()} entry_count={entry_count.setter()} hide_videoid=true />
+                    },
+                    TableMode::Thumbnails => html! {
+                        <PaginatedDetailTableRenderer mode={DetailType::Thumbnail} url={urls.1.clone()} entry_count={entry_count.setter()} hide_videoid=true />
+                    },
+                    _ => html! {
+                        <PaginatedDetailTableRenderer mode={DetailType::Title} url={urls.0.clone()} entry_count={entry_count.setter()} hide_videoid=true />
+                    },
+                }}
+            </Suspense>
         </>
     }
 }
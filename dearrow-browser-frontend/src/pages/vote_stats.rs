@@ -0,0 +1,114 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::rc::Rc;
+
+use dearrow_browser_api::unsync::{VoteHistograms, VoteStatsResponse};
+use yew::prelude::*;
+
+use crate::components::bar_chart::BarChart;
+use crate::contexts::{StatusContext, WindowContext};
+use crate::hooks::use_async_suspension;
+use crate::utils::api_request;
+
+/// Labels for buckets given by their inclusive lower bounds
+fn bucket_labels(buckets: &[i8]) -> Rc<[AttrValue]> {
+    buckets
+        .iter()
+        .enumerate()
+        .map(|(i, &lower)| {
+            let next = buckets.get(i + 1).copied();
+            AttrValue::from(match next {
+                None => format!("{lower}+"),
+                Some(next) if lower == i8::MIN => format!("< {next}"),
+                Some(next) if next - 1 == lower => lower.to_string(),
+                Some(next) => format!("{lower}-{}", next - 1),
+            })
+        })
+        .collect()
+}
+
+#[derive(Properties, PartialEq)]
+struct HistogramsProps {
+    name: AttrValue,
+    labels: Rc<[AttrValue]>,
+    histograms: Rc<VoteHistograms>,
+    high_score_threshold: i8,
+}
+
+#[function_component]
+fn Histograms(props: &HistogramsProps) -> Html {
+    let votes: Rc<[usize]> = props.histograms.votes.as_slice().into();
+    let downvotes: Rc<[usize]> = props.histograms.downvotes.as_slice().into();
+    html! {
+        <div>
+            <h3>{props.name.clone()}</h3>
+            <table class="info-table">
+                <tr><th>{"Locked"}</th><td>{props.histograms.locked}</td></tr>
+                <tr><th>{format!("Unlocked, score {} or higher", props.high_score_threshold)}</th><td>{props.histograms.high_score_unlocked}</td></tr>
+            </table>
+            <BarChart labels={props.labels.clone()} values={votes} title="Upvotes" />
+            <BarChart labels={props.labels.clone()} values={downvotes} title="Downvotes" />
+        </div>
+    }
+}
+
+#[function_component]
+fn VoteStats() -> HtmlResult {
+    let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
+    let status: StatusContext = use_context().expect("StatusContext should be defined");
+    let stats = use_async_suspension(|_| async move {
+        let url = window_context.api_join_segments(&["api", "stats", "votes"]);
+        api_request::<_, VoteStatsResponse>(url).await
+    }, status.map(|s| s.last_updated))?;
+
+    Ok(match *stats {
+        Err(ref e) => html! {
+            <center>
+                <b>{"Failed to fetch vote statistics from the API :/"}</b>
+                <pre>{format!("{e:?}")}</pre>
+            </center>
+        },
+        Ok(ref stats) => {
+            let labels = bucket_labels(&stats.buckets);
+            html! {
+                <div class="vote-stats">
+                    <Histograms name="Titles" labels={labels.clone()} histograms={Rc::new(stats.titles.clone())} high_score_threshold={stats.high_score_threshold} />
+                    <Histograms name="Thumbnails" {labels} histograms={Rc::new(stats.thumbnails.clone())} high_score_threshold={stats.high_score_threshold} />
+                </div>
+            }
+        },
+    })
+}
+
+#[function_component]
+pub fn VoteStatsPage() -> Html {
+    let fallback = html! {
+        <center><b>{"Loading..."}</b></center>
+    };
+
+    html! {
+        <>
+            <h2>{"Vote statistics"}</h2>
+            <p>{"Distribution of upvotes and downvotes of titles and thumbnails. Removed and shadowhidden submissions are not counted."}</p>
+            <Suspense {fallback}>
+                <VoteStats />
+            </Suspense>
+        </>
+    }
+}
@@ -0,0 +1,73 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::rc::Rc;
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::components::tables::warnings::PaginatedWarningsTable;
+use crate::contexts::WindowContext;
+use crate::hooks::use_location_state;
+
+#[function_component]
+pub fn WarningsPage() -> Html {
+    let window_context: Rc<WindowContext> = use_context().expect("WindowContext should be defined");
+    let entry_count = use_state_eq(|| None);
+    let active_only = use_state_eq(|| false);
+    let state_handle = use_location_state();
+
+    let url = use_memo(*active_only, |active_only| {
+        let mut url = window_context.api_join_segments(&["api", "warnings"]);
+        if *active_only {
+            url.query_pairs_mut().append_pair("active_only", "true");
+        }
+        Rc::new(url)
+    });
+    let toggle_active_only = {
+        let active_only = active_only.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            active_only.set(input.checked());
+            // the filtered list may have less pages
+            let mut state = state_handle.get_state();
+            state.detail_table_page = 0;
+            state_handle.replace_state(state);
+        })
+    };
+
+    html! {
+        <>
+            <h2>{"Warnings"}</h2>
+            <div>
+                <label for="warnings_active_only">{"Only show active warnings: "}</label>
+                <input id="warnings_active_only" type="checkbox" onchange={toggle_active_only} checked={*active_only} />
+            </div>
+            if let Some(count) = *entry_count {
+                <span>
+                    if count == 1 {
+                        {"1 entry"}
+                    } else {
+                        {format!("{count} entries")}
+                    }
+                </span>
+            }
+            <PaginatedWarningsTable url={(*url).clone()} entry_count={entry_count.setter()} />
+        </>
+    }
+}
@@ -18,9 +18,14 @@
 
 use std::{num::NonZeroUsize, rc::Rc};
 
+use chrono::FixedOffset;
+use cloneable_errors::{bail, ErrorContext, ResContext};
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use strum::{EnumString, VariantNames, IntoStaticStr};
 
+use crate::utils::DisplayTimezone;
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 #[serde(default)]
 pub struct Settings {
@@ -29,10 +34,33 @@ pub struct Settings {
     pub title_table_layout: TableLayout,
     pub thumbnail_table_layout: TableLayout,
     pub render_thumbnails_in_tables: bool,
+    /// Thumbnails scoring below this are not rendered in tables until clicked
+    pub min_thumbnail_score: Option<i8>,
+    /// Request thumbnails from the generator at a height matching the layout they're shown in
+    pub sized_thumbnail_requests: bool,
+    pub always_show_vote_breakdown: bool,
+    /// Render flags in tables as short text labels instead of icons
+    pub text_flag_labels: bool,
+    pub hide_original_titles: bool,
+    /// Show the original title in a panel above the titles table on video pages
+    pub compare_original_title: bool,
+    pub group_homepage_by_video: bool,
+    pub lazy_video_embed: bool,
+    /// Reload tables as soon as the server loads new data, instead of offering a refresh
+    pub auto_refresh_data: bool,
+    pub now_playing_listener: bool,
     pub disable_sharedworker: bool,
     pub private_user_id: Option<Rc<str>>,
     pub sponsorblock_api_base_url: Rc<str>,
+    /// Base URL of the server to load data from, the origin of the app is used if `None`
+    pub api_base_url: Option<Rc<str>>,
     pub enable_autosearch: bool,
+    pub timezone: TimezoneMode,
+    /// Offset from UTC used by [`TimezoneMode::Fixed`], in minutes
+    pub timezone_offset_minutes: i32,
+    pub bookmarks: Vec<Bookmark>,
+    /// Hash of the last server notice dismissed by the user
+    pub dismissed_notice: Option<Rc<str>>,
 }
 
 impl Default for Settings {
@@ -43,11 +71,100 @@ impl Default for Settings {
             title_table_layout: TableLayout::Expanded,
             thumbnail_table_layout: TableLayout::Expanded,
             render_thumbnails_in_tables: false,
+            min_thumbnail_score: None,
+            sized_thumbnail_requests: false,
+            always_show_vote_breakdown: false,
+            text_flag_labels: false,
+            hide_original_titles: false,
+            compare_original_title: false,
+            group_homepage_by_video: false,
+            lazy_video_embed: false,
+            auto_refresh_data: false,
+            now_playing_listener: false,
             disable_sharedworker: false,
             private_user_id: None,
             sponsorblock_api_base_url: "https://sponsor.ajay.app/".into(),
+            api_base_url: None,
             enable_autosearch: true,
+            timezone: TimezoneMode::Local,
+            timezone_offset_minutes: 0,
+            bookmarks: Vec::new(),
+            dismissed_notice: None,
+        }
+    }
+}
+
+impl Settings {
+    pub fn is_bookmarked(&self, kind: BookmarkKind, id: &str) -> bool {
+        self.bookmarks.iter().any(|b| b.kind == kind && *b.id == *id)
+    }
+
+    /// Adds a bookmark for the given video or user, or removes it if it already exists
+    pub fn toggle_bookmark(&mut self, kind: BookmarkKind, id: &str) {
+        if self.is_bookmarked(kind, id) {
+            self.remove_bookmark(kind, id);
+        } else {
+            self.bookmarks.push(Bookmark { kind, id: id.into(), label: None });
+        }
+    }
+
+    /// Removes all bookmarks of the given video or user
+    pub fn remove_bookmark(&mut self, kind: BookmarkKind, id: &str) {
+        self.bookmarks.retain(|b| b.kind != kind || *b.id != *id);
+    }
+
+    /// Returns the timezone in which timestamps should be displayed
+    pub fn display_timezone(&self) -> DisplayTimezone {
+        match self.timezone {
+            TimezoneMode::Local => DisplayTimezone::Local,
+            TimezoneMode::Utc => DisplayTimezone::Utc,
+            TimezoneMode::Fixed => FixedOffset::east_opt(self.timezone_offset_minutes * 60)
+                .map_or(DisplayTimezone::Utc, DisplayTimezone::Fixed),
+        }
+    }
+
+    /// Serializes the settings for backups or transferring to another browser.
+    ///
+    /// The private userID is left out of the export.
+    pub fn export(&self) -> String {
+        let settings = Settings {
+            private_user_id: None,
+            ..self.clone()
+        };
+        serde_json::to_string_pretty(&settings).expect("Settings should be serializable")
+    }
+
+    /// Parses settings exported with [`Settings::export`], keeping the current private userID.
+    ///
+    /// Missing fields are set to their defaults.
+    pub fn import(&self, exported: &str) -> Result<Settings, ErrorContext> {
+        let mut settings: Settings = serde_json::from_str(exported.trim()).context("Failed to parse the settings")?;
+        for (name, url) in [
+            ("thumbgen_api_base_url", Some(&settings.thumbgen_api_base_url)),
+            ("sponsorblock_api_base_url", Some(&settings.sponsorblock_api_base_url)),
+            ("api_base_url", settings.api_base_url.as_ref()),
+        ] {
+            let Some(url) = url else {
+                continue;
+            };
+            let url = Url::parse(url).with_context(|| format!("{name} is not a valid URL"))?;
+            if url.cannot_be_a_base() || url.scheme() != "https" {
+                bail!(format!("{name} must be a https:// base URL"));
+            }
         }
+        settings.private_user_id.clone_from(&self.private_user_id);
+        Ok(settings)
+    }
+
+    /// Names of the fields which differ between the two settings
+    pub fn changed_fields(&self, other: &Settings) -> Vec<String> {
+        let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(new))) = (serde_json::to_value(self), serde_json::to_value(other)) else {
+            return vec![];
+        };
+        new.into_iter()
+            .filter(|(name, value)| old.get(name) != Some(value))
+            .map(|(name, _)| name)
+            .collect()
     }
 }
 
@@ -61,3 +178,31 @@ pub enum TableLayout {
     #[serde(rename="expanded", other)]
     Expanded,
 }
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumString, IntoStaticStr, VariantNames, Debug)]
+pub enum TimezoneMode {
+    #[serde(rename="utc")]
+    #[strum(serialize="UTC")]
+    Utc,
+    #[serde(rename="fixed")]
+    #[strum(serialize="Fixed offset")]
+    Fixed,
+    #[serde(rename="local", other)]
+    Local,
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct Bookmark {
+    pub kind: BookmarkKind,
+    pub id: Rc<str>,
+    #[serde(default)]
+    pub label: Option<Rc<str>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum BookmarkKind {
+    #[serde(rename="video")]
+    Video,
+    #[serde(rename="user")]
+    User,
+}
@@ -25,6 +25,9 @@ use serde::{Deserialize, Serialize};
 pub struct ThumbnailKey {
     pub video_id: Rc<str>,
     pub timestamp: Rc<str>,
+    /// Height of the requested frame in pixels, the generator picks the size if `None`
+    #[serde(default)]
+    pub height: Option<u16>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
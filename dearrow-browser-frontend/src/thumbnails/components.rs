@@ -16,12 +16,12 @@
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::rc::Rc;
+use std::{cell::Cell, rc::Rc};
 
 use gloo_console::{error, log};
 use reqwest::Url;
-use yew::prelude::*;
-use yew_hooks::{use_async_with_options, UseAsyncHandle, UseAsyncOptions};
+use yew::{platform::spawn_local, prelude::*};
+use yew_hooks::{use_async_with_options, use_visible, UseAsyncHandle, UseAsyncOptions};
 
 use crate::components::modals::{thumbnail::ThumbnailModal, ModalMessage};
 use crate::hooks::use_async_suspension;
@@ -31,8 +31,12 @@ use crate::utils::RcEq;
 use super::common::{ThumbgenStats, ThumbnailKey};
 use super::local::{LocalBlobLink, LocalThumbGenerator};
 use super::remote::{Error, RemoteBlobLink, ThumbnailWorker};
+use super::utils::sleep;
 use super::worker_api::{ThumbnailWorkerRequest, WorkerSetting};
 
+/// How long a lazy thumbnail has to stay in view before it gets requested
+const LAZY_THUMBNAIL_DELAY_MS: u32 = 150;
+
 #[derive(Clone, Eq, PartialEq)]
 pub enum Thumbgen {
     Remote(ThumbnailWorker),
@@ -77,7 +81,7 @@ impl Thumbgen {
         match self {
             Self::Remote(worker) => drop(worker.request(ThumbnailWorkerRequest::ClearErrors).await),
             Self::Local { gen, .. } => drop(gen.clear_errors()),
-        };
+        }
     }
 }
 
@@ -177,7 +181,7 @@ pub fn ThumbgenProvider(props: &ThumbnailGeneratorProviderProps) -> Html {
                 let errors_removed = r#gen.clear_errors();
                 log!(format!("Cleared {errors_removed} error entries after updating thumbgen API URL"));
             }
-        };
+        }
         refresh_state.trigger_refresh();
     });
 
@@ -210,15 +214,48 @@ pub fn BaseThumbnail(props: &BaseThumbnailProps) -> HtmlResult {
     })
 }
 
+/// Where a thumbnail is rendered, mapped to a requested frame height if the
+/// `sized_thumbnail_requests` setting is enabled
+#[derive(PartialEq, Eq, Clone, Copy, Default)]
+pub enum ThumbnailSize {
+    /// Let the generator pick the size
+    #[default]
+    Default,
+    /// Detail table cells
+    Table,
+    /// Video and UUID page headers
+    Page,
+    /// The thumbnail preview modal
+    Modal,
+}
+
+impl ThumbnailSize {
+    fn height(self) -> Option<u16> {
+        match self {
+            ThumbnailSize::Default => None,
+            ThumbnailSize::Table => Some(144),
+            ThumbnailSize::Page => Some(360),
+            ThumbnailSize::Modal => Some(720),
+        }
+    }
+}
+
 #[derive(Properties, PartialEq, Clone)]
 pub struct UnwrappedThumbnailProps {
     pub video_id: Rc<str>,
     /// none means original thumb
     pub timestamp: Option<f64>,
+    /// if false, a placeholder is shown instead of requesting a generated thumbnail
+    #[prop_or(true)]
+    pub load: bool,
+    #[prop_or_default]
+    pub size: ThumbnailSize,
 }
 
 #[function_component]
 pub fn UnwrappedThumbnail(props: &UnwrappedThumbnailProps) -> Html {
+    let settings_context: SettingsContext = use_context().expect("SettingsContext should be available");
+    let sized_requests = settings_context.settings().sized_thumbnail_requests;
     let timestamp: Rc<Rc<str>> = use_memo(props.clone(), |props| {
         match props.timestamp {
             None => innertube::original_thumbnail_url(&props.video_id).as_str().into(),
@@ -229,11 +266,14 @@ pub fn UnwrappedThumbnail(props: &UnwrappedThumbnailProps) -> Html {
         html! {
             <img class="thumbnail" src={(*timestamp).clone()} />
         }
+    } else if !props.load {
+        html! {<span class="thumbnail-error">{"Waiting to scroll into view..."}</span>}
     } else {
         let fallback = html! {<span class="thumbnail-error">{"Generating thumbnail..."}</span>};
         let thumb_key = ThumbnailKey {
             video_id: props.video_id.clone(),
             timestamp: (*timestamp).clone(),
+            height: if sized_requests { props.size.height() } else { None },
         };
         html! {
             <Suspense {fallback}>
@@ -269,17 +309,58 @@ pub struct WrappedThumbnailProps {
     pub caption: ThumbnailCaption,
     #[prop_or_default]
     pub container_type: ContainerType,
+    /// only request generated thumbnails while the container is scrolled into view
+    #[prop_or_default]
+    pub lazy: bool,
+    #[prop_or_default]
+    pub size: ThumbnailSize,
+}
+
+/// Returns whether the node has stayed visible for at least [`LAZY_THUMBNAIL_DELAY_MS`].
+///
+/// Goes back to false as soon as the node is scrolled out of view, which unmounts the
+/// suspended thumbnail and discards its pending request. Always true if not enabled.
+#[hook]
+fn use_lazy_load(node: NodeRef, enabled: bool) -> bool {
+    let visible = use_visible(node, false);
+    let load = use_state_eq(|| false);
+    {
+        let load = load.clone();
+        use_effect_with(visible, move |&visible| {
+            let cancelled = Rc::new(Cell::new(false));
+            if visible {
+                let cancelled = cancelled.clone();
+                spawn_local(async move {
+                    sleep(LAZY_THUMBNAIL_DELAY_MS).await;
+                    if !cancelled.get() {
+                        load.set(true);
+                    }
+                });
+            } else {
+                load.set(false);
+            }
+            move || cancelled.set(true)
+        });
+    }
+    !enabled || *load
 }
 
 #[function_component]
 pub fn Thumbnail(props: &WrappedThumbnailProps) -> Html {
     let modal_controls: ModalRendererControls = use_context().expect("ModalRendererControls should be available");
+    let container_ref = use_node_ref();
+    let load = use_lazy_load(container_ref.clone(), props.lazy);
     let unwrapped_props = UnwrappedThumbnailProps {
         video_id: props.video_id.clone(),
         timestamp: props.timestamp,
+        load,
+        size: props.size,
     };
     let onclick = {
-        let props = unwrapped_props.clone();
+        let props = UnwrappedThumbnailProps {
+            load: true,
+            ..unwrapped_props.clone()
+        };
         Callback::from(move |_| {
             modal_controls.emit(ModalMessage::Open(html! {
                 <ThumbnailModal ..props.clone() />
@@ -298,10 +379,10 @@ pub fn Thumbnail(props: &WrappedThumbnailProps) -> Html {
     };
     match props.container_type {
         ContainerType::div => html! {
-            <div class="thumbnail-container clickable" {onclick}>{content}</div>
+            <div class="thumbnail-container clickable" ref={container_ref} {onclick}>{content}</div>
         },
         ContainerType::td => html! {
-            <td class="thumbnail-container clickable" {onclick}>{content}</td>
+            <td class="thumbnail-container clickable" ref={container_ref} {onclick}>{content}</td>
         },
     }
 }
@@ -138,6 +138,9 @@ async fn generate_thumb(mut url: Url, key: &ThumbnailKey) -> Result<LocalBlobLin
     url.query_pairs_mut()
         .append_pair("videoID", &key.video_id)
         .append_pair("time", &key.timestamp);
+    if let Some(height) = key.height {
+        url.query_pairs_mut().append_pair("height", &height.to_string());
+    }
     let response = GLOBAL.with(Clone::clone).fetch(url.as_str()).await.map_err(ThumbnailGenerationError::JSError)?;
 
     let status = response.status();
@@ -20,5 +20,5 @@ pub mod local;
 pub mod remote;
 pub mod worker_api;
 pub mod components;
-mod utils;
+pub(crate) mod utils;
 pub mod common;
@@ -48,7 +48,7 @@ impl Drop for RemoteBlobLink {
     fn drop(&mut self) {
         if let Err(err) = self.worker.post_request(ThumbnailWorkerRequest::BlobLinkDropped { ref_id: self.ref_id }) {
             err.log("Failed to notify worker about a RemoteBlobLink being dropped");
-        };
+        }
     }
 }
 
@@ -157,7 +157,7 @@ impl ThumbnailWorker {
     pub async fn new() -> Result<ThumbnailWorker, Error> {
         // Create the shared worker
         let worker = SharedWorker::new_with_worker_options(
-            "/thumbnails/worker_loader.mjs",
+            "thumbnails/worker_loader.mjs",
             &{
                 let opts = WorkerOptions::new();
                 opts.set_name("thumbnails_worker");
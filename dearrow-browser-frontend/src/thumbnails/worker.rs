@@ -250,7 +250,7 @@ impl WorkerContext {
             ThumbnailWorkerRequest::Version { version, git_hash, git_dirty } => {
                 if version != built_info::PKG_VERSION || git_hash.as_deref() != built_info::GIT_COMMIT_HASH || git_dirty != built_info::GIT_DIRTY {
                     warn!(format!("Version mismatch detected! Message (de)serialization errors may occur!\nNew client's version: {version}, git hash: {git_hash:?}, git dirty: {git_dirty:?}\nWorker version: {}, git hash: {:?}, git dirty: {:?}\nClose all DeArrow Browser windows to resolve this issue.", built_info::PKG_VERSION, built_info::GIT_COMMIT_HASH, built_info::GIT_DIRTY));
-                };
+                }
                 
                 ThumbnailWorkerResponse::Version { 
                     version: built_info::PKG_VERSION.to_owned(), 
@@ -380,6 +380,7 @@ fn main() {
     log!("Backlog processed!");
 }
 
+#[allow(clippy::needless_raw_string_hashes, clippy::doc_markdown)]
 pub mod built_info {
     // Contents generated by buildscript, using built
     include!(concat!(env!("OUT_DIR"), "/built.rs"));
@@ -15,7 +15,7 @@
 *  You should have received a copy of the GNU Affero General Public License
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
-import wasm_loader from '/thumbnails_worker.js';
+import wasm_loader from '../thumbnails_worker.js';
 
 // this will store missed 'connect' events until the wasm binary boots up
 // so we don't have to do the weird "ping the worker first" hack on the page
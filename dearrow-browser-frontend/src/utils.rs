@@ -17,27 +17,50 @@
 */
 use std::{ops::Deref, rc::Rc, fmt::Write};
 
-use chrono::{DateTime, Utc, NaiveDateTime};
-use cloneable_errors::{bail, ErrContext, ErrorContext, ResContext, SerializableError};
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, Utc};
+use cloneable_errors::{anyhow, bail, ErrContext, ErrorContext, ResContext, SerializableError};
 use reqwest::Url;
 use sha2::{digest::array::Array, Digest, Sha256};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{js_sys, Blob, BlobPropertyBag, HtmlAnchorElement};
 use yew::Html;
 
 use crate::constants::{REQWEST_CLIENT, SBB_BASE};
 
 const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
 
-pub fn render_datetime(dt: DateTime<Utc>) -> String 
+/// The timezone in which timestamps are displayed, see [`crate::settings::Settings::display_timezone`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DisplayTimezone {
+    Local,
+    Utc,
+    Fixed(FixedOffset),
+}
+
+pub fn render_datetime(dt: DateTime<Utc>, tz: DisplayTimezone) -> String 
 {
-    format!("{}", dt.format(TIME_FORMAT))
+    match tz {
+        DisplayTimezone::Local => format!("{}", dt.with_timezone(&Local).format(TIME_FORMAT)),
+        DisplayTimezone::Utc => format!("{}", dt.format(TIME_FORMAT)),
+        DisplayTimezone::Fixed(offset) => format!("{}", dt.with_timezone(&offset).format(TIME_FORMAT)),
+    }
+}
+/// Renders the name of the timezone in effect at the given time, like `UTC` or `UTC+02:00`
+pub fn render_timezone(dt: DateTime<Utc>, tz: DisplayTimezone) -> String
+{
+    match tz {
+        DisplayTimezone::Local => format!("UTC{}", dt.with_timezone(&Local).format("%:z")),
+        DisplayTimezone::Utc => "UTC".to_owned(),
+        DisplayTimezone::Fixed(offset) => format!("UTC{offset}"),
+    }
 }
 pub fn render_naive_datetime(dt: NaiveDateTime) -> String 
 {
     format!("{}", dt.format(TIME_FORMAT))
 }
-pub fn render_datetime_with_delta(dt: DateTime<Utc>) -> String
+pub fn render_datetime_with_delta(dt: DateTime<Utc>, tz: DisplayTimezone) -> String
 {
-    format!("{} UTC ({} minutes ago)", dt.format(TIME_FORMAT), (Utc::now()-dt).num_minutes())
+    format!("{} {} ({} minutes ago)", render_datetime(dt, tz), render_timezone(dt, tz), (Utc::now()-dt).num_minutes())
 }
 
 pub trait RenderNumber {
@@ -276,6 +299,36 @@ pub fn sbb_video_link(vid: &str) -> Url {
     url
 }
 
+/// Makes the browser download the given string as a file
+pub fn download_file(contents: &str, mime_type: &str, filename: &str) -> Result<(), ErrorContext> {
+    #[allow(clippy::needless_pass_by_value)]
+    fn js_err(err: JsValue) -> ErrorContext {
+        anyhow!(format!("{err:?}"))
+    }
+
+    let document = web_sys::window().context("Failed to get the window object")?
+        .document().context("Failed to get the document object")?;
+
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(&js_sys::Array::of1(&JsValue::from_str(contents)), &options)
+        .map_err(js_err).context("Failed to create the blob")?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)
+        .map_err(js_err).context("Failed to create an object URL")?;
+
+    let result = document.create_element("a")
+        .map_err(js_err).context("Failed to create the link element")
+        .and_then(|el| el.dyn_into::<HtmlAnchorElement>().map_err(|_| anyhow!("Created element was not an <a>")))
+        .map(|link| {
+            link.set_href(&url);
+            link.set_download(filename);
+            link.click();
+        });
+    // the download has already been started at this point
+    web_sys::Url::revoke_object_url(&url).map_err(js_err).context("Failed to revoke the object URL")?;
+    result
+}
+
 pub fn sbb_userid_link(uid: &str) -> Url {
     let mut url = SBB_BASE.clone();
     url.extend_segments(&["userid", uid]).expect("https://sb.ltn.fi/ should be a valid base");
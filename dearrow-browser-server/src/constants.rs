@@ -19,7 +19,7 @@ use std::{sync::{Arc, LazyLock}, time::Duration};
 use chrono::DateTime;
 use cloneable_errors::{ErrorContext, anyhow};
 use regex::Regex;
-use actix_web::http::StatusCode;
+use actix_web::http::{header::EntityTag, StatusCode};
 
 use crate::{built_info, innertube::BrowseMode};
 
@@ -27,12 +27,47 @@ pub static NUMBER_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d+").
 
 // Paths
 pub const CONFIG_PATH: &str = "config.toml";
+/// Commented template written as the default config, `auth_secret` gets replaced with a random one
+pub const CONFIG_TEMPLATE: &str = include_str!("../../config.toml.example");
+pub const CONFIG_TEMPLATE_SECRET: &str = r#"auth_secret = "set this to something random""#;
 pub const FSCACHE_TEMPDIR: &str = "tmp";
 pub const FSCACHE_PLAYLISTS: &str = "playlists";
+pub const FSCACHE_HANDLES: &str = "handles";
+pub const FSCACHE_DURATIONS: &str = "durations";
+/// File holding the string count of the last load, used as the initial `StringSet` capacity
+pub const FSCACHE_STRING_COUNT: &str = "string_count";
 
 // Limits
 pub static IT_TIMEOUT: Duration = Duration::from_secs(1);
-pub static FSCACHE_SIZE_CACHE_DURATION: Duration = Duration::from_secs(60);
+pub static FSCACHE_SIZE_CACHE_DURATION: Duration = Duration::from_mins(1);
+pub static FSCACHE_HANDLE_MAX_AGE: Duration = Duration::from_hours(30 * 24);
+pub const HANDLE_RESOLVE_RETRIES: u32 = 3;
+pub static HANDLE_RESOLVE_BACKOFF: Duration = Duration::from_secs(1);
+pub const HAS_DATA_MAX_BATCH: usize = 1000;
+pub const CHANNEL_SUMMARY_TOP_SUBMITTERS: usize = 10;
+/// Maximum amount of videos returned by the top videos endpoint
+pub const TOP_VIDEOS_MAX_COUNT: usize = 1024;
+/// Inclusive lower bounds of the buckets of the vote histograms, each bucket ends right before
+/// the next one starts and the last one is unbounded
+pub const VOTE_HISTOGRAM_BUCKETS: [i8; 9] = [i8::MIN, 0, 1, 2, 3, 5, 10, 20, 50];
+/// Minimum score of an unlocked title or thumbnail to be counted as high-score in the vote stats
+pub const HIGH_SCORE_THRESHOLD: i8 = 5;
+/// Amount of days included in the submissions per day stats
+pub const DAILY_SUBMISSIONS_DAYS: i64 = 30;
+/// Maximum amount of URLs in a single sitemap file, as defined by the sitemap protocol
+pub const SITEMAP_PAGE_SIZE: usize = 50_000;
+/// Paths of the api scope which keep working while the database is not loaded
+pub const DB_INDEPENDENT_PATHS: &[&str] = &["", "/", "/status", "/version", "/health", "/errors", "/reload", "/config"];
+/// How often the pre-reload command is checked for completion
+pub static PRE_RELOAD_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Amount of objects matched by a paginated endpoint, before applying `offset` and `count`
+pub const TOTAL_COUNT_HEADER: &str = "X-Total-Count";
+/// Amount of distinct videos discovered on a channel
+pub const CHANNEL_VIDEOS_HEADER: &str = "X-Channel-Videos";
+/// JSON pointers of secret `AppConfig` fields, hidden from the `/api/config` endpoint.
+///
+/// The pre-reload command is included, as sync commands often carry credentials.
+pub const REDACTED_CONFIG_KEYS: &[&str] = &["/auth_secret", "/innertube/po_token", "/innertube/visitor_data", "/pre_reload_command"];
 
 // Locking errors
 pub static SS_READ_ERR:  LazyLock<ErrorContext> = LazyLock::new(|| anyhow!("Failed to acquire StringSet for reading"));
@@ -58,12 +93,18 @@ pub const IT_RELEASES_SHELF_NAME: &str = "Albums & Singles";
 pub static UCID_EXTRACTION_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"externalId":"([^"]+)""#).expect("Should be able to parse the UCID extraction regex"));
 // https://github.com/yt-dlp/yt-dlp/blob/a065086640e888e8d58c615d52ed2f4f4e4c9d18/yt_dlp/extractor/youtube.py#L518-L519
 pub static UCID_REGEX:        LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^UC(?-u:[\w-]){22}$").expect("Should be able to parse the UCID regex"));
+pub static VIDEO_ID_REGEX:    LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[\w-]{11}$").expect("Should be able to parse the video ID regex"));
 pub static HANDLE_REGEX:      LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^@[\w.-]{3,30}$").expect("Should be able to parse the @handle regex"));
 
+// API versions served under /api/<version>, oldest first. /api is an alias of the last one.
+pub const API_VERSIONS: &[&str] = &["v1"];
+
 // Parsed built_info fields
 pub static SERVER_VERSION:  LazyLock<Arc<str>> = LazyLock::new(|| built_info::PKG_VERSION.into());
 pub static SERVER_GIT_HASH: LazyLock<Option<Arc<str>>> = LazyLock::new(|| built_info::GIT_COMMIT_HASH.map(std::convert::Into::into));
 pub static BUILD_TIMESTAMP: LazyLock<Option<i64>> = LazyLock::new(|| DateTime::parse_from_rfc2822(built_info::BUILT_TIME_UTC).ok().map(|t| t.timestamp()));
+/// Constant for the lifetime of the process, changes with every build
+pub static BUILD_ETAG: LazyLock<EntityTag> = LazyLock::new(|| EntityTag::new_strong(format!("{}-{}", built_info::PKG_VERSION, BUILD_TIMESTAMP.unwrap_or_default())));
 
 // Custom response status codes
 /// 333 Not ready yet - Indicates that the server is still querying requested data.
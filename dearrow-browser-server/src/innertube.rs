@@ -16,19 +16,19 @@
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::{collections::{HashSet, VecDeque}, ops::Deref, sync::{atomic::Ordering, Arc}, str::FromStr};
+use std::{collections::{HashSet, VecDeque}, ops::Deref, path::Path, sync::{atomic::Ordering, Arc}, str::FromStr};
 
-use actix_web::{get, http::StatusCode, web, Either, HttpResponse};
+use actix_web::{get, http::StatusCode, rt::time::sleep, web, Either, HttpResponse};
 use cloneable_errors::{anyhow, bail, ErrContext, ErrorContext, ResContext};
 use dearrow_browser_api::sync::{InnertubeChannel, InnertubeVideo, self as api};
-use log::{debug, warn};
+use log::{debug, info, warn};
 use reqwest::Client;
 use tokio::{fs::File, io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter}, task::JoinSet};
 use tokio_stream::{wrappers::LinesStream, StreamExt};
 
 use crate::{constants::*, utils::{ReqwestResponseExt, TemporaryFile}};
 use crate::middleware::etag::{ETagCache, ETagCacheControl};
-use crate::state::{self, AppConfig, DBLock, GetChannelOutput};
+use crate::state::{self, AppConfig, DBLock, GetChannelOutput, VideoDurationCache};
 use crate::utils::{self, ExtendResponder, ResponderExt};
 
 type JsonResult<T> = utils::Result<web::Json<T>>;
@@ -44,15 +44,80 @@ pub fn configure_enabled(cfg: &mut web::ServiceConfig) {
        // .service(get_playlist_endpoint);
 }
 
-async fn disabled_route() -> HttpResponse {
-    HttpResponse::NotFound().body("Innertube endpoints are disabled on this DeArrow Browser instance.")
+async fn disabled_route() -> utils::Result<HttpResponse> {
+    Err(utils::Error::from(anyhow!("Innertube endpoints are disabled on this DeArrow Browser instance.")).set_status(StatusCode::NOT_FOUND))
 }
 
 
-// https://github.com/ajayyy/DeArrow/blob/c4e1375380bc3b0cb202af283f0e7b4e5e6e30f1/src/thumbnails/thumbnailData.ts#L230
 #[get("/video/{video_id}")]
-async fn get_innertube_video(path: web::Path<String>, client: web::ThinData<Client>, config: web::Data<AppConfig>) -> JsonResult<InnertubeVideo> {
-    let vid = path.as_str();
+async fn get_innertube_video(path: web::Path<String>, cache: web::Data<VideoDurationCache>) -> JsonResult<InnertubeVideo> {
+    let duration = cache.get_duration(path.as_str()).await?;
+    Ok(web::Json(InnertubeVideo {
+        video_id: path.as_str().into(),
+        duration,
+    }))
+}
+
+/// Returns the duration of the given video, using the fscache if possible
+///
+/// Durations never change, so cache entries never expire.
+pub async fn get_video_duration(client: Client, config: Arc<AppConfig>, video_id: Arc<str>) -> Result<u64, ErrorContext> {
+    // don't let arbitrary strings near the filesystem
+    let fscache_path = VIDEO_ID_REGEX.is_match(&video_id).then(|| {
+        let mut path = config.cache_path.join(FSCACHE_DURATIONS);
+        path.push(&*video_id);
+        path
+    });
+    if let Some(ref path) = fscache_path {
+        if let Some(duration) = read_cached_duration(path, &video_id).await {
+            return Ok(duration);
+        }
+    }
+
+    let duration = fetch_video_duration(&client, &config, &video_id).await?;
+
+    if let Some(path) = fscache_path {
+        let fscache_tmpdir = config.cache_path.join(FSCACHE_TEMPDIR);
+        match TemporaryFile::new(path, &fscache_tmpdir).await {
+            Err(err) => {
+                warn!("Got an unexpected error while trying to open the duration cache entry for video '{video_id}' for writing: {err}");
+            },
+            Ok(mut file) => {
+                let result: std::io::Result<()> = async {
+                    file.write_all(duration.to_string().as_bytes()).await?;
+                    file.flush().await
+                }.await;
+                if let Err(err) = result {
+                    warn!("Got an unexpected error while trying to write the duration cache entry for video '{video_id}': {err}");
+                } else if let Err(err) = file.commit().await {
+                    warn!("Got an unexpected error while trying to commit the duration cache entry for video '{video_id}': {err:?}");
+                }
+            }
+        }
+    }
+
+    Ok(duration)
+}
+
+async fn read_cached_duration(path: &Path, video_id: &str) -> Option<u64> {
+    match tokio::fs::read_to_string(path).await {
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => {
+            warn!("Got an unexpected error while trying to read the duration cache entry for video '{video_id}': {err}");
+            None
+        },
+        Ok(duration) => match duration.trim().parse() {
+            Ok(duration) => Some(duration),
+            Err(err) => {
+                warn!("Duration cache entry for video '{video_id}' contained an invalid duration: {err}");
+                None
+            },
+        },
+    }
+}
+
+// https://github.com/ajayyy/DeArrow/blob/c4e1375380bc3b0cb202af283f0e7b4e5e6e30f1/src/thumbnails/thumbnailData.ts#L230
+async fn fetch_video_duration(client: &Client, config: &AppConfig, vid: &str) -> Result<u64, ErrorContext> {
     let url = IT_PLAYER_URL.clone();
     let input = {
         let mut context = it::Context::default();
@@ -72,14 +137,11 @@ async fn get_innertube_video(path: web::Path<String>, client: web::ThinData<Clie
     }
     let resp = req.send().await.context("Failed to send innertube request")?;
     let resp = resp.error_for_status().context("Innertube request failed")?;
-    let result: it::player::out::Video = resp.json().await.context("Failed to deserialize innertube response")?;
+    let result: it::player::out::Video = resp.json_debug("player", config.innertube.max_response_size).await.context("Failed to deserialize innertube response")?;
     if result.video_details.video_id != vid {
-        return Err(anyhow!("Innertube returned the wrong videoid - requested: {vid}, got: {}", result.video_details.video_id).into());
+        return Err(anyhow!("Innertube returned the wrong videoid - requested: {vid}, got: {}", result.video_details.video_id));
     }
-    Ok(web::Json(InnertubeVideo {
-        video_id: vid.into(),
-        duration: result.video_details.length_seconds,
-    }))
+    Ok(result.video_details.length_seconds)
 }
 
 #[get("/channel/{handle}", wrap="ETagCache")]
@@ -111,41 +173,112 @@ async fn get_channel_endpoint(path: web::Path<String>, db_lock: DBLock) -> JsonR
 //
 // #[get("/channel_albums/{handle}")]
 // async fn get_playlist_endpoint(path: web::Path<String>, client: web::ThinData<Client>, config: web::Data<AppConfig>) -> JsonResult<Vec<Vec<String>>> {
-//     let ucid = handle_to_ucid(&client, path.into_inner().as_str()).await?;
+//     let ucid = handle_to_ucid(&client, &config, path.into_inner().as_str()).await?;
 //     Ok(web::Json(browse_releases_tab((*client).clone(), config.clone().into_inner(), ucid.into(), Arc::default()).await?))
 // }
 
-pub async fn handle_to_ucid(client: &Client, handle: &str) -> Result<String, ErrorContext> {
+pub async fn handle_to_ucid(client: &Client, config: &AppConfig, handle: &str) -> Result<String, ErrorContext> {
     if UCID_REGEX.is_match(handle) {
         return Ok(handle.to_owned());
     }
 
-    let url = if HANDLE_REGEX.is_match(handle) {
-        let mut url = YT_BASE_URL.clone();
-        url.path_segments_mut().expect("YT_BASE_URL should be a base or smth")
-            .push(handle);
-        url
+    let handle = if HANDLE_REGEX.is_match(handle) {
+        handle.to_owned()
     } else {
         let handle = format!("@{handle}");
-        if HANDLE_REGEX.is_match(&handle) {
-            let mut url = YT_BASE_URL.clone();
-            url.path_segments_mut().expect("YT_BASE_URL should be a base or smth")
-                .push(&handle);
-            url
-        } else {
+        if !HANDLE_REGEX.is_match(&handle) {
             bail!("Invalid handle!");
         }
+        handle
+    };
+
+    // Check fscache
+    let fscache_path = {
+        let mut path = config.cache_path.join(FSCACHE_HANDLES);
+        path.push(&handle);
+        path
     };
+    if let Some(ucid) = read_cached_ucid(&fscache_path, &handle).await {
+        return Ok(ucid);
+    }
 
-    let resp = client.get(url).send().await.context("Failed to send channel page request")?;
-    let resp = resp.error_for_status().context("Channel page request failed")?;
-    let page = resp.text().await.context("Failed to receive the channel page")?;
+    let mut url = YT_BASE_URL.clone();
+    url.path_segments_mut().expect("YT_BASE_URL should be a base or smth")
+        .push(&handle);
+
+    let mut attempt = 0;
+    let page = loop {
+        attempt += 1;
+        let err = match client.get(url.clone()).send().await {
+            Err(err) => err.context("Failed to send channel page request"),
+            Ok(resp) if resp.status().is_server_error() => anyhow!("Channel page request failed with status {}", resp.status()),
+            Ok(resp) => {
+                // client errors are not retried - the handle most likely doesn't exist
+                let resp = resp.error_for_status().context("Channel page request failed")?;
+                let body = resp.bytes_limited(config.innertube.max_response_size).await.context("Failed to receive the channel page")?;
+                break String::from_utf8_lossy(&body).into_owned();
+            },
+        };
+        if attempt > HANDLE_RESOLVE_RETRIES {
+            return Err(err.context(format!("Giving up after {attempt} attempts")));
+        }
+        debug!("Channel page request for '{handle}' failed (attempt {attempt}), retrying: {err}");
+        sleep(HANDLE_RESOLVE_BACKOFF * attempt).await;
+    };
 
     let Some(captures) = UCID_EXTRACTION_REGEX.captures(&page) else {
         bail!("Failed to find the UCID for this channel");
     };
+    let ucid = captures[1].to_owned();
+
+    // Cache the result
+    let fscache_tmpdir = config.cache_path.join(FSCACHE_TEMPDIR);
+    match TemporaryFile::new(fscache_path, &fscache_tmpdir).await {
+        Err(err) => {
+            warn!("Got an unexpected error while trying to open the UCID cache entry for handle '{handle}' for writing: {err}");
+        },
+        Ok(mut file) => {
+            let result: std::io::Result<()> = async {
+                file.write_all(ucid.as_bytes()).await?;
+                file.flush().await
+            }.await;
+            if let Err(err) = result {
+                warn!("Got an unexpected error while trying to write the UCID cache entry for handle '{handle}': {err}");
+            } else if let Err(err) = file.commit().await {
+                warn!("Got an unexpected error while trying to commit the UCID cache entry for handle '{handle}': {err:?}");
+            }
+        }
+    }
 
-    Ok(captures[1].to_owned())
+    Ok(ucid)
+}
+
+/// Returns the cached UCID for the given handle, if present and not older than [`FSCACHE_HANDLE_MAX_AGE`]
+async fn read_cached_ucid(path: &Path, handle: &str) -> Option<String> {
+    let expired = match tokio::fs::metadata(path).await {
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(err) => {
+            warn!("Got an unexpected error while trying to stat the UCID cache entry for handle '{handle}': {err}");
+            return None;
+        },
+        Ok(meta) => meta.modified().ok()
+            .and_then(|mtime| mtime.elapsed().ok())
+            .is_none_or(|age| age > FSCACHE_HANDLE_MAX_AGE),
+    };
+    if expired {
+        return None;
+    }
+    match tokio::fs::read_to_string(path).await {
+        Err(err) => {
+            warn!("Got an unexpected error while trying to read the UCID cache entry for handle '{handle}': {err}");
+            None
+        },
+        Ok(ucid) if UCID_REGEX.is_match(ucid.trim()) => Some(ucid.trim().to_owned()),
+        Ok(..) => {
+            warn!("UCID cache entry for handle '{handle}' contained an invalid UCID");
+            None
+        },
+    }
 }
 
 
@@ -179,7 +312,17 @@ pub async fn browse_channel(client: Client, config: Arc<AppConfig>, mode: &Brows
         path
     };
     let fscache_tmpdir = config.cache_path.join(FSCACHE_TEMPDIR);
+    // appending only ever adds videos, so deleted videos are only dropped by a full refetch
+    let cache_age = tokio::fs::metadata(&fscache_path).await
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|mtime| mtime.elapsed().ok());
+    let cache_stale = cache_age.is_some_and(|age| age.as_secs_f64() > config.innertube.channel_cache_max_age_secs);
+    if cache_stale {
+        info!("The videos cache entry for channel UCID '{ucid}' is older than the configured maximum age, fetching all videos again");
+    }
     let cached_video_ids: Vec<String> = match File::open(&fscache_path).await {
+        _ if cache_stale => vec![],
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => vec![],
         Err(err) => {
             warn!("Got an unexpected error while trying to open the videos cache entry for channel UCID '{ucid}' for reading: {err}");
@@ -217,12 +360,12 @@ pub async fn browse_channel(client: Client, config: Arc<AppConfig>, mode: &Brows
         let resp = resp.error_for_status().context("Browse request failed")?;
 
         let results = if is_continuation {
-            let mut resp: it::browse::out::RichGridContinuation = resp.json_debug(mode.tab_name).await.context("Failed to decode browse continuation response")?;
+            let mut resp: it::browse::out::RichGridContinuation = resp.json_debug(mode.tab_name, config.innertube.max_response_size).await.context("Failed to decode browse continuation response")?;
             
             resp.on_response_received_actions.pop().context("Failed to decode browse continuation response - decoded actions list was empty")?
                 .append_continuation_items_action.continuation_items
         } else {
-            let mut resp: it::browse::out::BrowseOutput = resp.json_debug(mode.tab_name).await.context("Failed to decode browse channel response")?;
+            let mut resp: it::browse::out::BrowseOutput = resp.json_debug(mode.tab_name, config.innertube.max_response_size).await.context("Failed to decode browse channel response")?;
 
             channel_name = Some(resp.microformat.microformat_data_renderer.title);
 
@@ -256,6 +399,9 @@ pub async fn browse_channel(client: Client, config: Arc<AppConfig>, mode: &Brows
                         break 'outer;
                     }
                     new_video_ids.push(video_id);
+                    if new_video_ids.len() + cached_video_ids.len() > config.innertube.max_channel_videos {
+                        bail!("The {} tab of this channel has more than {} videos (max_channel_videos), refusing to fetch the rest", mode.tab_name, config.innertube.max_channel_videos);
+                    }
                 },
                 it::browse::out::RichGridItem::ContinuationItemRenderer { continuation_endpoint } => pending_requests.push_back(it::browse::Input {
                     continuation: Some(continuation_endpoint.continuation_command.token),
@@ -366,12 +512,12 @@ pub async fn browse_playlist(client: Client, config: Arc<AppConfig>, plid: Strin
         let resp = resp.error_for_status().context("Browse request failed")?;
 
         let results = if is_continuation {
-            let mut resp: it::browse::out::PlaylistContinuation = resp.json_debug("playlist").await.context("Failed to decode browse continuation response")?;
+            let mut resp: it::browse::out::PlaylistContinuation = resp.json_debug("playlist", config.innertube.max_response_size).await.context("Failed to decode browse continuation response")?;
             
             resp.on_response_received_actions.pop().context("Failed to decode browse continuation response - decoded actions list was empty")?
                 .append_continuation_items_action.continuation_items
         } else {
-            let mut resp: it::browse::out::BrowseOutput = resp.json_debug("playlist").await.context("Failed to decode browse playlist response")?;
+            let mut resp: it::browse::out::BrowseOutput = resp.json_debug("playlist", config.innertube.max_response_size).await.context("Failed to decode browse playlist response")?;
 
             if count_hint.is_none() && !cached_video_ids.is_empty() {
                 if let it::browse::out::BrowseHeader::PlaylistHeaderRenderer { num_videos_text } = resp.header {
@@ -471,12 +617,12 @@ pub async fn browse_releases_tab(client: Client, config: Arc<AppConfig>, ucid: A
         let resp = resp.error_for_status().context("Browse request failed")?;
 
         let results = if is_continuation {
-            let mut resp: it::browse::out::RichGridContinuation = resp.json_debug("releases_tab").await.context("Failed to decode browse continuation response")?;
+            let mut resp: it::browse::out::RichGridContinuation = resp.json_debug("releases_tab", config.innertube.max_response_size).await.context("Failed to decode browse continuation response")?;
             
             resp.on_response_received_actions.pop().context("Failed to decode browse continuation response - decoded actions list was empty")?
                 .append_continuation_items_action.continuation_items
         } else {
-            let mut resp: it::browse::out::BrowseOutput = resp.json_debug("releases_tab").await.context("Failed to decode browse channel response")?;
+            let mut resp: it::browse::out::BrowseOutput = resp.json_debug("releases_tab", config.innertube.max_response_size).await.context("Failed to decode browse channel response")?;
 
             let Some(tab) = resp.contents.two_column_browse_results_renderer.tabs.pop() else {
                 debug!("release tab browsing aborted: tab unavailable");
@@ -547,12 +693,12 @@ pub async fn browse_releases_homepage(client: Client, config: Arc<AppConfig>, uc
         let resp = resp.error_for_status().context("Browse request failed")?;
 
         let results = if is_continuation {
-            let mut resp: it::browse::out::GridContinuation = resp.json_debug("releases_home").await.context("Failed to decode browse continuation response")?;
+            let mut resp: it::browse::out::GridContinuation = resp.json_debug("releases_home", config.innertube.max_response_size).await.context("Failed to decode browse continuation response")?;
             
             resp.on_response_received_actions.pop().context("Failed to decode browse continuation response - decoded actions list was empty")?
                 .append_continuation_items_action.continuation_items
         } else {
-            let mut resp: it::browse::out::BrowseOutput = resp.json_debug("releases_home").await.context("Failed to decode browse channel response")?;
+            let mut resp: it::browse::out::BrowseOutput = resp.json_debug("releases_home", config.innertube.max_response_size).await.context("Failed to decode browse channel response")?;
 
             let Some(tab) = resp.contents.two_column_browse_results_renderer.tabs.pop() else {
                 debug!("release browsing via homepage aborted: tab unavailable");
@@ -595,7 +741,7 @@ pub async fn browse_releases_homepage(client: Client, config: Arc<AppConfig>, uc
                 params: None,
             }).send().await.context("Failed to send followup browse continuation request")?;
             let resp = resp.error_for_status().context("Followup browse continuation request failed")?;
-            let mut resp: it::browse::out::ShelfContinuation = resp.json_debug("releases_home").await.context("Failed to decode followup browse continuation response")?;
+            let mut resp: it::browse::out::ShelfContinuation = resp.json_debug("releases_home", config.innertube.max_response_size).await.context("Failed to decode followup browse continuation response")?;
 
             resp.on_response_received_actions.pop().context("Failed to decode followup browse continuation response - decoded actions list was empty")?
                 .append_continuation_items_action.continuation_items.pop().context("Failed to decode followup browse continuation response - no grid renderer found")?
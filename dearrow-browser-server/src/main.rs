@@ -18,29 +18,36 @@
 use actix_files::{Files, NamedFile};
 use actix_web::{
     dev::{fn_service, ServiceRequest, ServiceResponse},
+    error::ErrorInternalServerError,
+    guard,
+    http::header::{self, ContentType},
     middleware::NormalizePath,
-    web, App, HttpResponse, HttpServer,
+    rt::{spawn, task::spawn_blocking, time::sleep},
+    web, App, HttpRequest, HttpResponse, HttpServer,
 };
 use chrono::Utc;
-use constants::CONFIG_PATH;
+use constants::{CONFIG_PATH, CONFIG_TEMPLATE};
 use dearrow_parser::{DearrowDB, StringSet};
 use env_logger::Env;
 use cloneable_errors::{bail, ErrorContext, ResContext};
-use log::info;
+use log::{error, info, warn};
 use std::{
+    collections::BTreeMap,
     fs::{create_dir_all, set_permissions, File, Permissions},
     future::ready,
     io::{self, Read, Write},
     os::unix::prelude::PermissionsExt,
-    sync::RwLock,
+    sync::{Arc, OnceLock, RwLock},
     time::Duration,
 };
 
 mod constants;
 mod innertube;
 mod middleware;
+mod precompressed;
 mod routes;
 mod sbserver_emulation;
+mod sitemap;
 mod state;
 mod utils;
 use reqwest::ClientBuilder;
@@ -49,22 +56,30 @@ use state::*;
 #[actix_web::main]
 async fn main() -> Result<(), ErrorContext> {
     env_logger::init_from_env(Env::default().default_filter_or("info"));
+    if std::env::args().nth(1).as_deref() == Some("generate-config") {
+        print!("{}", default_config()?);
+        return Ok(());
+    }
     let config: web::Data<AppConfig> = web::Data::new(match File::open(CONFIG_PATH) {
         Ok(mut file) => {
             let mut contents = String::new();
             file.read_to_string(&mut contents)
                 .with_context(|| format!("Failed to read {CONFIG_PATH}"))?;
-            let cfg: AppConfig = toml::from_str(&contents)
+            let mut cfg: AppConfig = toml::from_str(&contents)
                 .with_context(|| format!("Failed to deserialize contents of {CONFIG_PATH}"))?;
+            cfg.resolve_auth_secret()?;
             if cfg.listen.tcp.is_none() && cfg.listen.unix.is_none() {
                 bail!("Invalid configuration - no tcp port or unix socket path specified");
             }
+            if !cfg.base_path.starts_with('/') || !cfg.base_path.ends_with('/') {
+                bail!("Invalid configuration - base_path must start and end with a slash");
+            }
             cfg
         }
         Err(e) if e.kind() == io::ErrorKind::NotFound => {
-            let cfg = AppConfig::default();
-            let serialized =
-                toml::to_string(&cfg).context("Failed to serialize default AppConfig as TOML")?;
+            let serialized = default_config()?;
+            let cfg: AppConfig = toml::from_str(&serialized)
+                .context("Failed to deserialize the default config template")?;
             let mut file = File::options()
                 .write(true)
                 .create_new(true)
@@ -85,6 +100,10 @@ async fn main() -> Result<(), ErrorContext> {
             .context("Failed to create the cache temporary directory")?;
         create_dir_all(config.cache_path.join(constants::FSCACHE_PLAYLISTS))
             .context("Failed to create the cache playlists directory")?;
+        create_dir_all(config.cache_path.join(constants::FSCACHE_HANDLES))
+            .context("Failed to create the cache handles directory")?;
+        create_dir_all(config.cache_path.join(constants::FSCACHE_DURATIONS))
+            .context("Failed to create the cache durations directory")?;
         create_dir_all(
             config
                 .cache_path
@@ -101,97 +120,94 @@ async fn main() -> Result<(), ErrorContext> {
         .context("Failed to create the channel cache shorts directory")?;
     }
     info!("Loading database...");
-    let string_set_lock = web::Data::new(RwLock::new(StringSet::with_capacity(16384)));
+    let string_set_lock = web::Data::new(RwLock::new(StringSet::with_capacity(string_set_capacity_hint(&config))));
     let reqwest_client = web::ThinData(
-        ClientBuilder::new()
-            .timeout(Duration::from_secs_f64(config.reqwest_timeout_secs))
-            .build()
-            .expect("Should be able to create a reqwest Client"),
+        {
+            let builder = ClientBuilder::new()
+                .timeout(Duration::from_secs_f64(config.reqwest_timeout_secs))
+                .pool_max_idle_per_host(config.innertube.pool_max_idle_per_host)
+                .pool_idle_timeout(Duration::from_secs_f64(config.innertube.pool_idle_timeout_secs))
+                .tcp_keepalive((config.innertube.tcp_keepalive_secs > 0.).then(|| Duration::from_secs_f64(config.innertube.tcp_keepalive_secs)));
+            if config.innertube.http2_prior_knowledge {
+                builder.http2_prior_knowledge()
+            } else {
+                builder
+            }
+        }
+        .build()
+        .expect("Should be able to create a reqwest Client"),
     );
+    let video_duration_cache = web::Data::new(VideoDurationCache::new(
+        config.clone().into_inner(),
+        reqwest_client.0.clone(),
+    ));
     let db: web::Data<RwLock<DatabaseState>> = {
         let mut string_set = string_set_lock
             .write()
             .map_err(|_| constants::SS_WRITE_ERR.clone())?;
-        let (db, errors) = DearrowDB::load_dir(&config.mirror_path, &mut string_set)
-            .context("Initial DearrowDB load failed")?;
+        let load_result = DearrowDB::load_dir(&config.mirror_path, config.load_category_flags(), config.load_options(), &mut string_set)
+            .context("Initial DearrowDB load failed");
+        let (mut db, errors, available) = match load_result {
+            Ok((db, errors)) => (db, errors, true),
+            Err(err) if config.retry_initial_load => {
+                error!("{err:?}");
+                warn!("Starting without data, loading will be retried every {} seconds", config.initial_load_retry_secs);
+                (DearrowDB::default(), Vec::new(), false)
+            },
+            Err(err) => return Err(err),
+        };
+        db.sort();
         string_set.clean();
+        if available {
+            record_string_count(&config, &string_set);
+        }
 
+        let video_presence = index_video_presence(&db);
         let mut db_state = DatabaseState {
-            db,
+            db: Arc::new(db),
             errors: errors.into(),
             last_updated: Utc::now().timestamp_millis(),
             last_modified: utils::get_mtime(&config.mirror_path.join("titles.csv")),
             updating_now: false,
+            available,
             etag: None,
             channel_cache: ChannelCache::new(
                 string_set_lock.clone().into_inner(),
                 config.clone().into_inner(),
                 reqwest_client.0.clone(),
             ),
-            uncut_segment_count: 0,
-            video_info_count: 0,
+            stats: Arc::default(),
+            sitemap: OnceLock::new(),
+            top_videos: OnceLock::new(),
+            vote_stats: OnceLock::new(),
+            video_presence,
         };
-        db_state.db.sort();
-        db_state.uncut_segment_count = db_state.calculate_uncut_segment_count();
-        db_state.video_info_count = db_state.calculate_video_info_count();
         db_state.etag = Some(db_state.generate_etag());
         web::Data::new(RwLock::new(db_state))
     };
-    info!("Database ready!");
+    if db.read().map_err(|_| constants::DB_READ_ERR.clone())?.available {
+        compute_stats_in_background(db.clone());
+        info!("Database ready!");
+    } else {
+        spawn(retry_initial_load(db.clone(), string_set_lock.clone(), config.clone()));
+    }
 
     let mut server = {
         let config = config.clone();
+        let scope_path = config.base_path.trim_end_matches('/').to_owned();
         HttpServer::new(move || {
-            let config2 = config.clone();
-            let mut app = App::new()
+            App::new()
                 .wrap(NormalizePath::trim())
                 .app_data(config.clone())
                 .app_data(db.clone())
                 .app_data(string_set_lock.clone())
                 .app_data(reqwest_client.clone())
+                .app_data(video_duration_cache.clone())
                 .wrap(middleware::custom_status::CustomStatusCodes)
                 .wrap(middleware::timings::Timings)
+                .wrap(middleware::request_id::RequestIdentifier)
                 .wrap(middleware::errors::ErrorRepresentation)
-                .service(web::scope("/api").configure(routes::configure(config.clone())));
-            if config.enable_sbserver_emulation {
-                app = app.service(
-                    web::scope("/sbserver").configure(sbserver_emulation::configure_enabled),
-                );
-            } else {
-                app = app.service(
-                    web::scope("/sbserver").configure(sbserver_emulation::configure_disabled),
-                );
-            }
-            if config.innertube.enable {
-                app = app.service(web::scope("/innertube").configure(innertube::configure_enabled));
-            } else {
-                app =
-                    app.service(web::scope("/innertube").configure(innertube::configure_disabled));
-            }
-            if config.enable_fakeapi {
-                app = app.service(web::scope("/fakeapi").default_service(fn_service(
-                    |req: ServiceRequest| {
-                        ready(Ok(ServiceResponse::new(
-                            req.into_parts().0,
-                            HttpResponse::Ok().finish(),
-                        )))
-                    },
-                )));
-            }
-            app.service(
-                Files::new("/", config.static_content_path.as_path())
-                    .index_file("index.html")
-                    .default_handler(fn_service(move |req: ServiceRequest| {
-                        let config = config2.clone();
-                        async move {
-                            let (req, _) = req.into_parts();
-                            let index_file = config.static_content_path.join("index.html");
-                            let file = NamedFile::open_async(index_file.as_path()).await?;
-                            let resp = file.into_response(&req);
-                            Ok(ServiceResponse::new(req, resp))
-                        }
-                    })),
-            )
+                .service(web::scope(&scope_path).configure(|cfg| configure_app(cfg, &config)))
         })
     };
     if let Some((ref ip, port)) = config.listen.tcp {
@@ -200,7 +216,7 @@ async fn main() -> Result<(), ErrorContext> {
             .bind((ip_str, port))
             .with_context(|| format!("Failed to bind to tcp port {ip_str}:{port}"))?;
         info!("Listening on {ip_str}:{port}");
-    };
+    }
     if let Some(ref path) = config.listen.unix {
         let path_str = path.as_str();
         server = server
@@ -213,10 +229,155 @@ async fn main() -> Result<(), ErrorContext> {
             })?;
         }
         info!("Listening on {path_str}");
-    };
+    }
     server.run().await.context("Error while running the server")
 }
 
+/// Keeps reloading the database until it succeeds, used when the initial load failed
+async fn retry_initial_load(db: web::Data<RwLock<DatabaseState>>, string_set_lock: web::Data<RwLock<StringSet>>, config: web::Data<AppConfig>) {
+    let delay = Duration::from_secs_f64(config.initial_load_retry_secs);
+    for attempt in 1.. {
+        sleep(delay).await;
+        info!("Retrying the initial database load (attempt {attempt})...");
+        let (db, string_set_lock, config) = (db.clone(), string_set_lock.clone(), config.clone());
+        match spawn_blocking(move || routes::do_reload(db, string_set_lock, config)).await {
+            Ok(Ok(())) => {
+                info!("Database ready!");
+                return;
+            },
+            Ok(Err(err)) => warn!("Initial database load attempt {attempt} failed, retrying in {} seconds: {err:?}", delay.as_secs_f64()),
+            Err(err) => warn!("Initial database load attempt {attempt} panicked, retrying in {} seconds: {err}", delay.as_secs_f64()),
+        }
+    }
+}
+
+/// Renders the commented default config with a freshly generated auth secret
+fn default_config() -> Result<String, ErrorContext> {
+    if !CONFIG_TEMPLATE.contains(constants::CONFIG_TEMPLATE_SECRET) {
+        bail!("The default config template is missing the auth_secret placeholder");
+    }
+    let config = CONFIG_TEMPLATE.replacen(
+        constants::CONFIG_TEMPLATE_SECRET,
+        &format!(r#"auth_secret = "{}""#, utils::random_b64::<64>()),
+        1,
+    );
+    // make sure the template still matches the AppConfig structure
+    toml::from_str::<AppConfig>(&config).context("Failed to deserialize the default config template")?;
+    Ok(config)
+}
+
+fn configure_app(cfg: &mut web::ServiceConfig, config: &web::Data<AppConfig>) {
+    // versioned scopes must be registered first, /api would swallow them otherwise
+    for version in constants::API_VERSIONS {
+        cfg.service(web::scope(&format!("/api/{version}")).wrap(middleware::require_database::RequireDatabase).wrap(middleware::head_as_get::HeadAsGet).configure(routes::configure(config.clone())));
+    }
+    cfg.service(web::scope("/api").wrap(middleware::require_database::RequireDatabase).wrap(middleware::head_as_get::HeadAsGet).configure(routes::configure(config.clone())));
+    if config.enable_sbserver_emulation {
+        cfg.service(web::scope("/sbserver").wrap(middleware::require_database::RequireDatabase).wrap(middleware::head_as_get::HeadAsGet).configure(sbserver_emulation::configure_enabled));
+    } else {
+        cfg.service(web::scope("/sbserver").configure(sbserver_emulation::configure_disabled));
+    }
+    if config.innertube.enable {
+        cfg.service(web::scope("/innertube").configure(innertube::configure_enabled));
+    } else {
+        cfg.service(web::scope("/innertube").configure(innertube::configure_disabled));
+    }
+    // only mounted when enabled, requests to /fakeapi fall through to the frontend otherwise
+    if config.enable_fakeapi {
+        cfg.service(web::scope("/fakeapi").default_service(fn_service(|req: ServiceRequest| {
+            let (req, _) = req.into_parts();
+            let resp = fakeapi_echo(&req);
+            ready(Ok(ServiceResponse::new(req, resp)))
+        })));
+    }
+    // only mounted when enabled, requests fall through to the frontend otherwise
+    if config.enable_sitemap {
+        cfg.configure(sitemap::configure);
+    }
+    let config2 = config.clone();
+    cfg.service(
+        web::resource(["", "/"]).get(|req: HttpRequest, config: web::Data<AppConfig>| async move {
+            serve_index(&req, &config).await
+        }),
+    );
+    // clients accepting compressed responses get precompressed files, if available
+    cfg.service(
+        web::resource("/{path:.*}")
+            .guard(guard::fn_guard(|ctx| ctx.head().headers().contains_key(header::ACCEPT_ENCODING)))
+            .route(web::route().guard(guard::Any(guard::Get()).or(guard::Head())).to(serve_static)),
+    );
+    cfg.service(
+        Files::new("/", config.static_content_path.as_path())
+            .default_handler(fn_service(move |req: ServiceRequest| {
+                let config = config2.clone();
+                async move {
+                    let (req, _) = req.into_parts();
+                    let resp = serve_index(&req, &config).await?;
+                    Ok(ServiceResponse::new(req, resp))
+                }
+            })),
+    );
+}
+
+/// Debugging aid for API clients - echoes back the request's method, path, query string and
+/// headers as JSON
+fn fakeapi_echo(req: &HttpRequest) -> HttpResponse {
+    let mut headers: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for (name, value) in req.headers() {
+        headers.entry(name.as_str()).or_default().push(String::from_utf8_lossy(value.as_bytes()).into_owned());
+    }
+    HttpResponse::Ok().json(serde_json::json!({
+        "method": req.method().as_str(),
+        "path": req.path(),
+        "query": req.query_string(),
+        "headers": headers,
+    }))
+}
+
+/// Serves a static file, preferring precompressed variants. Falls back to index.html if the file
+/// doesn't exist, same as the [`Files`] service.
+async fn serve_static(req: HttpRequest, config: web::Data<AppConfig>) -> Result<HttpResponse, actix_web::Error> {
+    let Some(path) = precompressed::resolve(&config.static_content_path, req.match_info().query("path")) else {
+        return serve_index(&req, &config).await;
+    };
+    if let Some(resp) = precompressed::serve_variant(&req, &path).await {
+        return Ok(resp);
+    }
+    if tokio::fs::metadata(&path).await.is_ok_and(|m| m.is_file()) {
+        let file = NamedFile::open_async(&path).await?;
+        return Ok(file.into_response(&req));
+    }
+    serve_index(&req, &config).await
+}
+
+/// `<base>` element of the frontend's index.html, rewritten by [`serve_index`]
+const BASE_PLACEHOLDER: &str = r#"<base href="/">"#;
+
+/// Serves the frontend's index.html, pointing its `<base>` element at the configured base path.
+///
+/// Responds with an error if index.html lacks the [`BASE_PLACEHOLDER`], as the app would load its
+/// assets from the wrong path.
+async fn serve_index(req: &HttpRequest, config: &AppConfig) -> Result<HttpResponse, actix_web::Error> {
+    let index_file = config.static_content_path.join("index.html");
+    if config.base_path == "/" {
+        // precompressed variants can only be used as-is, without rewriting the base path
+        if let Some(resp) = precompressed::serve_variant(req, &index_file).await {
+            return Ok(resp);
+        }
+        let file = NamedFile::open_async(index_file.as_path()).await?;
+        return Ok(file.into_response(req));
+    }
+    let contents = tokio::fs::read_to_string(index_file.as_path()).await?;
+    if !contents.contains(BASE_PLACEHOLDER) {
+        error!("{} does not contain the {BASE_PLACEHOLDER} placeholder required by the configured base_path", index_file.display());
+        return Err(ErrorInternalServerError("index.html cannot be served under the configured base path"));
+    }
+    Ok(HttpResponse::Ok()
+        .content_type(ContentType::html())
+        .body(contents.replacen(BASE_PLACEHOLDER, &format!(r#"<base href="{}">"#, config.base_path), 1)))
+}
+
+#[allow(clippy::needless_raw_string_hashes, clippy::doc_markdown)]
 mod built_info {
     // Contents generated by buildscript, using built
     include!(concat!(env!("OUT_DIR"), "/built.rs"));
@@ -22,12 +22,15 @@ use actix_web::body::{BoxBody, EitherBody, MessageBody};
 use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
 use actix_web::error::Error;
 use actix_web::http::header::{Accept, ContentType, Header};
+use actix_web::HttpMessage;
 use cloneable_errors::SerializableError;
 use futures::{future::LocalBoxFuture, FutureExt};
 use log::warn;
 
 use crate::utils::{HeaderMapExt, SerializableErrorResponseMarker};
 
+use super::request_id::RequestId;
+
 
 pub struct ErrorRepresentation;
 
@@ -77,6 +80,7 @@ where
 
             // client did not explicitly request json and the response contains serialized error
             // json - convert to plaintext
+            let request_id = resp.request().extensions().get::<RequestId>().cloned();
             let resp = resp.map_body(|head, body| {
                 match body.try_into_bytes() {
                     Err(body) => {
@@ -93,7 +97,10 @@ where
                                 if let Err(err) = head.headers.replace_header(ContentType::plaintext()) {
                                     warn!("Failed to replace the ContentType header: {err}");
                                 }
-                                EitherBody::right(BoxBody::new(format!("{error:?}")))
+                                match request_id {
+                                    None => EitherBody::right(BoxBody::new(format!("{error:?}"))),
+                                    Some(RequestId(id)) => EitherBody::right(BoxBody::new(format!("{error:?}\n\nRequest ID: {id}"))),
+                                }
                             }
                         }
                     }
@@ -16,15 +16,17 @@
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use std::fmt::Write;
 use std::future::{ready, Ready};
 
 use actix_web::body::{BoxBody, EitherBody};
 use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
 use actix_web::error::Error;
-use actix_web::http::header::{CacheControl, CacheDirective, ETag, Header, IfNoneMatch};
+use actix_web::http::header::{CacheControl, CacheDirective, ETag, EntityTag, Header, IfNoneMatch, VARY};
 use actix_web::http::StatusCode;
 use actix_web::HttpResponseBuilder;
 use futures::{future::LocalBoxFuture, FutureExt};
+use sha2::{Digest, Sha256};
 
 use crate::{constants::*, utils::HeaderMapExt};
 use crate::state::DBLock;
@@ -38,6 +40,43 @@ pub enum ETagCacheControl {
     /// The `ETag` header will not be appended to this response.
     /// The `Cache-Control` header will be set to `no-cache, no-store` on this response.
     DoNotCache,
+    /// Derive the `ETag` from this digest instead of the database snapshot.
+    ///
+    /// Used by responses which depend on more than the database, or only on a small part of it.
+    /// The response is replaced with `304 Not Modified` if the client already has this version.
+    Validator([u8; 16]),
+}
+
+impl ETagCacheControl {
+    /// Builds a [`ETagCacheControl::Validator`] from the digest of everything fed into `hasher`
+    pub fn validator(hasher: Sha256) -> Self {
+        let digest = hasher.finalize();
+        let mut validator = [0u8; 16];
+        validator.copy_from_slice(&digest[..16]);
+        Self::Validator(validator)
+    }
+}
+
+fn validator_etag(validator: [u8; 16]) -> EntityTag {
+    let mut tag = String::with_capacity(34);
+    tag.push_str("v-");
+    for byte in validator {
+        write!(tag, "{byte:02x}").expect("writing to a String should not fail");
+    }
+    EntityTag::new_weak(tag)
+}
+
+/// Distinguishes the `ETag`s of CSV responses from JSON ones, as both are served from the same URL
+fn representation_etag(etag: EntityTag, csv: bool) -> EntityTag {
+    if csv {
+        EntityTag::new(etag.weak, format!("{}-csv", etag.tag()))
+    } else {
+        etag
+    }
+}
+
+fn inm_matches(inm: &IfNoneMatch, etag: &EntityTag) -> bool {
+    matches!(inm, IfNoneMatch::Items(etags) if etags.iter().any(|e| e.weak_eq(etag)))
 }
 
 pub struct ETagCache;
@@ -80,19 +119,20 @@ where
         let Ok(etag) = db.read().map(|db| db.get_etag()) else {
             return ready(Err(utils::Error::from(DB_READ_ERR.clone()).into())).boxed_local();
         };
+        let csv = utils::prefers_csv(&req);
+        let etag = representation_etag(etag, csv);
 
         let inm = match IfNoneMatch::parse(&req) {
             Ok(inm) => inm,
             Err(err) => return ready(Err(err.into())).boxed_local(),
         };
 
-        if let IfNoneMatch::Items(etags) = inm {
-            if etags.iter().any(|e| e.weak_eq(&etag)) {
-                let mut resp = HttpResponseBuilder::new(StatusCode::NOT_MODIFIED);
-                resp.append_header(ETag(etag))
-                    .append_header(CacheControl(vec![CacheDirective::NoCache]));
-                return ready(Ok(req.into_response(resp).map_into_right_body())).boxed_local();
-            }
+        if inm_matches(&inm, &etag) {
+            let mut resp = HttpResponseBuilder::new(StatusCode::NOT_MODIFIED);
+            resp.append_header(ETag(etag))
+                .append_header(CacheControl(vec![CacheDirective::NoCache]))
+                .append_header((VARY, "accept"));
+            return ready(Ok(req.into_response(resp).map_into_right_body())).boxed_local();
         }
 
         let srv = self.service.call(req);
@@ -111,6 +151,20 @@ where
                     let headers = resp.headers_mut();
                     headers.append_header(CacheControl(vec![CacheDirective::NoCache, CacheDirective::NoStore])).map_err(Into::<actix_web::error::HttpError>::into)?;
                 },
+                Some(ETagCacheControl::Validator(validator)) => {
+                    let etag = representation_etag(validator_etag(validator), csv);
+                    if inm_matches(&inm, &etag) {
+                        let mut not_modified = HttpResponseBuilder::new(StatusCode::NOT_MODIFIED);
+                        not_modified.append_header(ETag(etag))
+                            .append_header(CacheControl(vec![CacheDirective::NoCache]))
+                            .append_header((VARY, "accept"));
+                        let req = resp.request().clone();
+                        return Ok(ServiceResponse::new(req, not_modified.finish()).map_into_right_body());
+                    }
+                    let headers = resp.headers_mut();
+                    headers.append_header(ETag(etag)).map_err(Into::<actix_web::error::HttpError>::into)?;
+                    headers.append_header(CacheControl(vec![CacheDirective::NoCache])).map_err(Into::<actix_web::error::HttpError>::into)?;
+                },
             }
 
 
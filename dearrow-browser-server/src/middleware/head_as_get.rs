@@ -0,0 +1,130 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*  
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::future::{ready, Ready};
+
+use actix_web::body::{self, EitherBody};
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::error::Error;
+use actix_web::http::Method;
+use futures::{future::LocalBoxFuture, FutureExt};
+
+
+/// Answers HEAD requests using GET routes.
+///
+/// HEAD requests are routed as GET requests, so the response headers (including the `ETag`)
+/// are identical to those of a GET request. The body is then replaced with an empty one of
+/// unknown size, so no `Content-Length` is sent for it.
+pub struct HeadAsGet;
+
+
+impl<S, B> Transform<S, ServiceRequest> for HeadAsGet
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B, body::None>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = HeadAsGetInstance<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(HeadAsGetInstance { service }))
+    }
+}
+
+pub struct HeadAsGetInstance<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for HeadAsGetInstance<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B, body::None>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let head = req.method() == Method::HEAD;
+        if head {
+            req.head_mut().method = Method::GET;
+        }
+        let srv = self.service.call(req);
+
+        async move {
+            let resp = srv.await?;
+            Ok(if head {
+                resp.map_body(|_, _| EitherBody::right(body::None::new()))
+            } else {
+                resp.map_into_left_body()
+            })
+        }.boxed_local()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{get, http::{header, StatusCode}, test, web, App};
+
+    use crate::middleware::etag::ETagCache;
+    use crate::state::DatabaseState;
+
+    use super::*;
+
+    #[get("/resource", wrap = "ETagCache")]
+    async fn resource() -> &'static str {
+        "some content"
+    }
+
+    #[actix_web::test]
+    async fn head_matches_get() {
+        let (db, _) = DatabaseState::golden_fixture();
+        let app = test::init_service(
+            App::new()
+                .app_data(db)
+                .service(web::scope("/api").wrap(HeadAsGet).service(resource)),
+        ).await;
+
+        let get = test::call_service(&app, test::TestRequest::get().uri("/api/resource").to_request()).await;
+        assert_eq!(get.status(), StatusCode::OK);
+        let get_etag = get.headers().get(header::ETAG).expect("GET should have an ETag").clone();
+        assert_eq!(test::read_body(get).await, "some content");
+
+        let head = test::call_service(&app, test::TestRequest::default().method(Method::HEAD).uri("/api/resource").to_request()).await;
+        assert_eq!(head.status(), StatusCode::OK);
+        assert_eq!(head.headers().get(header::ETAG), Some(&get_etag));
+        assert!(test::read_body(head).await.is_empty());
+
+        let revalidated = test::call_service(
+            &app,
+            test::TestRequest::default()
+                .method(Method::HEAD)
+                .uri("/api/resource")
+                .insert_header((header::IF_NONE_MATCH, get_etag))
+                .to_request(),
+        ).await;
+        assert_eq!(revalidated.status(), StatusCode::NOT_MODIFIED);
+    }
+}
@@ -19,4 +19,7 @@
 pub mod custom_status;
 pub mod errors;
 pub mod etag;
+pub mod head_as_get;
+pub mod request_id;
+pub mod require_database;
 pub mod timings;
@@ -0,0 +1,129 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::{future::{ready, Ready}, sync::Arc};
+
+use actix_web::body::{BoxBody, EitherBody, MessageBody};
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::error::{Error, HttpError};
+use actix_web::HttpMessage;
+use futures::{future::LocalBoxFuture, FutureExt};
+use log::{error, warn};
+
+use crate::utils::{random_b64, HeaderMapExt, SerializableErrorResponseMarker};
+
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// This extension will be present on all requests passing through the [`RequestIdentifier`]
+/// middleware
+#[derive(Clone)]
+pub struct RequestId(pub Arc<str>);
+
+/// Checks whether an `X-Request-Id` value provided by the client is sane enough to be echoed back
+fn is_valid_request_id(id: &str) -> bool {
+    (1..=64).contains(&id.len()) && id.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
+
+pub struct RequestIdentifier;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestIdentifier
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B, BoxBody>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = RequestIdentifierInstance<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestIdentifierInstance { service }))
+    }
+}
+
+pub struct RequestIdentifierInstance<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestIdentifierInstance<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B, BoxBody>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let request_id: Arc<str> = req.headers().get(REQUEST_ID_HEADER)
+            .and_then(|h| h.to_str().ok())
+            .filter(|id| is_valid_request_id(id))
+            .map_or_else(|| random_b64::<12>().into(), Into::into);
+        req.extensions_mut().insert(RequestId(request_id.clone()));
+
+        let srv = self.service.call(req);
+
+        async move {
+            let mut resp = srv.await?;
+            if let Err(e) = resp.headers_mut().replace_header((REQUEST_ID_HEADER, &*request_id)) {
+                error!("Failed to set the {REQUEST_ID_HEADER} header: {}", HttpError::from(e));
+            }
+            if !resp.response().extensions().contains::<SerializableErrorResponseMarker>() {
+                return Ok(resp.map_into_left_body());
+            }
+
+            if resp.status().is_server_error() {
+                warn!("Request {request_id} to {} failed with status {}", resp.request().path(), resp.status());
+            }
+
+            // response contains a serialized error - include the request id in the error envelope
+            let resp = resp.map_body(|_, body| {
+                match body.try_into_bytes() {
+                    Err(body) => {
+                        warn!("Failed to read the body of a SerializableError response");
+                        EitherBody::left(body)
+                    },
+                    Ok(bytes) => {
+                        match serde_json::from_slice::<serde_json::Map<String, serde_json::Value>>(&bytes) {
+                            Err(err) => {
+                                warn!("Failed to deserialize the SerializableError response: {err}");
+                                EitherBody::right(BoxBody::new(bytes))
+                            },
+                            Ok(mut envelope) => {
+                                envelope.insert("request_id".to_owned(), request_id.to_string().into());
+                                match serde_json::to_vec(&envelope) {
+                                    Err(err) => {
+                                        warn!("Failed to serialize the SerializableError response: {err}");
+                                        EitherBody::right(BoxBody::new(bytes))
+                                    },
+                                    Ok(new_body) => EitherBody::right(BoxBody::new(new_body)),
+                                }
+                            },
+                        }
+                    }
+                }
+            });
+            Ok(resp)
+        }.boxed_local()
+    }
+}
@@ -0,0 +1,88 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*  
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::future::{ready, Ready};
+
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::error::Error;
+use actix_web::http::StatusCode;
+use cloneable_errors::anyhow;
+use futures::{future::LocalBoxFuture, FutureExt};
+
+use crate::constants::*;
+use crate::state::DBLock;
+use crate::utils;
+
+
+/// Responds with `503 Service Unavailable` while the database is not loaded.
+///
+/// Paths listed in [`DB_INDEPENDENT_PATHS`] are passed through regardless, so that the status
+/// and health of the server can still be queried.
+pub struct RequireDatabase;
+
+
+impl<S, B> Transform<S, ServiceRequest> for RequireDatabase
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = RequireDatabaseInstance<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequireDatabaseInstance { service }))
+    }
+}
+
+pub struct RequireDatabaseInstance<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequireDatabaseInstance<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        if !DB_INDEPENDENT_PATHS.contains(&req.match_info().unprocessed()) {
+            let db = req.app_data::<DBLock>().unwrap().clone();
+            let available = match db.read() {
+                Ok(db) => db.available,
+                Err(_) => return ready(Err(utils::Error::from(DB_READ_ERR.clone()).into())).boxed_local(),
+            };
+            if !available {
+                return ready(Err(utils::Error::from(anyhow!("The database is not loaded yet, try again later."))
+                    .set_status(StatusCode::SERVICE_UNAVAILABLE)
+                    .into()))
+                .boxed_local();
+            }
+        }
+        self.service.call(req).boxed_local()
+    }
+}
@@ -0,0 +1,99 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Serving of precompressed `.br`/`.gz` variants of static files.
+//!
+//! Files are never compressed on the fly - a variant is only served if it exists next to the
+//! original file and the client accepts its encoding.
+
+use std::path::{Path, PathBuf};
+
+use actix_files::NamedFile;
+use actix_web::{
+    http::header::{self, ContentEncoding},
+    HttpRequest, HttpResponse,
+};
+
+/// Supported encodings in order of preference, with the extensions of their files
+const VARIANTS: &[(ContentEncoding, &str)] = &[
+    (ContentEncoding::Brotli, "br"),
+    (ContentEncoding::Gzip, "gz"),
+];
+
+/// Checks whether the `Accept-Encoding` header of the request allows the given encoding
+fn accepts(req: &HttpRequest, encoding: ContentEncoding) -> bool {
+    req.headers()
+        .get_all(header::ACCEPT_ENCODING)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|item| {
+            let mut parts = item.split(';').map(str::trim);
+            let name = parts.next().unwrap_or_default();
+            let rejected = parts.any(|param| {
+                param.strip_prefix("q=").and_then(|q| q.parse::<f32>().ok()).is_some_and(|q| q <= 0.0)
+            });
+            !rejected && (name.eq_ignore_ascii_case(encoding.as_str()) || name == "*")
+        })
+}
+
+/// Opens the most preferred precompressed variant of `path` accepted by the client
+pub async fn open_variant(req: &HttpRequest, path: &Path) -> Option<NamedFile> {
+    let file_name = path.file_name()?.to_str()?;
+    for &(encoding, extension) in VARIANTS {
+        if !accepts(req, encoding) {
+            continue;
+        }
+        let variant_path = path.with_file_name(format!("{file_name}.{extension}"));
+        if !tokio::fs::metadata(&variant_path).await.is_ok_and(|m| m.is_file()) {
+            continue;
+        }
+        let Ok(file) = NamedFile::open_async(&variant_path).await else {
+            continue;
+        };
+        let mime = actix_files::file_extension_to_mime(
+            path.extension().and_then(|ext| ext.to_str()).unwrap_or_default(),
+        );
+        return Some(
+            file.set_content_type(mime)
+                .set_content_encoding(encoding)
+                .disable_content_disposition(),
+        );
+    }
+    None
+}
+
+/// Responds with a precompressed variant of `path`, marking the response as varying by encoding
+pub async fn serve_variant(req: &HttpRequest, path: &Path) -> Option<HttpResponse> {
+    let file = open_variant(req, path).await?;
+    let mut resp = file.into_response(req);
+    resp.headers_mut().insert(header::VARY, header::HeaderValue::from_static("accept-encoding"));
+    Some(resp)
+}
+
+/// Resolves a request path to a file in `root`, rejecting hidden files and path traversal
+pub fn resolve(root: &Path, request_path: &str) -> Option<PathBuf> {
+    let mut path = root.to_path_buf();
+    for segment in request_path.split('/') {
+        match segment {
+            "" | "." => {},
+            _ if segment.starts_with('.') || segment.contains('\\') => return None,
+            _ => path.push(segment),
+        }
+    }
+    Some(path)
+}
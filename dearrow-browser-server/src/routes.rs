@@ -18,26 +18,42 @@
 #![allow(clippy::needless_pass_by_value)]
 use actix_web::Either;
 use actix_web::{
-    get, http::StatusCode, post, rt::task::spawn_blocking, web, HttpResponse, Responder,
+    get,
+    http::{
+        header::{CacheControl, CacheDirective, ETag, Header, IfNoneMatch},
+        StatusCode,
+    },
+    post, rt::task::spawn_blocking, web, CustomizeResponder, HttpRequest, HttpResponse, Responder,
 };
 use chrono::Utc;
 use dearrow_browser_api::sync::{self as api, *};
-use dearrow_parser::{DearrowDB, ThumbnailFlags, TitleFlags};
+use dearrow_parser::{
+    compute_hashprefix, get_random_time_for_video,
+    title_casing::{format_title, TitleFormatting},
+    visibility::{title_status, winning_title},
+    DearrowDB, Thumbnail, ThumbnailFlags, Title, TitleFlags,
+};
 use cloneable_errors::{
     anyhow, bail, ErrorContext, IntoErrorIterator, ResContext, SerializableError,
 };
 use futures::join;
-use log::warn;
-use serde::Deserialize;
+use log::{info, warn};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    io::Read,
+    process::{Command, Stdio},
+    sync::{Arc, OnceLock},
+    time::{Duration, Instant},
+};
 
 use crate::built_info;
 use crate::constants::*;
 use crate::middleware::etag::{ETagCache, ETagCacheControl};
-use crate::sbserver_emulation::get_random_time_for_video;
 use crate::state::*;
-use crate::utils::{self, ExtendResponder, ResponderExt};
+use crate::utils::{self, ExtendResponder, JsonOrCsv, ResponderExt};
 
 pub fn configure(app_config: web::Data<AppConfig>) -> impl FnOnce(&mut web::ServiceConfig) {
     move |cfg| {
@@ -45,42 +61,73 @@ pub fn configure(app_config: web::Data<AppConfig>) -> impl FnOnce(&mut web::Serv
             .service(get_titles)
             .service(get_unverified_titles)
             .service(get_broken_titles)
+            .service(get_locked_titles)
+            .service(get_titles_identical_to_original)
             .service(get_title_by_uuid)
+            .service(get_title_competition)
+            .service(get_title_status)
             .service(get_titles_by_video_id)
             .service(get_titles_by_user_id)
+            .service(count_titles_by_video_id)
+            .service(get_original_title_comparison)
+            .service(count_titles_by_user_id)
             .service(get_thumbnails)
             .service(get_broken_thumbnails)
+            .service(get_locked_thumbnails)
             .service(get_thumbnail_by_uuid)
             .service(get_thumbnails_by_video_id)
             .service(get_thumbnails_by_user_id)
+            .service(count_thumbnails_by_video_id)
+            .service(count_thumbnails_by_user_id)
             .service(get_user_by_userid)
+            .service(get_user_submissions)
+            .service(get_warnings)
             .service(get_user_warnings)
             .service(get_issued_warnings)
+            .service(get_top_videos)
             .service(get_video)
+            .service(get_videos_have_data)
+            .service(get_title_casing_preview)
+            .service(get_vote_stats)
             .service(get_status)
+            .service(get_version)
+            .service(get_health)
+            .service(get_freshness)
             .service(get_errors)
-            .service(request_reload);
+            .service(request_reload)
+            .service(get_config);
 
         if app_config.innertube.enable {
             cfg.service(get_titles_by_channel)
-                .service(get_thumbnails_by_channel);
+                .service(get_thumbnails_by_channel)
+                .service(get_channel_summary);
         } else {
             cfg.route(
                 "/titles/channel/{channel}",
                 web::route().to(innertube_disabled),
             )
             .route(
-                "/titles/channel/{channel}",
+                "/thumbnails/channel/{channel}",
+                web::route().to(innertube_disabled),
+            )
+            .route(
+                "/channel/{channel}/summary",
                 web::route().to(innertube_disabled),
             );
         }
+
+        // only mounted when enabled, requests fall through to the 404 handler otherwise
+        if app_config.enable_debug_endpoints {
+            cfg.service(get_hashprefix_bucket);
+        }
     }
 }
 
 type JsonResult<T> = utils::Result<web::Json<T>>;
-type JsonResultOrFetchProgress<T> = utils::Result<
+type ListResult<T> = utils::Result<JsonOrCsv<T>>;
+type ListResultOrFetchProgress<T> = utils::Result<
     Either<
-        web::Json<T>,
+        ExtendResponder<CustomizeResponder<JsonOrCsv<T>>>,
         (
             ExtendResponder<web::Json<api::ChannelFetchProgress>>,
             StatusCode,
@@ -93,6 +140,9 @@ type JsonResultOrFetchProgress<T> = utils::Result<
 pub struct MainEndpointURLParams {
     pub offset: usize,
     pub count: usize,
+    /// Only used by `/titles` and `/thumbnails`, newest first if neither is given
+    pub sort_by: SortBy,
+    pub order: SortOrder,
 }
 
 impl Default for MainEndpointURLParams {
@@ -100,12 +150,63 @@ impl Default for MainEndpointURLParams {
         Self {
             offset: 0,
             count: 50,
+            sort_by: SortBy::TimeSubmitted,
+            order: SortOrder::Desc,
         }
     }
 }
 
-async fn innertube_disabled() -> HttpResponse {
-    HttpResponse::NotFound().body("This endpoint requires making requests to innertube, which is disabled on this DeArrow Browser instance.")
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortBy {
+    TimeSubmitted,
+    Score,
+    Votes,
+    Downvotes,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// Orders entries sorted by submission time as requested by the query.
+/// Entries with equal sort keys stay ordered by submission time, in the requested direction.
+fn sort_entries<'a, T>(
+    entries: &'a [T],
+    query: &MainEndpointURLParams,
+    key: impl Fn(&T, SortBy) -> i16,
+) -> Box<dyn Iterator<Item = &'a T> + 'a> {
+    let sort_by = query.sort_by;
+    match (sort_by, query.order) {
+        (SortBy::TimeSubmitted, SortOrder::Asc) => Box::new(entries.iter()),
+        (SortBy::TimeSubmitted, SortOrder::Desc) => Box::new(entries.iter().rev()),
+        (_, SortOrder::Asc) => {
+            let mut sorted: Vec<&T> = entries.iter().collect();
+            sorted.sort_by_key(|e| key(e, sort_by));
+            Box::new(sorted.into_iter())
+        },
+        (_, SortOrder::Desc) => {
+            let mut sorted: Vec<&T> = entries.iter().rev().collect();
+            sorted.sort_by_key(|e| Reverse(key(e, sort_by)));
+            Box::new(sorted.into_iter())
+        },
+    }
+}
+
+/// Channel endpoints return every matching object unless `count` is given
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct ChannelURLParams {
+    pub offset: usize,
+    pub count: Option<usize>,
+}
+
+async fn innertube_disabled() -> utils::Result<HttpResponse> {
+    Err(utils::Error::from(anyhow!("This endpoint requires making requests to innertube, which is disabled on this DeArrow Browser instance."))
+        .set_status(StatusCode::NOT_FOUND))
 }
 
 #[get("/")]
@@ -132,6 +233,8 @@ async fn get_status(
         channel_cache.num_channels_fscached()
     );
     let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    // None if still being computed, or if the lock is busy
+    let stats = db.stats.try_read().ok().and_then(|stats| stats.clone());
     Ok(web::Json(StatusResponse {
         last_updated: db.last_updated,
         last_modified: db.last_modified,
@@ -143,8 +246,12 @@ async fn get_status(
         warnings: db.db.warnings.len(),
         errors: db.errors.len(),
         string_count: strings,
-        video_infos: db.video_info_count,
-        uncut_segments: db.uncut_segment_count,
+        video_infos: stats.as_ref().map(|s| s.video_info_count),
+        uncut_segments: stats.as_ref().map(|s| s.uncut_segment_count),
+        daily_submissions: stats.map(|s| s.daily_submissions.to_vec()),
+        titles_missing_votes: db.db.broken_entries.titles_missing_votes,
+        thumbnails_missing_votes: db.db.broken_entries.thumbnails_missing_votes,
+        thumbnails_missing_timestamp: db.db.broken_entries.thumbnails_missing_timestamp,
         cached_channels,
         fscached_channels,
         server_version: SERVER_VERSION.clone(),
@@ -152,6 +259,61 @@ async fn get_status(
         server_git_dirty: built_info::GIT_DIRTY,
         server_build_timestamp: *BUILD_TIMESTAMP,
         server_startup_timestamp: config.startup_timestamp.timestamp(),
+        api_versions: API_VERSIONS.iter().map(|&v| v.into()).collect(),
+        report_issue_url: config.report_issue_url.as_deref().map(Into::into),
+        notice: (!config.notice.trim().is_empty()).then(|| Notice {
+            text: config.notice.trim().into(),
+            severity: config.notice_severity,
+        }),
+        trusted_users: config.trusted_users.iter().map(|u| u.as_str().into()).collect(),
+    }))
+}
+
+/// Lightweight alternative to [`get_status`] - doesn't touch the database
+#[get("/version")]
+async fn get_version(req: HttpRequest) -> HttpResponse {
+    let not_modified = IfNoneMatch::parse(&req)
+        .is_ok_and(|inm| matches!(inm, IfNoneMatch::Items(etags) if etags.iter().any(|e| e.weak_eq(&BUILD_ETAG))));
+    let mut resp = if not_modified {
+        HttpResponse::NotModified()
+    } else {
+        HttpResponse::Ok()
+    };
+    resp.insert_header(ETag(BUILD_ETAG.clone()))
+        .insert_header(CacheControl(vec![CacheDirective::NoCache]));
+    if not_modified {
+        return resp.finish();
+    }
+    resp.json(VersionResponse {
+        server_version: SERVER_VERSION.clone(),
+        server_git_hash: SERVER_GIT_HASH.clone(),
+        server_git_dirty: built_info::GIT_DIRTY,
+        server_build_timestamp: *BUILD_TIMESTAMP,
+        api_versions: API_VERSIONS.iter().map(|&v| v.into()).collect(),
+    })
+}
+
+/// Readiness probe, responds with 200 even if the database is not loaded
+#[get("/health")]
+async fn get_health(db_lock: DBLock) -> JsonResult<HealthResponse> {
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    Ok(web::Json(HealthResponse {
+        ready: db.available,
+        updating_now: db.updating_now,
+    }))
+}
+
+/// Lets operators check that every data stream is still receiving submissions
+#[get("/freshness")]
+async fn get_freshness(db_lock: DBLock) -> JsonResult<FreshnessResponse> {
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    Ok(web::Json(FreshnessResponse {
+        last_updated: db.last_updated,
+        last_modified: db.last_modified,
+        // titles and thumbnails are sorted by time_submitted
+        titles: db.db.titles.last().map(|t| t.time_submitted),
+        thumbnails: db.db.thumbnails.last().map(|t| t.time_submitted),
+        warnings: db.db.warnings.iter().map(|w| w.time_issued).max(),
     }))
 }
 
@@ -160,7 +322,70 @@ struct Auth {
     auth: Option<String>,
 }
 
-fn do_reload(
+/// Compares the provided secret with the configured one
+fn check_auth(config: &AppConfig, provided: &str) -> bool {
+    Sha256::digest(provided) == Sha256::digest(config.auth_secret.as_str())
+}
+
+/// Runs the configured `pre_reload_command`, if any, logging its output
+///
+/// Fails if the command can't be started, exits unsuccessfully or exceeds the timeout.
+fn run_pre_reload_command(config: &AppConfig) -> Result<(), ErrorContext> {
+    let Some((program, args)) = config.pre_reload_command.split_first() else {
+        return Ok(());
+    };
+    info!("Running the pre-reload command: {}", config.pre_reload_command.join(" "));
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to start the pre-reload command {program}"))?;
+    // pipes have to be drained while waiting, the command could block on a full pipe otherwise
+    let mut stdout = child.stdout.take().expect("stdout should be piped");
+    let mut stderr = child.stderr.take().expect("stderr should be piped");
+    let stdout_thread = std::thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stdout.read_to_string(&mut output);
+        output
+    });
+    let stderr_thread = std::thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stderr.read_to_string(&mut output);
+        output
+    });
+
+    let timeout = Duration::from_secs_f64(config.pre_reload_timeout_secs);
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().context("Failed to wait for the pre-reload command")? {
+            break Some(status);
+        }
+        if started.elapsed() > timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            break None;
+        }
+        std::thread::sleep(PRE_RELOAD_POLL_INTERVAL);
+    };
+    let stdout = stdout_thread.join().unwrap_or_default();
+    let stderr = stderr_thread.join().unwrap_or_default();
+    if !stdout.trim().is_empty() {
+        info!("Pre-reload command stdout:\n{}", stdout.trim_end());
+    }
+    if !stderr.trim().is_empty() {
+        warn!("Pre-reload command stderr:\n{}", stderr.trim_end());
+    }
+
+    match status {
+        None => bail!(format!("The pre-reload command timed out after {} seconds: {}", timeout.as_secs_f64(), stderr.trim())),
+        Some(status) if !status.success() => bail!(format!("The pre-reload command failed with {status}: {}", stderr.trim())),
+        Some(_) => Ok(()),
+    }
+}
+
+pub fn do_reload(
     db_lock: DBLock,
     string_set_lock: StringSetLock,
     config: web::Data<AppConfig>,
@@ -177,9 +402,18 @@ fn do_reload(
         .read()
         .map_err(|_| SS_READ_ERR.clone())?
         .clone();
+    string_set_clone.reserve_total(string_set_clone.set.len().max(config.string_set_capacity));
     let (mut new_db, errors) =
-        DearrowDB::load_dir(config.mirror_path.as_path(), &mut string_set_clone)?;
+        match run_pre_reload_command(&config).and_then(|()| DearrowDB::load_dir(config.mirror_path.as_path(), config.load_category_flags(), config.load_options(), &mut string_set_clone)) {
+            Ok(result) => result,
+            Err(err) => {
+                // allow the next reload to proceed
+                db_lock.write().map_err(|_| DB_WRITE_ERR.clone())?.updating_now = false;
+                return Err(err);
+            },
+        };
     new_db.sort();
+    let video_presence = index_video_presence(&new_db);
     let last_updated = Utc::now().timestamp_millis();
     let last_modified = utils::get_mtime(&config.mirror_path.join("titles.csv"));
     {
@@ -187,21 +421,25 @@ fn do_reload(
         let mut db_state = db_lock.write().map_err(|_| DB_WRITE_ERR.clone())?;
         *string_set = string_set_clone;
         *db_state = DatabaseState {
-            db: new_db,
+            db: Arc::new(new_db),
             errors: errors.into(),
             last_updated,
             last_modified,
             updating_now: false,
+            available: true,
             etag: None,
             channel_cache: db_state.channel_cache.reset(),
-            uncut_segment_count: 0,
-            video_info_count: 0,
+            stats: Arc::default(),
+            sitemap: OnceLock::new(),
+            top_videos: OnceLock::new(),
+            vote_stats: OnceLock::new(),
+            video_presence,
         };
-        db_state.uncut_segment_count = db_state.calculate_uncut_segment_count();
-        db_state.video_info_count = db_state.calculate_video_info_count();
         db_state.etag = Some(db_state.generate_etag());
         string_set.clean();
+        record_string_count(&config, &string_set);
     }
+    compute_stats_in_background(db_lock);
     warn!("Reload finished");
     Ok(())
 }
@@ -213,39 +451,79 @@ async fn request_reload(
     config: web::Data<AppConfig>,
     auth: web::Query<Auth>,
 ) -> HttpResponse {
-    let provided_hash = match auth.auth.as_deref() {
+    match auth.auth.as_deref() {
         None => {
             return HttpResponse::NotFound().finish();
         }
-        Some(s) => Sha256::digest(s),
-    };
-    let actual_hash = Sha256::digest(config.auth_secret.as_str());
-
-    if provided_hash != actual_hash {
-        return HttpResponse::Forbidden().finish();
+        Some(s) if !check_auth(&config, s) => {
+            return HttpResponse::Forbidden().finish();
+        }
+        Some(_) => {}
     }
     match spawn_blocking(move || do_reload(db_lock, string_set_lock, config)).await {
-        Ok(..) => HttpResponse::Ok().body("Reload complete"),
+        Ok(Ok(())) => HttpResponse::Ok().body("Reload complete"),
+        Ok(Err(e)) => HttpResponse::InternalServerError().body(format!("{e:?}")),
         Err(e) => HttpResponse::InternalServerError().body(format!("{e:?}")),
     }
 }
 
+/// Effective configuration of the running server, with secrets redacted
+#[get("/config")]
+async fn get_config(config: web::Data<AppConfig>, auth: web::Query<Auth>) -> utils::Result<HttpResponse> {
+    match auth.auth.as_deref() {
+        None => {
+            return Ok(HttpResponse::NotFound().finish());
+        }
+        Some(s) if !check_auth(&config, s) => {
+            return Ok(HttpResponse::Forbidden().finish());
+        }
+        Some(_) => {}
+    }
+    let mut value = serde_json::to_value(&**config).context("Failed to serialize the config")?;
+    for path in REDACTED_CONFIG_KEYS {
+        // unset fields are kept as they are, so that it's still visible whether they're set
+        let is_unset = |v: &serde_json::Value| v.is_null() || v.as_array().is_some_and(Vec::is_empty);
+        if let Some(field) = value.pointer_mut(path).filter(|v| !is_unset(v)) {
+            *field = serde_json::Value::from("<redacted>");
+        }
+    }
+    Ok(HttpResponse::Ok().json(value))
+}
+
+/// Authorized requests may request any amount of errors, to fetch the full list at once
 #[get("/errors")]
-async fn get_errors(db_lock: DBLock) -> JsonResult<Vec<SerializableError>> {
+async fn get_errors(
+    db_lock: DBLock,
+    config: web::Data<AppConfig>,
+    query: web::Query<MainEndpointURLParams>,
+    auth: web::Query<Auth>,
+) -> JsonResult<ErrorsPage<SerializableError>> {
+    let authorized = auth.auth.as_deref().is_some_and(|s| check_auth(&config, s));
+    if query.count > 1024 && !authorized {
+        return Err(utils::Error::from(anyhow!(
+            "Too many requested errors. You requested {} errors, but the configured max is 1024.",
+            query.count
+        ))
+        .set_status(StatusCode::BAD_REQUEST));
+    }
     let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
-    Ok(web::Json(
-        db.errors
+    Ok(web::Json(ErrorsPage {
+        total: db.errors.len(),
+        offset: query.offset,
+        errors: db.errors
             .iter()
+            .skip(query.offset)
+            .take(query.count)
             .map(IntoErrorIterator::serializable_copy)
             .collect(),
-    ))
+    }))
 }
 
 #[get("/titles", wrap = "ETagCache")]
 async fn get_titles(
     db_lock: DBLock,
     query: web::Query<MainEndpointURLParams>,
-) -> JsonResult<Vec<ApiTitle>> {
+) -> ListResult<ApiTitle> {
     if query.count > 1024 {
         return Err(utils::Error::from(anyhow!(
             "Too many requested titles. You requested {} titles, but the configured max is 1024.",
@@ -254,22 +532,41 @@ async fn get_titles(
         .set_status(StatusCode::BAD_REQUEST));
     }
     let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
-    Ok(web::Json(
+    let key = |t: &Title, sort_by| match sort_by {
+        SortBy::TimeSubmitted => 0,
+        SortBy::Score => t.score().into(),
+        SortBy::Votes => t.votes.into(),
+        SortBy::Downvotes => t.downvotes.into(),
+    };
+    Ok(JsonOrCsv(
+        sort_entries(&db.db.titles, &query, key)
+            .skip(query.offset)
+            .take(query.count)
+            .map(|t| t.into_with_db(&db.db))
+            .collect::<Vec<_>>(),
+    ))
+}
+
+/// Community titles identical to a known original title of their video, which add nothing
+/// over the original. Removed titles are skipped, as they're already dealt with.
+#[get("/titles/identical_to_original", wrap = "ETagCache")]
+async fn get_titles_identical_to_original(db_lock: DBLock) -> ListResult<ApiTitle> {
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    Ok(JsonOrCsv(
         db.db
             .titles
             .iter()
             .rev()
-            .skip(query.offset)
-            .take(query.count)
+            .filter(|t| t.identical_to_original == Some(true) && !t.flags.contains(TitleFlags::Removed))
             .map(|t| t.into_with_db(&db.db))
             .collect::<Vec<_>>(),
     ))
 }
 
 #[get("/titles/unverified", wrap = "ETagCache")]
-async fn get_unverified_titles(db_lock: DBLock) -> JsonResult<Vec<ApiTitle>> {
+async fn get_unverified_titles(db_lock: DBLock) -> ListResult<ApiTitle> {
     let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
-    Ok(web::Json(
+    Ok(JsonOrCsv(
         db.db
             .titles
             .iter()
@@ -286,10 +583,24 @@ async fn get_unverified_titles(db_lock: DBLock) -> JsonResult<Vec<ApiTitle>> {
     ))
 }
 
+#[get("/titles/locked", wrap = "ETagCache")]
+async fn get_locked_titles(db_lock: DBLock) -> ListResult<ApiTitle> {
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    Ok(JsonOrCsv(
+        db.db
+            .titles
+            .iter()
+            .rev()
+            .filter(|t| t.flags.contains(TitleFlags::Locked))
+            .map(|t| t.into_with_db(&db.db))
+            .collect::<Vec<_>>(),
+    ))
+}
+
 #[get("/titles/broken", wrap = "ETagCache")]
-async fn get_broken_titles(db_lock: DBLock) -> JsonResult<Vec<ApiTitle>> {
+async fn get_broken_titles(db_lock: DBLock) -> ListResult<ApiTitle> {
     let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
-    Ok(web::Json(
+    Ok(JsonOrCsv(
         db.db
             .titles
             .iter()
@@ -326,12 +637,125 @@ async fn get_title_by_uuid(
     ))
 }
 
+#[get("/titles/uuid/{uuid}/competition", wrap = "ETagCache")]
+async fn get_title_competition(
+    db_lock: DBLock,
+    string_set: StringSetLock,
+    path: web::Path<String>,
+) -> JsonResult<TitleCompetition> {
+    let Some(uuid) = string_set
+        .read()
+        .map_err(|_| SS_READ_ERR.clone())?
+        .set
+        .get(path.into_inner().as_str())
+        .cloned()
+    else {
+        return Err(utils::Error::EmptyStatus(StatusCode::NOT_FOUND));
+    };
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    let title = db
+        .db
+        .titles
+        .iter()
+        .find(|t| Arc::ptr_eq(&t.uuid, &uuid))
+        .ok_or(utils::Error::EmptyStatus(StatusCode::NOT_FOUND))?;
+
+    if !title.returned_by_branding() {
+        return Ok(web::Json(TitleCompetition::Hidden));
+    }
+
+    let winner = winning_title(db.db.titles.iter().filter(|t| Arc::ptr_eq(&t.video_id, &title.video_id)));
+
+    Ok(web::Json(match winner {
+        Some(winner) if Arc::ptr_eq(&winner.uuid, &title.uuid) => TitleCompetition::Winning,
+        Some(winner)
+            if winner.flags.contains(TitleFlags::Locked)
+                && !title.flags.contains(TitleFlags::Locked) =>
+        {
+            TitleCompetition::LockedOut {
+                winner: winner.into_with_db(&db.db),
+            }
+        }
+        Some(winner) => TitleCompetition::Behind {
+            score_needed: i16::from(winner.score()) - i16::from(title.score()) + 1,
+            winner: Some(winner.into_with_db(&db.db)),
+        },
+        None => TitleCompetition::Behind {
+            winner: None,
+            score_needed: -i16::from(title.score()),
+        },
+    }))
+}
+
+#[get("/titles/uuid/{uuid}/status", wrap = "ETagCache")]
+async fn get_title_status(
+    db_lock: DBLock,
+    string_set: StringSetLock,
+    path: web::Path<String>,
+) -> JsonResult<api::TitleStatus> {
+    let Some(uuid) = string_set
+        .read()
+        .map_err(|_| SS_READ_ERR.clone())?
+        .set
+        .get(path.into_inner().as_str())
+        .cloned()
+    else {
+        return Err(utils::Error::EmptyStatus(StatusCode::NOT_FOUND));
+    };
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    let title = db
+        .db
+        .titles
+        .iter()
+        .find(|t| Arc::ptr_eq(&t.uuid, &uuid))
+        .ok_or(utils::Error::EmptyStatus(StatusCode::NOT_FOUND))?;
+
+    let status = title_status(title, db.db.titles.iter().filter(|t| Arc::ptr_eq(&t.video_id, &title.video_id)));
+    Ok(web::Json(api::TitleStatus {
+        title: title.into_with_db(&db.db),
+        visibility: status.visibility.into(),
+        reasons: status.reasons.iter().map(Into::into).collect(),
+        winner: status.winner.map(|w| w.into_with_db(&db.db)),
+    }))
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct DedupeParams {
+    dedupe: bool,
+}
+
+/// Collapses titles with identical text into the highest scoring one, preserving the order of
+/// their most recent submissions
+fn dedupe_titles<'a>(titles: impl Iterator<Item = &'a Title>) -> Vec<(&'a Title, u32)> {
+    // compared by value, title texts are not interned with `skip_title_interning`
+    let mut groups: HashMap<&str, usize> = HashMap::new();
+    let mut kept: Vec<(&Title, u32)> = Vec::new();
+    for title in titles {
+        match groups.get(&*title.title) {
+            None => {
+                groups.insert(&title.title, kept.len());
+                kept.push((title, 0));
+            }
+            Some(&index) => {
+                let (best, merged) = &mut kept[index];
+                *merged += 1;
+                if title.score() > best.score() {
+                    *best = title;
+                }
+            }
+        }
+    }
+    kept
+}
+
 #[get("/titles/video_id/{video_id}", wrap = "ETagCache")]
 async fn get_titles_by_video_id(
     db_lock: DBLock,
     string_set: StringSetLock,
     path: web::Path<String>,
-) -> JsonResult<Vec<ApiTitle>> {
+    query: web::Query<DedupeParams>,
+) -> ListResult<ApiTitle> {
     let video_id = string_set
         .read()
         .map_err(|_| SS_READ_ERR.clone())?
@@ -341,16 +765,27 @@ async fn get_titles_by_video_id(
     let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
     let titles = match video_id {
         None => vec![],
-        Some(id) => db
-            .db
-            .titles
-            .iter()
-            .rev()
-            .filter(|title| Arc::ptr_eq(&title.video_id, &id))
-            .map(|t| t.into_with_db(&db.db))
-            .collect(),
+        Some(id) => {
+            let titles = db
+                .db
+                .titles
+                .iter()
+                .rev()
+                .filter(|title| Arc::ptr_eq(&title.video_id, &id));
+            if query.dedupe {
+                dedupe_titles(titles)
+                    .into_iter()
+                    .map(|(t, merged)| ApiTitle {
+                        merged: Some(merged),
+                        ..t.into_with_db(&db.db)
+                    })
+                    .collect()
+            } else {
+                titles.map(|t| t.into_with_db(&db.db)).collect()
+            }
+        }
     };
-    Ok(web::Json(titles))
+    Ok(JsonOrCsv(titles))
 }
 
 #[get("/titles/user_id/{user_id}", wrap = "ETagCache")]
@@ -358,7 +793,7 @@ async fn get_titles_by_user_id(
     db_lock: DBLock,
     string_set: StringSetLock,
     path: web::Path<String>,
-) -> JsonResult<Vec<ApiTitle>> {
+) -> ListResult<ApiTitle> {
     let user_id = string_set
         .read()
         .map_err(|_| SS_READ_ERR.clone())?
@@ -377,14 +812,202 @@ async fn get_titles_by_user_id(
             .map(|t| t.into_with_db(&db.db))
             .collect(),
     };
-    Ok(web::Json(titles))
+    Ok(JsonOrCsv(titles))
+}
+
+/// Counts objects with `field` pointing to the interned instance of `id`.
+/// The count is 0 if `id` isn't in the string set, as no object can reference it then.
+fn count_by_id<T>(
+    db_lock: &DBLock,
+    string_set: &StringSetLock,
+    id: &str,
+    objects: impl Fn(&DearrowDB) -> &[T],
+    field: impl Fn(&T) -> &Arc<str>,
+) -> JsonResult<CountResponse> {
+    let id = string_set
+        .read()
+        .map_err(|_| SS_READ_ERR.clone())?
+        .set
+        .get(id)
+        .cloned();
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    let count = match id {
+        None => 0,
+        Some(id) => objects(&db.db).iter().filter(|o| Arc::ptr_eq(field(o), &id)).count(),
+    };
+    Ok(web::Json(CountResponse { count: count as u64 }))
+}
+
+/// Original title of a video, its winning title and all of its titles in one response.
+/// The original title is only known if it was submitted by voting for it in the extension.
+#[get("/titles/video_id/{video_id}/original", wrap = "ETagCache")]
+async fn get_original_title_comparison(
+    db_lock: DBLock,
+    string_set: StringSetLock,
+    path: web::Path<String>,
+) -> JsonResult<OriginalTitleComparison> {
+    let video_id = string_set
+        .read()
+        .map_err(|_| SS_READ_ERR.clone())?
+        .set
+        .get(path.into_inner().as_str())
+        .cloned();
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    let Some(video_id) = video_id else {
+        return Ok(web::Json(OriginalTitleComparison {
+            original_title: None,
+            winner: None,
+            titles: vec![],
+        }));
+    };
+    let video_titles = db.db.titles.iter().rev().filter(|t| Arc::ptr_eq(&t.video_id, &video_id));
+    Ok(web::Json(OriginalTitleComparison {
+        original_title: video_titles
+            .clone()
+            .find(|t| t.flags.contains(TitleFlags::Original))
+            .map(|t| t.title.clone()),
+        winner: winning_title(video_titles.clone()).map(|t| t.into_with_db(&db.db)),
+        titles: video_titles.map(|t| t.into_with_db(&db.db)).collect(),
+    }))
+}
+
+#[get("/titles/video_id/{video_id}/count", wrap = "ETagCache")]
+async fn count_titles_by_video_id(
+    db_lock: DBLock,
+    string_set: StringSetLock,
+    path: web::Path<String>,
+) -> JsonResult<CountResponse> {
+    count_by_id(&db_lock, &string_set, &path, |db| &db.titles, |t| &t.video_id)
+}
+
+#[get("/titles/user_id/{user_id}/count", wrap = "ETagCache")]
+async fn count_titles_by_user_id(
+    db_lock: DBLock,
+    string_set: StringSetLock,
+    path: web::Path<String>,
+) -> JsonResult<CountResponse> {
+    count_by_id(&db_lock, &string_set, &path, |db| &db.titles, |t| &t.user_id)
+}
+
+/// Parts of a channel result that can change between database loads, fed into its `ETag` validator
+trait ChannelValidated {
+    fn hash_version(&self, hasher: &mut Sha256);
+}
+
+/// Hashes a string with a terminator, so that neighbouring strings can't be confused
+fn hash_str(hasher: &mut Sha256, s: &str) {
+    hasher.update(s.as_bytes());
+    hasher.update([0]);
+}
+
+fn hash_username(hasher: &mut Sha256, username: Option<&str>, vip: bool) {
+    hash_str(hasher, username.unwrap_or_default());
+    hasher.update([u8::from(username.is_some()), u8::from(vip)]);
+}
+
+// the submitted data of a title or thumbnail never changes, only its votes, flags and submitter do
+impl ChannelValidated for ApiTitle {
+    fn hash_version(&self, hasher: &mut Sha256) {
+        hash_str(hasher, &self.uuid);
+        hasher.update(self.votes.to_le_bytes());
+        hasher.update(self.downvotes.to_le_bytes());
+        hasher.update([
+            self.original,
+            self.locked,
+            self.shadow_hidden,
+            self.unverified,
+            self.removed,
+            self.votes_missing,
+        ].map(u8::from));
+        hash_username(hasher, self.username.as_deref(), self.vip);
+    }
+}
+
+impl ChannelValidated for ApiThumbnail {
+    fn hash_version(&self, hasher: &mut Sha256) {
+        hash_str(hasher, &self.uuid);
+        hasher.update(self.votes.to_le_bytes());
+        hasher.update(self.downvotes.to_le_bytes());
+        hasher.update([
+            self.original,
+            self.locked,
+            self.shadow_hidden,
+            self.removed,
+            self.votes_missing,
+            self.timestamp_missing,
+        ].map(u8::from));
+        hash_username(hasher, self.username.as_deref(), self.vip);
+    }
+}
+
+impl ChannelValidated for api::ChannelSummary {
+    fn hash_version(&self, hasher: &mut Sha256) {
+        hash_str(hasher, &self.channel_name);
+        for count in [
+            self.total_videos,
+            self.videos_with_titles,
+            self.videos_with_thumbnails,
+            self.title_submissions,
+            self.thumbnail_submissions,
+        ] {
+            hasher.update(count.to_le_bytes());
+        }
+        for submitter in &self.top_submitters {
+            hash_str(hasher, &submitter.user_id);
+            hash_username(hasher, submitter.username.as_deref(), false);
+            hasher.update(submitter.titles.to_le_bytes());
+            hasher.update(submitter.thumbnails.to_le_bytes());
+        }
+    }
+}
+
+/// Builds an `ETag` validator covering the channel's video list and the computed results, so that
+/// only changes affecting this channel invalidate cached responses
+///
+/// `total` is the amount of results before pagination, or 1 for single objects.
+fn channel_validator<'a, T: ChannelValidated + 'a>(video_ids: &[Arc<str>], total: usize, results: impl IntoIterator<Item = &'a T>) -> ETagCacheControl {
+    let mut sorted_ids: Vec<&str> = video_ids.iter().map(|id| &**id).collect();
+    sorted_ids.sort_unstable();
+    let mut hasher = Sha256::new();
+    for video_id in sorted_ids {
+        hash_str(&mut hasher, video_id);
+    }
+    hasher.update([0]);
+    hasher.update(total.to_le_bytes());
+    for result in results {
+        result.hash_version(&mut hasher);
+    }
+    ETagCacheControl::validator(hasher)
+}
+
+/// Wraps channel results with a [`channel_validator`]
+///
+/// The amount of matched objects before pagination and of the channel's discovered videos are
+/// returned in the [`TOTAL_COUNT_HEADER`] and [`CHANNEL_VIDEOS_HEADER`] headers.
+fn channel_response<T: Serialize + DeserializeOwned + ChannelValidated>(video_ids: &[Arc<str>], channel_videos: usize, total: usize, items: Vec<T>) -> ExtendResponder<CustomizeResponder<JsonOrCsv<T>>> {
+    let validator = channel_validator(video_ids, total, &items);
+    let mut resp = JsonOrCsv(items)
+        .customize()
+        .insert_header((TOTAL_COUNT_HEADER, total))
+        .insert_header((CHANNEL_VIDEOS_HEADER, channel_videos))
+        .extend();
+    resp.extensions.insert(validator);
+    resp
 }
 
 #[get("/titles/channel/{channel}", wrap = "ETagCache")]
 async fn get_titles_by_channel(
     db_lock: DBLock,
     path: web::Path<String>,
-) -> JsonResultOrFetchProgress<Vec<ApiTitle>> {
+    query: web::Query<ChannelURLParams>,
+) -> ListResultOrFetchProgress<ApiTitle> {
+    if let Some(count) = query.count.filter(|&count| count > 1024) {
+        return Err(utils::Error::from(anyhow!(
+            "Too many requested titles. You requested {} titles, but the configured max is 1024.",
+            count
+        ))
+        .set_status(StatusCode::BAD_REQUEST));
+    }
     let channel_cache = {
         let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
         db.channel_cache.clone()
@@ -405,15 +1028,21 @@ async fn get_titles_by_channel(
             // the `StringSet`
             let vid_set: HashSet<usize> = result.video_ids.iter().map(utils::arc_addr).collect();
             let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
-            let titles = db
+            let matched: Vec<_> = db
                 .db
                 .titles
                 .iter()
                 .rev()
                 .filter(|title| vid_set.contains(&utils::arc_addr(&title.video_id)))
+                .collect();
+            let total = matched.len();
+            let titles = matched
+                .into_iter()
+                .skip(query.offset)
+                .take(query.count.unwrap_or(usize::MAX))
                 .map(|t| t.into_with_db(&db.db))
                 .collect();
-            Ok(Either::Left(web::Json(titles)))
+            Ok(Either::Left(channel_response(&result.video_ids, vid_set.len(), total, titles)))
         }
     }
 }
@@ -422,7 +1051,7 @@ async fn get_titles_by_channel(
 async fn get_thumbnails(
     db_lock: DBLock,
     query: web::Query<MainEndpointURLParams>,
-) -> JsonResult<Vec<ApiThumbnail>> {
+) -> ListResult<ApiThumbnail> {
     if query.count > 1024 {
         return Err(
             utils::Error::from(anyhow!("Too many requested thumbnails. You requested {} thumbnails, but the configured max is 1024.", query.count))
@@ -430,11 +1059,14 @@ async fn get_thumbnails(
         );
     }
     let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
-    Ok(web::Json(
-        db.db
-            .thumbnails
-            .iter()
-            .rev()
+    let key = |t: &Thumbnail, sort_by| match sort_by {
+        SortBy::TimeSubmitted => 0,
+        SortBy::Score => i16::from(t.votes) - i16::from(t.downvotes),
+        SortBy::Votes => t.votes.into(),
+        SortBy::Downvotes => t.downvotes.into(),
+    };
+    Ok(JsonOrCsv(
+        sort_entries(&db.db.thumbnails, &query, key)
             .skip(query.offset)
             .take(query.count)
             .map(|t| t.into_with_db(&db.db))
@@ -443,9 +1075,9 @@ async fn get_thumbnails(
 }
 
 #[get("/thumbnails/broken", wrap = "ETagCache")]
-async fn get_broken_thumbnails(db_lock: DBLock) -> JsonResult<Vec<ApiThumbnail>> {
+async fn get_broken_thumbnails(db_lock: DBLock) -> ListResult<ApiThumbnail> {
     let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
-    Ok(web::Json(
+    Ok(JsonOrCsv(
         db.db
             .thumbnails
             .iter()
@@ -459,6 +1091,20 @@ async fn get_broken_thumbnails(db_lock: DBLock) -> JsonResult<Vec<ApiThumbnail>>
     ))
 }
 
+#[get("/thumbnails/locked", wrap = "ETagCache")]
+async fn get_locked_thumbnails(db_lock: DBLock) -> ListResult<ApiThumbnail> {
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    Ok(JsonOrCsv(
+        db.db
+            .thumbnails
+            .iter()
+            .rev()
+            .filter(|t| t.flags.contains(ThumbnailFlags::Locked))
+            .map(|t| t.into_with_db(&db.db))
+            .collect::<Vec<_>>(),
+    ))
+}
+
 #[get("/thumbnails/uuid/{uuid}", wrap = "ETagCache")]
 async fn get_thumbnail_by_uuid(
     db_lock: DBLock,
@@ -490,7 +1136,7 @@ async fn get_thumbnails_by_video_id(
     db_lock: DBLock,
     string_set: StringSetLock,
     path: web::Path<String>,
-) -> JsonResult<Vec<ApiThumbnail>> {
+) -> ListResult<ApiThumbnail> {
     let video_id = string_set
         .read()
         .map_err(|_| SS_READ_ERR.clone())?
@@ -509,7 +1155,25 @@ async fn get_thumbnails_by_video_id(
             .map(|t| t.into_with_db(&db.db))
             .collect(),
     };
-    Ok(web::Json(titles))
+    Ok(JsonOrCsv(titles))
+}
+
+#[get("/thumbnails/video_id/{video_id}/count", wrap = "ETagCache")]
+async fn count_thumbnails_by_video_id(
+    db_lock: DBLock,
+    string_set: StringSetLock,
+    path: web::Path<String>,
+) -> JsonResult<CountResponse> {
+    count_by_id(&db_lock, &string_set, &path, |db| &db.thumbnails, |t| &t.video_id)
+}
+
+#[get("/thumbnails/user_id/{user_id}/count", wrap = "ETagCache")]
+async fn count_thumbnails_by_user_id(
+    db_lock: DBLock,
+    string_set: StringSetLock,
+    path: web::Path<String>,
+) -> JsonResult<CountResponse> {
+    count_by_id(&db_lock, &string_set, &path, |db| &db.thumbnails, |t| &t.user_id)
 }
 
 #[get("/thumbnails/user_id/{video_id}", wrap = "ETagCache")]
@@ -517,7 +1181,7 @@ async fn get_thumbnails_by_user_id(
     db_lock: DBLock,
     string_set: StringSetLock,
     path: web::Path<String>,
-) -> JsonResult<Vec<ApiThumbnail>> {
+) -> ListResult<ApiThumbnail> {
     let user_id = string_set
         .read()
         .map_err(|_| SS_READ_ERR.clone())?
@@ -536,14 +1200,22 @@ async fn get_thumbnails_by_user_id(
             .map(|t| t.into_with_db(&db.db))
             .collect(),
     };
-    Ok(web::Json(titles))
+    Ok(JsonOrCsv(titles))
 }
 
 #[get("/thumbnails/channel/{channel}", wrap = "ETagCache")]
 async fn get_thumbnails_by_channel(
     db_lock: DBLock,
     path: web::Path<String>,
-) -> JsonResultOrFetchProgress<Vec<ApiThumbnail>> {
+    query: web::Query<ChannelURLParams>,
+) -> ListResultOrFetchProgress<ApiThumbnail> {
+    if let Some(count) = query.count.filter(|&count| count > 1024) {
+        return Err(utils::Error::from(anyhow!(
+            "Too many requested thumbnails. You requested {} thumbnails, but the configured max is 1024.",
+            count
+        ))
+        .set_status(StatusCode::BAD_REQUEST));
+    }
     let channel_cache = {
         let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
         db.channel_cache.clone()
@@ -564,15 +1236,99 @@ async fn get_thumbnails_by_channel(
             // the `StringSet`
             let vid_set: HashSet<usize> = result.video_ids.iter().map(utils::arc_addr).collect();
             let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
-            let thumbs = db
+            let matched: Vec<_> = db
                 .db
                 .thumbnails
                 .iter()
                 .rev()
                 .filter(|thumbnail| vid_set.contains(&utils::arc_addr(&thumbnail.video_id)))
+                .collect();
+            let total = matched.len();
+            let thumbs = matched
+                .into_iter()
+                .skip(query.offset)
+                .take(query.count.unwrap_or(usize::MAX))
                 .map(|t| t.into_with_db(&db.db))
                 .collect();
-            Ok(Either::Left(web::Json(thumbs)))
+            Ok(Either::Left(channel_response(&result.video_ids, vid_set.len(), total, thumbs)))
+        }
+    }
+}
+
+fn compute_channel_summary(db: &DearrowDB, channel: &ChannelData) -> api::ChannelSummary {
+    let vid_set: HashSet<usize> = channel.video_ids.iter().map(utils::arc_addr).collect();
+    let mut titled_videos: HashSet<usize> = HashSet::new();
+    let mut thumbnailed_videos: HashSet<usize> = HashSet::new();
+    let mut title_submissions = 0;
+    let mut thumbnail_submissions = 0;
+    // user ID -> (titles, thumbnails)
+    let mut submitters: HashMap<&Arc<str>, (u64, u64)> = HashMap::new();
+    for title in db.titles.iter().filter(|t| vid_set.contains(&utils::arc_addr(&t.video_id))) {
+        titled_videos.insert(utils::arc_addr(&title.video_id));
+        title_submissions += 1;
+        submitters.entry(&title.user_id).or_default().0 += 1;
+    }
+    for thumb in db.thumbnails.iter().filter(|t| vid_set.contains(&utils::arc_addr(&t.video_id))) {
+        thumbnailed_videos.insert(utils::arc_addr(&thumb.video_id));
+        thumbnail_submissions += 1;
+        submitters.entry(&thumb.user_id).or_default().1 += 1;
+    }
+    let mut submitters: Vec<_> = submitters.into_iter().collect();
+    submitters.sort_unstable_by(|(a_id, a), (b_id, b)| (b.0 + b.1).cmp(&(a.0 + a.1)).then_with(|| a_id.cmp(b_id)));
+    api::ChannelSummary {
+        channel_name: channel.channel_name.as_ref().into(),
+        total_videos: channel.total_videos as u64,
+        videos_with_titles: titled_videos.len() as u64,
+        videos_with_thumbnails: thumbnailed_videos.len() as u64,
+        title_submissions,
+        thumbnail_submissions,
+        top_submitters: submitters
+            .into_iter()
+            .take(CHANNEL_SUMMARY_TOP_SUBMITTERS)
+            .map(|(user_id, (titles, thumbnails))| ChannelSubmitter {
+                user_id: user_id.clone(),
+                username: db.usernames.get(user_id).map(|u| u.username.clone()),
+                titles,
+                thumbnails,
+            })
+            .collect(),
+    }
+}
+
+#[get("/channel/{channel}/summary", wrap = "ETagCache")]
+async fn get_channel_summary(
+    db_lock: DBLock,
+    path: web::Path<String>,
+) -> utils::Result<
+    Either<
+        ExtendResponder<web::Json<Arc<api::ChannelSummary>>>,
+        (
+            ExtendResponder<web::Json<api::ChannelFetchProgress>>,
+            StatusCode,
+        ),
+    >,
+> {
+    let channel_cache = {
+        let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+        db.channel_cache.clone()
+    };
+    let channel_data = channel_cache
+        .get_channel(path.into_inner().as_str())
+        .await
+        .context("Failed to get channel info")?;
+
+    match channel_data {
+        GetChannelOutput::Pending(progress) => {
+            let mut resp = web::Json(api::ChannelFetchProgress::from(&progress)).extend();
+            resp.extensions.insert(ETagCacheControl::DoNotCache);
+            Ok(Either::Right((resp, *NOT_READY_YET)))
+        }
+        GetChannelOutput::Resolved(result) => {
+            let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+            let summary = result.summary.get_or_init(|| Arc::new(compute_channel_summary(&db.db, &result)));
+            let mut resp = web::Json(summary.clone()).extend();
+            resp.extensions.insert(channel_validator(&result.video_ids, 1, [&**summary]));
+            Ok(Either::Left(resp))
         }
     }
 }
@@ -615,7 +1371,7 @@ async fn get_user_by_userid(
             User {
                 user_id: user_id.clone(),
                 username: username.map(|u| u.username.clone()),
-                username_locked: username.map_or(false, |u| u.locked),
+                username_locked: username.is_some_and(|u| u.locked),
                 vip: db.db.vip_users.contains(&user_id),
                 title_count: db
                     .db
@@ -636,6 +1392,79 @@ async fn get_user_by_userid(
     }))
 }
 
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct WarningsURLParams {
+    active_only: bool,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct SubmissionsParams {
+    /// Limits both lists to this many most recent submissions
+    count: Option<usize>,
+}
+
+#[get("/users/user_id/{user_id}/submissions", wrap = "ETagCache")]
+async fn get_user_submissions(
+    db_lock: DBLock,
+    string_set: StringSetLock,
+    path: web::Path<String>,
+    query: web::Query<SubmissionsParams>,
+) -> JsonResult<UserSubmissions> {
+    let user_id = string_set
+        .read()
+        .map_err(|_| SS_READ_ERR.clone())?
+        .set
+        .get(path.as_str())
+        .cloned();
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    let count = query.count.unwrap_or(usize::MAX);
+    Ok(web::Json(match user_id {
+        None => UserSubmissions {
+            titles: vec![],
+            thumbnails: vec![],
+        },
+        Some(id) => UserSubmissions {
+            titles: db
+                .db
+                .titles
+                .iter()
+                .rev()
+                .filter(|title| Arc::ptr_eq(&title.user_id, &id))
+                .take(count)
+                .map(|t| t.into_with_db(&db.db))
+                .collect(),
+            thumbnails: db
+                .db
+                .thumbnails
+                .iter()
+                .rev()
+                .filter(|thumb| Arc::ptr_eq(&thumb.user_id, &id))
+                .take(count)
+                .map(|t| t.into_with_db(&db.db))
+                .collect(),
+        },
+    }))
+}
+
+#[get("/warnings", wrap = "ETagCache")]
+async fn get_warnings(
+    db_lock: DBLock,
+    query: web::Query<WarningsURLParams>,
+) -> JsonResult<Vec<ApiWarning>> {
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    Ok(web::Json(
+        db.db
+            .warnings
+            .iter()
+            .rev()
+            .filter(|w| !query.active_only || w.active)
+            .map(|w| w.into_with_db(&db.db))
+            .collect(),
+    ))
+}
+
 #[get("/warnings/user_id/{user_id}/received")]
 async fn get_user_warnings(
     db_lock: DBLock,
@@ -698,6 +1527,176 @@ fn unknown_video(video_id: Arc<str>) -> Video {
     }
 }
 
+#[derive(Deserialize)]
+struct TitleCasingParams {
+    title: String,
+    /// Format the title like an original title, without trusting its custom capitalization
+    #[serde(default)]
+    original: bool,
+}
+
+#[get("/title_casing")]
+async fn get_title_casing_preview(query: web::Query<TitleCasingParams>) -> JsonResult<TitleCasingPreview> {
+    let format = |formatting| format_title(&query.title, formatting, !query.original).into();
+    Ok(web::Json(TitleCasingPreview {
+        capitalize_words: format(TitleFormatting::CapitalizeWords),
+        title_case: format(TitleFormatting::TitleCase),
+        sentence_case: format(TitleFormatting::SentenceCase),
+        lower_case: format(TitleFormatting::LowerCase),
+        first_letter_uppercase: format(TitleFormatting::FirstLetterUppercase),
+    }))
+}
+
+#[get("/debug/hashprefix/{video_id}")]
+async fn get_hashprefix_bucket(
+    db_lock: DBLock,
+    string_set: StringSetLock,
+    path: web::Path<String>,
+) -> JsonResult<HashPrefixBucket> {
+    let hash_prefix = compute_hashprefix(path.as_str());
+    let video_id = string_set
+        .read()
+        .map_err(|_| SS_READ_ERR.clone())?
+        .set
+        .get(path.as_str())
+        .cloned();
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    Ok(web::Json(HashPrefixBucket {
+        bucket_size: db.db.video_infos[hash_prefix as usize].len(),
+        video_info_found: video_id.as_ref().is_some_and(|id| db.db.get_video_info(id).is_some()),
+        video_id: video_id.unwrap_or_else(|| path.as_str().into()),
+        hash_prefix,
+    }))
+}
+
+#[post("/videos/has_data")]
+async fn get_videos_have_data(
+    db_lock: DBLock,
+    video_ids: web::Json<Vec<String>>,
+) -> JsonResult<HashMap<Arc<str>, VideoDataPresence>> {
+    if video_ids.len() > HAS_DATA_MAX_BATCH {
+        return Err(utils::Error::from(anyhow!(
+            "Too many requested videos. You requested {} videos, but the max is {HAS_DATA_MAX_BATCH}.",
+            video_ids.len()
+        ))
+        .set_status(StatusCode::BAD_REQUEST));
+    }
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    Ok(web::Json(
+        video_ids
+            .iter()
+            .map(|id| match db.video_presence.get_key_value(id.as_str()) {
+                Some((id, presence)) => (id.clone(), *presence),
+                None => (id.as_str().into(), VideoDataPresence::default()),
+            })
+            .collect(),
+    ))
+}
+
+/// Computes the [`TOP_VIDEOS_MAX_COUNT`] videos with the most submissions, skipping videos
+/// where every submission was removed or shadowhidden
+fn compute_top_videos(db: &DearrowDB) -> Arc<[TopVideo]> {
+    #[derive(Default)]
+    struct Counts {
+        titles: usize,
+        thumbnails: usize,
+        visible: bool,
+    }
+    let mut videos: HashMap<&Arc<str>, Counts> = HashMap::new();
+    for title in &db.titles {
+        let counts = videos.entry(&title.video_id).or_default();
+        counts.titles += 1;
+        counts.visible |= !title.flags.intersects(TitleFlags::Removed | TitleFlags::ShadowHidden);
+    }
+    for thumbnail in &db.thumbnails {
+        let counts = videos.entry(&thumbnail.video_id).or_default();
+        counts.thumbnails += 1;
+        counts.visible |= !thumbnail.flags.intersects(ThumbnailFlags::Removed | ThumbnailFlags::ShadowHidden);
+    }
+    let mut videos: Vec<(&Arc<str>, Counts)> = videos.into_iter().filter(|(_, c)| c.visible).collect();
+    videos.sort_unstable_by(|(a_id, a), (b_id, b)| {
+        (b.titles + b.thumbnails).cmp(&(a.titles + a.thumbnails)).then_with(|| a_id.cmp(b_id))
+    });
+    videos
+        .into_iter()
+        .take(TOP_VIDEOS_MAX_COUNT)
+        .map(|(video_id, counts)| TopVideo {
+            video_id: video_id.clone(),
+            titles: counts.titles,
+            thumbnails: counts.thumbnails,
+        })
+        .collect()
+}
+
+/// Videos with the most title and thumbnail submissions, most submitted first
+#[get("/videos/top", wrap = "ETagCache")]
+async fn get_top_videos(
+    db_lock: DBLock,
+    query: web::Query<MainEndpointURLParams>,
+) -> JsonResult<Vec<TopVideo>> {
+    if query.count > TOP_VIDEOS_MAX_COUNT {
+        return Err(utils::Error::from(anyhow!(
+            "Too many requested videos. You requested {} videos, but the max is {TOP_VIDEOS_MAX_COUNT}.",
+            query.count
+        ))
+        .set_status(StatusCode::BAD_REQUEST));
+    }
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    let top_videos = db.top_videos.get_or_init(|| compute_top_videos(&db.db));
+    Ok(web::Json(
+        top_videos.iter().skip(query.offset).take(query.count).cloned().collect(),
+    ))
+}
+
+/// Counts votes of titles or thumbnails into [`VOTE_HISTOGRAM_BUCKETS`], skipping removed and
+/// shadowhidden entries
+fn vote_histograms(entries: impl Iterator<Item = (i8, i8, i8, bool)>) -> VoteHistograms {
+    let bucket = |value: i8| VOTE_HISTOGRAM_BUCKETS.partition_point(|&lower| lower <= value) - 1;
+    let mut histograms = VoteHistograms {
+        votes: vec![0; VOTE_HISTOGRAM_BUCKETS.len()],
+        downvotes: vec![0; VOTE_HISTOGRAM_BUCKETS.len()],
+        locked: 0,
+        high_score_unlocked: 0,
+    };
+    for (votes, downvotes, score, locked) in entries {
+        histograms.votes[bucket(votes)] += 1;
+        histograms.downvotes[bucket(downvotes)] += 1;
+        if locked {
+            histograms.locked += 1;
+        } else if score >= HIGH_SCORE_THRESHOLD {
+            histograms.high_score_unlocked += 1;
+        }
+    }
+    histograms
+}
+
+fn compute_vote_stats(db: &DearrowDB) -> Arc<VoteStatsResponse> {
+    let titles = db
+        .titles
+        .iter()
+        .filter(|t| !t.flags.intersects(TitleFlags::Removed | TitleFlags::ShadowHidden))
+        .map(|t| (t.votes, t.downvotes, t.score(), t.flags.contains(TitleFlags::Locked)));
+    let thumbnails = db
+        .thumbnails
+        .iter()
+        .filter(|t| !t.flags.intersects(ThumbnailFlags::Removed | ThumbnailFlags::ShadowHidden))
+        .map(|t| (t.votes, t.downvotes, t.votes.saturating_sub(t.downvotes), t.flags.contains(ThumbnailFlags::Locked)));
+    Arc::new(VoteStatsResponse {
+        buckets: VOTE_HISTOGRAM_BUCKETS.to_vec(),
+        high_score_threshold: HIGH_SCORE_THRESHOLD,
+        titles: vote_histograms(titles),
+        thumbnails: vote_histograms(thumbnails),
+    })
+}
+
+/// Distribution of votes and downvotes of titles and thumbnails
+#[get("/stats/votes", wrap = "ETagCache")]
+async fn get_vote_stats(db_lock: DBLock) -> JsonResult<VoteStatsResponse> {
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    let vote_stats = db.vote_stats.get_or_init(|| compute_vote_stats(&db.db));
+    Ok(web::Json((**vote_stats).clone()))
+}
+
 #[get("/videos/{video_id}", wrap = "ETagCache")]
 async fn get_video(
     db_lock: DBLock,
@@ -728,3 +1727,80 @@ async fn get_video(
         }
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::header, test, App};
+
+    use super::*;
+
+    const UCID: &str = "UCuAXFkgsw1L7xaCfnd5JJOw";
+
+    /// Looks up the instances of the given video IDs stored in the database, as channel
+    /// results are matched by pointer
+    fn video_ids(db: &DBLock, ids: &[&str]) -> Box<[Arc<str>]> {
+        let db = db.read().unwrap();
+        ids.iter()
+            .map(|id| db.db.titles.iter().find(|t| &*t.video_id == *id).expect("video should have titles").video_id.clone())
+            .collect()
+    }
+
+    fn etag(resp: &actix_web::dev::ServiceResponse) -> String {
+        resp.headers().get(header::ETAG).expect("response should have an ETag").to_str().unwrap().to_owned()
+    }
+
+    #[actix_web::test]
+    async fn config_redacts_secrets() {
+        let config = web::Data::new(AppConfig {
+            auth_secret: "secret".to_owned(),
+            pre_reload_command: vec!["rsync".to_owned(), "--password-file=/run/secrets/mirror".to_owned()],
+            ..AppConfig::default()
+        });
+        let app = test::init_service(App::new().app_data(config).service(get_config)).await;
+        let req = test::TestRequest::get().uri("/config?auth=secret").to_request();
+        let value: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(value["auth_secret"], "<redacted>");
+        assert_eq!(value["pre_reload_command"], "<redacted>");
+        // unset secrets stay visible as such
+        assert!(value["innertube"]["po_token"].is_null(), "{value}");
+    }
+
+    #[actix_web::test]
+    async fn channel_etags_follow_channel_refreshes() {
+        for path in [format!("/titles/channel/{UCID}"), format!("/channel/{UCID}/summary")] {
+            let (db, _) = DatabaseState::golden_fixture();
+            let channel_cache = db.read().unwrap().channel_cache.clone();
+            channel_cache.insert_resolved(UCID, video_ids(&db, &["dQw4w9WgXcQ"])).await;
+            let app = test::init_service(
+                App::new()
+                    .app_data(db.clone())
+                    .service(get_titles_by_channel)
+                    .service(get_channel_summary),
+            ).await;
+            let get = |etag: Option<&str>| {
+                let req = test::TestRequest::get().uri(&path);
+                match etag {
+                    Some(etag) => req.insert_header((header::IF_NONE_MATCH, etag.to_owned())),
+                    None => req,
+                }.to_request()
+            };
+
+            let resp = test::call_service(&app, get(None)).await;
+            assert_eq!(resp.status(), StatusCode::OK, "{path}");
+            let first = etag(&resp);
+            let resp = test::call_service(&app, get(Some(&first))).await;
+            assert_eq!(resp.status(), StatusCode::NOT_MODIFIED, "{path}");
+
+            // a reload which doesn't affect the channel keeps cached responses valid
+            db.write().unwrap().etag = Some(header::EntityTag::new_weak("reloaded".to_owned()));
+            let resp = test::call_service(&app, get(Some(&first))).await;
+            assert_eq!(resp.status(), StatusCode::NOT_MODIFIED, "{path}");
+
+            // a channel refresh which finds more videos invalidates them
+            channel_cache.insert_resolved(UCID, video_ids(&db, &["dQw4w9WgXcQ", "jNQXAC9IVRw"])).await;
+            let resp = test::call_service(&app, get(Some(&first))).await;
+            assert_eq!(resp.status(), StatusCode::OK, "{path}");
+            assert_ne!(etag(&resp), first, "{path}");
+        }
+    }
+}
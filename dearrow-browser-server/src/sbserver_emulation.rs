@@ -22,9 +22,8 @@
 use std::{sync::Arc, collections::HashMap};
 
 use actix_web::{get, http::StatusCode, post, web, CustomizeResponder, HttpResponse, Responder};
-use alea_js::Alea;
 use cloneable_errors::anyhow;
-use dearrow_parser::{Extension, Thumbnail, ThumbnailFlags, Title, TitleFlags, VideoInfo};
+use dearrow_parser::{get_random_time_for_video, Extension, Thumbnail, ThumbnailFlags, Title, TitleFlags, VideoInfo};
 use serde::{Deserialize, Serialize};
 
 use crate::{middleware::etag::ETagCache, state::{DBLock, StringSetLock}, utils};
@@ -127,36 +126,6 @@ struct SBApiVideo {
 }
 
 // https://github.com/ajayyy/SponsorBlockServer/blob/af31f511a53a7e30ad27123656a911393200672b/src/routes/getBranding.ts#L233
-pub fn get_random_time_for_video(video_id: &str, video_info: Option<&VideoInfo>) -> f64 {
-    let random_time = Alea::new(video_id).random();
-
-    if let Some(video_info) = video_info {
-        let mut random_time = if !video_info.has_outro && random_time > 0.9 {
-            random_time - 0.9
-        } else {
-            random_time
-        };
-
-        // Scale to the unmarked length of the video
-        random_time *= video_info.uncut_segments.iter().map(|s| s.length).sum::<f64>();
-
-        // Then map it to the unmarked segments
-        for segment in &video_info.uncut_segments {
-            if random_time <= segment.length {
-                random_time += segment.offset;
-                break;
-            }
-            random_time -= segment.length;
-        };
-
-        random_time
-    } else if random_time > 0.9 {
-        random_time - 0.9
-    } else {
-        random_time
-    }
-}
-
 fn unknown_video(video_id: &str) -> SBApiVideo {
     SBApiVideo {
         titles: vec![],
@@ -188,16 +157,9 @@ async fn get_video_branding(db_lock: DBLock, string_set: StringSetLock, query: w
             Ok(web::Json(SBApiVideo {
                 titles: {
                     let mut titles: Vec<SBApiTitle> = db.db.titles.iter()
-                        .filter(|t| 
-                            Arc::ptr_eq(&t.video_id, &video_id) 
-                            && t.votes > -1 
-                            && t.votes.saturating_sub(t.downvotes) > -2 
-                            && !t.flags.intersects(TitleFlags::Removed | TitleFlags::ShadowHidden | TitleFlags::MissingVotes)
-                            && (
-                                query.0.fetchAll 
-                                || t.flags.contains(TitleFlags::Locked) 
-                                || t.votes.saturating_sub(t.downvotes) >= t.flags.contains(TitleFlags::Unverified).into()
-                            )
+                        .filter(|t|
+                            Arc::ptr_eq(&t.video_id, &video_id)
+                            && if query.0.fetchAll { t.returned_by_branding() } else { t.shown_by_extension() }
                         )
                         .map(|t| SBApiTitle::from_db(t, query.0.returnUserID))
                         .collect();
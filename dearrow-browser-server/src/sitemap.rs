@@ -0,0 +1,130 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+#![allow(clippy::needless_pass_by_value)]
+use std::{collections::HashSet, fmt::Write, sync::Arc};
+
+use actix_web::{get, http::{header::ContentType, StatusCode}, web, HttpRequest, HttpResponse};
+use chrono::{DateTime, SecondsFormat};
+use cloneable_errors::anyhow;
+use dearrow_parser::{DearrowDB, TitleFlags};
+
+use crate::constants::*;
+use crate::middleware::etag::ETagCache;
+use crate::state::{AppConfig, DBLock};
+use crate::utils;
+
+type XmlResult = utils::Result<HttpResponse>;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_sitemap)
+        .service(get_sitemap_page);
+}
+
+/// Lists videos with a custom title that would be shown to `DeArrow` users, most recent first.
+///
+/// Uses the visibility rules from [`dearrow_parser::visibility`], ignoring competition between titles.
+pub fn sitemap_videos(db: &DearrowDB, max_videos: usize) -> Arc<[Arc<str>]> {
+    let mut seen: HashSet<*const str> = HashSet::new();
+    db.titles.iter().rev()
+        .filter(|t|
+            !t.flags.contains(TitleFlags::Original)
+            && t.shown_by_extension()
+            && VIDEO_ID_REGEX.is_match(&t.video_id)
+        )
+        .filter(|t| seen.insert(Arc::as_ptr(&t.video_id)))
+        .take(max_videos)
+        .map(|t| t.video_id.clone())
+        .collect()
+}
+
+/// Absolute URL of the app root, as seen by the client
+fn app_url(req: &HttpRequest, config: &AppConfig) -> String {
+    let conn = req.connection_info();
+    xml_escape(&format!("{}://{}{}", conn.scheme(), conn.host(), config.base_path))
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn xml_response(body: String) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(ContentType::xml())
+        .body(body)
+}
+
+fn render_urlset(app_url: &str, videos: &[Arc<str>], lastmod: &str) -> String {
+    let mut xml = String::with_capacity(128 + videos.len() * (app_url.len() + 80));
+    xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push_str(r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#);
+    for video_id in videos {
+        write!(xml, "<url><loc>{app_url}video_id/{video_id}</loc><lastmod>{lastmod}</lastmod></url>")
+            .expect("writing to a String should not fail");
+    }
+    xml.push_str("</urlset>");
+    xml
+}
+
+/// Data shared by both sitemap endpoints
+struct SitemapData {
+    videos: Arc<[Arc<str>]>,
+    app_url: String,
+    lastmod: String,
+}
+
+fn sitemap_data(req: &HttpRequest, db_lock: &DBLock, config: &AppConfig) -> utils::Result<SitemapData> {
+    let db = db_lock.read().map_err(|_| DB_READ_ERR.clone())?;
+    let videos = db.sitemap.get_or_init(|| sitemap_videos(&db.db, config.sitemap_max_videos)).clone();
+    let lastmod = DateTime::from_timestamp_millis(db.last_modified)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Secs, true);
+    Ok(SitemapData { videos, app_url: app_url(req, config), lastmod })
+}
+
+/// Either the only sitemap or a sitemap index, if there are too many videos for one file
+#[get("/sitemap.xml", wrap = "ETagCache")]
+async fn get_sitemap(req: HttpRequest, db_lock: DBLock, config: web::Data<AppConfig>) -> XmlResult {
+    let SitemapData { videos, app_url, lastmod } = sitemap_data(&req, &db_lock, &config)?;
+    if videos.len() <= SITEMAP_PAGE_SIZE {
+        return Ok(xml_response(render_urlset(&app_url, &videos, &lastmod)));
+    }
+    let mut xml = String::new();
+    xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push_str(r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#);
+    for page in 1..=videos.len().div_ceil(SITEMAP_PAGE_SIZE) {
+        write!(xml, "<sitemap><loc>{app_url}sitemap-{page}.xml</loc><lastmod>{lastmod}</lastmod></sitemap>")
+            .expect("writing to a String should not fail");
+    }
+    xml.push_str("</sitemapindex>");
+    Ok(xml_response(xml))
+}
+
+/// A single page of the sitemap, numbered from 1
+#[get("/sitemap-{page}.xml", wrap = "ETagCache")]
+async fn get_sitemap_page(req: HttpRequest, db_lock: DBLock, config: web::Data<AppConfig>, page: web::Path<usize>) -> XmlResult {
+    let SitemapData { videos, app_url, lastmod } = sitemap_data(&req, &db_lock, &config)?;
+    let page = page.into_inner();
+    let Some(chunk) = page.checked_sub(1).and_then(|page| videos.chunks(SITEMAP_PAGE_SIZE).nth(page)) else {
+        return Err(utils::Error::from(anyhow!("Sitemap page not found")).set_status(StatusCode::NOT_FOUND));
+    };
+    Ok(xml_response(render_urlset(&app_url, chunk, &lastmod)))
+}
@@ -22,7 +22,8 @@ use actix_web::{
 };
 use chrono::{DateTime, Utc};
 use dearrow_browser_api::sync as api;
-use dearrow_parser::{DearrowDB, StringSet};
+use dearrow_parser::{DearrowDB, LoadCategory, LoadOptions, SegmentActionType, StringSet};
+use enumflags2::BitFlags;
 use cloneable_errors::{bail, ErrContext, ErrorContext, ResContext};
 use futures::{
     channel::oneshot,
@@ -31,16 +32,16 @@ use futures::{
     lock::Mutex,
     select_biased, FutureExt,
 };
-use log::warn;
+use log::{info, warn};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
     ffi::OsString,
     path::{Path, PathBuf},
     sync::{
         atomic::{AtomicUsize, Ordering::Relaxed},
-        Arc, RwLock,
+        Arc, OnceLock, RwLock,
     },
     time::Instant,
 };
@@ -56,8 +57,15 @@ pub type StringSetLock = web::Data<RwLock<StringSet>>;
 pub struct AppConfig {
     pub mirror_path: PathBuf,
     pub static_content_path: PathBuf,
+    /// Path prefix under which the whole app is served, must start and end with a slash
+    pub base_path: String,
     pub listen: ListenConfig,
     pub auth_secret: String,
+    /// Read the auth secret from this file instead, overrides `auth_secret`
+    pub auth_secret_file: Option<PathBuf>,
+    /// Read the auth secret from this environment variable instead, overrides `auth_secret` and
+    /// `auth_secret_file`
+    pub auth_secret_env: Option<String>,
     pub enable_sbserver_emulation: bool,
     pub reqwest_timeout_secs: f64,
     #[serde(skip)]
@@ -66,6 +74,81 @@ pub struct AppConfig {
     pub enable_timings_header: bool,
     pub cache_path: PathBuf,
     pub enable_fakeapi: bool,
+    /// Object categories to load from the mirror, everything else will be left empty
+    pub load_categories: Vec<LoadCategory>,
+    /// Check the hashedVideoID of every title and thumbnail while loading, reporting mismatches
+    pub verify_hashprefixes: bool,
+    /// Guess the language of every title while loading, exposed as the `language` field of titles
+    pub detect_title_languages: bool,
+    /// Don't intern title texts, saving memory at the cost of comparing identical titles by value
+    pub skip_title_interning: bool,
+    /// Truncate titles longer than this many characters while loading, reporting each as an error
+    pub max_title_length: Option<usize>,
+    /// Replace invalid UTF-8 in the mirror while loading instead of dropping the affected rows,
+    /// reporting each affected line as an error
+    pub lossy_utf8: bool,
+    /// Action types of `outro` segments which mark a video as having an outro, affecting the
+    /// random thumbnail timestamp. Only `skip` segments are ever cut out of the video.
+    pub outro_action_types: Vec<SegmentActionType>,
+    /// Mount the `/api/debug` endpoints, used for diagnosing the database internals
+    pub enable_debug_endpoints: bool,
+    /// "New issue" page of an issue tracker, linked from video and detail pages with prefilled
+    /// `title` and `body` query parameters
+    pub report_issue_url: Option<String>,
+    /// Announcement shown as a banner on every page, nothing is shown if empty
+    pub notice: String,
+    pub notice_severity: api::NoticeSeverity,
+    /// Public user IDs of contributors to badge as trusted in tables, unrelated to VIPs
+    pub trusted_users: Vec<String>,
+    /// Serve a `/sitemap.xml` listing video pages of videos with custom titles
+    pub enable_sitemap: bool,
+    /// Maximum amount of videos listed in the sitemap, the most recently titled ones are kept
+    pub sitemap_max_videos: usize,
+    /// Start without data and keep retrying if the initial database load fails, instead of exiting
+    pub retry_initial_load: bool,
+    /// Delay between attempts of loading the database when `retry_initial_load` is enabled
+    pub initial_load_retry_secs: f64,
+    /// Command to run before every reload, such as syncing the mirror. The first element is the
+    /// program, the rest are its arguments. Disabled if empty.
+    pub pre_reload_command: Vec<String>,
+    /// The pre-reload command is killed and the reload aborted after this many seconds
+    pub pre_reload_timeout_secs: f64,
+    /// Minimum initial capacity of the string set, the string count of the last load is used if
+    /// larger
+    pub string_set_capacity: usize,
+}
+
+impl AppConfig {
+    pub fn load_category_flags(&self) -> BitFlags<LoadCategory> {
+        self.load_categories.iter().copied().collect()
+    }
+
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            verify_hashprefixes: self.verify_hashprefixes,
+            detect_title_languages: self.detect_title_languages,
+            skip_title_interning: self.skip_title_interning,
+            max_title_length: self.max_title_length,
+            lossy_utf8: self.lossy_utf8,
+            outro_action_types: self.outro_action_types.iter().copied().collect(),
+        }
+    }
+
+    /// Replaces `auth_secret` with the secret from `auth_secret_env` or `auth_secret_file`, if set
+    pub fn resolve_auth_secret(&mut self) -> Result<(), ErrorContext> {
+        if let Some(ref var) = self.auth_secret_env {
+            self.auth_secret = std::env::var(var)
+                .with_context(|| format!("Failed to read the auth secret from the {var} environment variable"))?;
+        } else if let Some(ref path) = self.auth_secret_file {
+            let secret = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read the auth secret from {}", path.display()))?;
+            secret.trim_end_matches(['\r', '\n']).clone_into(&mut self.auth_secret);
+        }
+        if self.auth_secret.is_empty() {
+            bail!("The auth secret must not be empty");
+        }
+        Ok(())
+    }
 }
 
 impl Default for AppConfig {
@@ -73,8 +156,11 @@ impl Default for AppConfig {
         Self {
             mirror_path: PathBuf::from("./mirror"),
             static_content_path: PathBuf::from("./static"),
+            base_path: "/".to_owned(),
             listen: ListenConfig::default(),
             auth_secret: random_b64::<64>(),
+            auth_secret_file: None,
+            auth_secret_env: None,
             enable_sbserver_emulation: false,
             reqwest_timeout_secs: 20.,
             startup_timestamp: Utc::now(),
@@ -82,6 +168,25 @@ impl Default for AppConfig {
             enable_timings_header: false,
             cache_path: PathBuf::from("./cache"),
             enable_fakeapi: false,
+            load_categories: BitFlags::<LoadCategory>::all().iter().collect(),
+            verify_hashprefixes: false,
+            detect_title_languages: false,
+            skip_title_interning: false,
+            max_title_length: None,
+            lossy_utf8: false,
+            outro_action_types: vec![SegmentActionType::Skip],
+            enable_debug_endpoints: false,
+            report_issue_url: None,
+            notice: String::new(),
+            notice_severity: api::NoticeSeverity::Info,
+            trusted_users: Vec::new(),
+            enable_sitemap: false,
+            sitemap_max_videos: 200_000,
+            retry_initial_load: false,
+            initial_load_retry_secs: 60.,
+            pre_reload_command: Vec::new(),
+            pre_reload_timeout_secs: 600.,
+            string_set_capacity: 16384,
         }
     }
 }
@@ -92,6 +197,22 @@ pub struct InnertubeConfig {
     pub enable: bool,
     pub visitor_data: Option<String>,
     pub po_token: Option<String>,
+    /// Maximum amount of videos in a single channel tab; browsing is aborted once exceeded
+    pub max_channel_videos: usize,
+    /// Maximum size of a single response body received from youtube, in bytes
+    pub max_response_size: usize,
+    /// Maximum amount of video durations kept in memory, durations are also cached on disk
+    pub video_cache_size: usize,
+    /// Maximum amount of idle connections kept open per host
+    pub pool_max_idle_per_host: usize,
+    /// Time after which idle pooled connections are closed, in seconds
+    pub pool_idle_timeout_secs: f64,
+    /// Whether to skip protocol negotiation and always speak HTTP/2
+    pub http2_prior_knowledge: bool,
+    /// Interval of TCP keepalive probes on open connections, in seconds. Disabled if set to 0
+    pub tcp_keepalive_secs: f64,
+    /// Cached channel video lists older than this are ignored and fetched again in full, in seconds
+    pub channel_cache_max_age_secs: f64,
 }
 
 impl Default for InnertubeConfig {
@@ -100,6 +221,14 @@ impl Default for InnertubeConfig {
             enable: true,
             visitor_data: None,
             po_token: None,
+            max_channel_videos: 50_000,
+            max_response_size: 16 * 1024 * 1024,
+            video_cache_size: 4096,
+            pool_max_idle_per_host: 16,
+            pool_idle_timeout_secs: 90.,
+            http2_prior_knowledge: false,
+            tcp_keepalive_secs: 60.,
+            channel_cache_max_age_secs: 90. * 24. * 60. * 60.,
         }
     }
 }
@@ -122,15 +251,140 @@ impl Default for ListenConfig {
 }
 
 pub struct DatabaseState {
-    pub db: DearrowDB,
+    pub db: Arc<DearrowDB>,
     pub errors: Box<[ErrorContext]>,
     pub last_updated: i64,
     pub last_modified: i64,
     pub updating_now: bool,
+    /// `false` while the initial load failed and is being retried, `db` is empty until then
+    pub available: bool,
     pub etag: Option<EntityTag>,
     pub channel_cache: ChannelCache,
+    /// Filled in by a background thread after each (re)load, see [`compute_stats_in_background`]
+    pub stats: Arc<RwLock<Option<DatabaseStats>>>,
+    /// Video IDs listed in the sitemap, computed on first request after each (re)load
+    pub sitemap: OnceLock<Arc<[Arc<str>]>>,
+    /// Videos with the most submissions, computed on first request after each (re)load
+    pub top_videos: OnceLock<Arc<[api::TopVideo]>>,
+    /// Vote histograms, computed on first request after each (re)load
+    pub vote_stats: OnceLock<Arc<api::VoteStatsResponse>>,
+    /// Whether each video has any titles or thumbnails, built while (re)loading
+    pub video_presence: HashMap<Arc<str>, api::VideoDataPresence>,
+}
+
+/// Aggregates that are too slow to compute while holding up a (re)load
+#[derive(Clone)]
+pub struct DatabaseStats {
     pub video_info_count: usize,
     pub uncut_segment_count: usize,
+    /// Last [`DAILY_SUBMISSIONS_DAYS`] days, ending with the day of the most recent submission
+    pub daily_submissions: Arc<[api::DailySubmissions]>,
+}
+
+impl DatabaseStats {
+    pub fn compute(db: &DearrowDB) -> Self {
+        Self {
+            video_info_count: db.video_infos.iter().map(|chunk| chunk.len()).sum(),
+            uncut_segment_count: db
+                .video_infos
+                .iter()
+                .map(|chunk| chunk.iter().map(|v| v.uncut_segments.len()).sum::<usize>())
+                .sum(),
+            daily_submissions: daily_submissions(db),
+        }
+    }
+}
+
+fn daily_submissions(db: &DearrowDB) -> Arc<[api::DailySubmissions]> {
+    const DAY_MILLIS: i64 = 24 * 60 * 60 * 1000;
+    let last_day = db.titles.iter().map(|t| t.time_submitted)
+        .chain(db.thumbnails.iter().map(|t| t.time_submitted))
+        .max()
+        .map_or(0, |t| t.div_euclid(DAY_MILLIS));
+    let first_day = last_day - DAILY_SUBMISSIONS_DAYS + 1;
+    let mut days: Vec<api::DailySubmissions> = (first_day..=last_day)
+        .map(|day| api::DailySubmissions { day: day * DAY_MILLIS, titles: 0, thumbnails: 0 })
+        .collect();
+    let bucket = |time_submitted: i64| usize::try_from(time_submitted.div_euclid(DAY_MILLIS) - first_day).ok();
+    for title in &db.titles {
+        if let Some(day) = bucket(title.time_submitted).and_then(|i| days.get_mut(i)) {
+            day.titles += 1;
+        }
+    }
+    for thumbnail in &db.thumbnails {
+        if let Some(day) = bucket(thumbnail.time_submitted).and_then(|i| days.get_mut(i)) {
+            day.thumbnails += 1;
+        }
+    }
+    days.into()
+}
+
+/// Indexes which videos have any titles or thumbnails
+pub fn index_video_presence(db: &DearrowDB) -> HashMap<Arc<str>, api::VideoDataPresence> {
+    let mut presence: HashMap<Arc<str>, api::VideoDataPresence> = HashMap::new();
+    for title in &db.titles {
+        presence.entry(title.video_id.clone()).or_default().has_title = true;
+    }
+    for thumbnail in &db.thumbnails {
+        presence.entry(thumbnail.video_id.clone()).or_default().has_thumbnail = true;
+    }
+    presence
+}
+
+/// Computes the [`DatabaseStats`] of the currently loaded database on a separate thread.
+///
+/// Every load creates a new `stats` slot, so a computation finishing after the database was
+/// replaced again will not affect the stats of the newer database.
+pub fn compute_stats_in_background(db_lock: DBLock) {
+    let result = std::thread::Builder::new()
+        .name("stats".to_owned())
+        .spawn(move || {
+            // only hold the lock while grabbing the data, so that reloads aren't blocked until the
+            // stats are computed
+            let (slot, db) = {
+                let Ok(db_state) = db_lock.read() else {
+                    warn!("Failed to acquire the database lock for computing stats");
+                    return;
+                };
+                (db_state.stats.clone(), db_state.db.clone())
+            };
+            let stats = DatabaseStats::compute(&db);
+            let Ok(mut slot) = slot.write() else {
+                warn!("Failed to acquire the stats lock for storing computed stats");
+                return;
+            };
+            *slot = Some(stats);
+        });
+    if let Err(err) = result {
+        warn!("Failed to spawn the stats thread: {err}");
+    }
+}
+
+/// Capacity to preallocate for the string set, based on the string count of the last load
+pub fn string_set_capacity_hint(config: &AppConfig) -> usize {
+    let path = config.cache_path.join(FSCACHE_STRING_COUNT);
+    let last_count = match std::fs::read_to_string(&path) {
+        Ok(count) => count.trim().parse().unwrap_or_else(|err| {
+            warn!("The string count cache entry contained an invalid number: {err}");
+            0
+        }),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => 0,
+        Err(err) => {
+            warn!("Got an unexpected error while trying to read the string count cache entry: {err}");
+            0
+        },
+    };
+    last_count.max(config.string_set_capacity)
+}
+
+/// Logs the string count and rehashes of a load, saving the count for the next startup
+pub fn record_string_count(config: &AppConfig, string_set: &StringSet) {
+    let count = string_set.set.len();
+    info!("Interned {count} strings, the string set was rehashed {} times while loading", string_set.rehashes);
+    let path = config.cache_path.join(FSCACHE_STRING_COUNT);
+    if let Err(err) = std::fs::write(&path, count.to_string()) {
+        warn!("Got an unexpected error while trying to write the string count cache entry: {err}");
+    }
 }
 
 impl DatabaseState {
@@ -141,34 +395,118 @@ impl DatabaseState {
         }
     }
 
-    pub fn calculate_video_info_count(&self) -> usize {
-        self.db.video_infos.iter().map(|chunk| chunk.len()).sum()
-    }
-
-    pub fn calculate_uncut_segment_count(&self) -> usize {
-        self.db
-            .video_infos
-            .iter()
-            .map(|chunk| chunk.iter().map(|v| v.uncut_segments.len()).sum::<usize>())
-            .sum()
-    }
-
     pub fn generate_etag(&self) -> EntityTag {
         EntityTag::new_weak(format!(
-            "{}:{}:{}+{}+{}+{}+{}+{}+{}",
+            "{}:{}:{}+{}+{}+{}+{}",
             self.last_updated,
             self.last_modified,
             self.db.titles.len(),
             self.db.thumbnails.len(),
             self.db.usernames.len(),
             self.db.vip_users.len(),
-            self.video_info_count,
-            self.uncut_segment_count,
             self.db.warnings.len(),
         ))
     }
 }
 
+#[cfg(test)]
+impl DatabaseState {
+    /// Loads the parser's golden fixture database, for tests of handlers and middleware
+    pub fn golden_fixture() -> (DBLock, StringSetLock) {
+        let string_set = web::Data::new(RwLock::new(StringSet::with_capacity(64)));
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../dearrow-parser/tests/fixtures/golden");
+        let (mut db, errors) = DearrowDB::load_dir(
+            &dir,
+            BitFlags::all(),
+            LoadOptions::default(),
+            &mut string_set.write().unwrap(),
+        ).expect("fixture database should load");
+        db.sort();
+        let config = Arc::new(AppConfig::default());
+        let mut state = DatabaseState {
+            video_presence: index_video_presence(&db),
+            db: Arc::new(db),
+            errors: errors.into(),
+            last_updated: 0,
+            last_modified: 0,
+            updating_now: false,
+            available: true,
+            etag: None,
+            channel_cache: ChannelCache::new(string_set.clone().into_inner(), config, Client::new()),
+            stats: Arc::default(),
+            sitemap: OnceLock::new(),
+            top_videos: OnceLock::new(),
+            vote_stats: OnceLock::new(),
+        };
+        state.etag = Some(state.generate_etag());
+        (web::Data::new(RwLock::new(state)), string_set)
+    }
+}
+
+type DurationFutureResult = Result<u64, ErrorContext>;
+type SharedDurationFuture = Shared<BoxFuture<'static, DurationFutureResult>>;
+
+/// In-memory cache of video durations fetched from innertube, backed by the fscache.
+///
+/// Concurrent requests for the same video share a single innertube request.
+/// Failed fetches are not cached.
+#[derive(Clone)]
+pub struct VideoDurationCache {
+    /// NOTE: Keys of this hashmap are NOT stored in the `StringSet`!
+    entries: Arc<Mutex<VideoDurationCacheEntries>>,
+    config: Arc<AppConfig>,
+    client: reqwest::Client,
+}
+
+#[derive(Default)]
+struct VideoDurationCacheEntries {
+    futures: HashMap<Arc<str>, SharedDurationFuture>,
+    /// Keys of `futures`, oldest first
+    insertion_order: VecDeque<Arc<str>>,
+}
+
+impl VideoDurationCache {
+    pub fn new(config: Arc<AppConfig>, client: Client) -> VideoDurationCache {
+        VideoDurationCache {
+            entries: Arc::default(),
+            config,
+            client,
+        }
+    }
+
+    pub async fn get_duration(&self, video_id: &str) -> DurationFutureResult {
+        let future = {
+            let mut entries = self.entries.lock().await;
+            if let Some(future) = entries.futures.get(video_id) {
+                future.clone()
+            } else {
+                let video_id: Arc<str> = video_id.into();
+                let future = innertube::get_video_duration(self.client.clone(), self.config.clone(), video_id.clone())
+                    .boxed()
+                    .shared();
+                entries.futures.insert(video_id.clone(), future.clone());
+                entries.insertion_order.push_back(video_id);
+                while entries.insertion_order.len() > self.config.innertube.video_cache_size {
+                    if let Some(evicted) = entries.insertion_order.pop_front() {
+                        entries.futures.remove(&evicted);
+                    }
+                }
+                future
+            }
+        };
+        let result = future.clone().await;
+        if result.is_err() {
+            // allow retrying failed fetches
+            let mut entries = self.entries.lock().await;
+            if entries.futures.get(video_id).is_some_and(|f| f.ptr_eq(&future)) {
+                entries.futures.remove(video_id);
+                entries.insertion_order.retain(|v| **v != *video_id);
+            }
+        }
+        result
+    }
+}
+
 type UCIDFutureResult = Result<Arc<str>, ErrorContext>;
 type SharedUCIDFuture = Shared<BoxFuture<'static, UCIDFutureResult>>;
 
@@ -297,6 +635,8 @@ pub struct ChannelData {
     pub num_shorts: usize,
     pub num_releases: usize,
     pub total_videos: usize,
+    /// Computed on the first summary request, dropped along with the rest of the channel cache
+    pub summary: OnceLock<Arc<api::ChannelSummary>>,
 }
 
 #[derive(Clone, Debug)]
@@ -305,6 +645,24 @@ pub enum GetChannelOutput {
     Resolved(Arc<ChannelData>),
 }
 
+#[cfg(test)]
+impl ChannelCache {
+    /// Stores channel data as if it was just fetched, replacing any previous data
+    pub async fn insert_resolved(&self, ucid: &str, video_ids: Box<[Arc<str>]>) {
+        let data = ChannelData {
+            channel_name: "Test channel".into(),
+            num_videos: video_ids.len(),
+            num_vods: 0,
+            num_shorts: 0,
+            num_releases: 0,
+            total_videos: video_ids.len(),
+            video_ids,
+            summary: OnceLock::new(),
+        };
+        self.data_cache.lock().await.insert(ucid.into(), ChannelDataCacheEntry::Resolved(Arc::new(data)));
+    }
+}
+
 impl ChannelCache {
     pub fn new(
         string_set: Arc<RwLock<StringSet>>,
@@ -350,8 +708,8 @@ impl ChannelCache {
         fut.await
     }
 
-    async fn handle_to_ucid(client: Client, handle: Arc<str>) -> UCIDFutureResult {
-        innertube::handle_to_ucid(&client, &handle)
+    async fn handle_to_ucid(client: Client, config: Arc<AppConfig>, handle: Arc<str>) -> UCIDFutureResult {
+        innertube::handle_to_ucid(&client, &config, &handle)
             .await
             .map(Into::into)
     }
@@ -432,12 +790,13 @@ impl ChannelCache {
             video_ids: videos
                 .video_ids
                 .into_iter()
-                .chain(vods.video_ids.into_iter())
-                .chain(shorts.video_ids.into_iter())
+                .chain(vods.video_ids)
+                .chain(shorts.video_ids)
                 .chain(releases_tab.into_iter().flat_map(Vec::into_iter))
                 .chain(releases_home.into_iter().flat_map(Vec::into_iter))
                 .filter_map(|vid| string_set.set.get(vid.as_str()).cloned())
                 .collect(),
+            summary: OnceLock::new(),
         }))
     }
 
@@ -476,7 +835,7 @@ impl ChannelCache {
                 ucid_cache
                     .entry(handle)
                     .or_insert_with_key(|handle| {
-                        Self::handle_to_ucid(self.client.clone(), handle.clone())
+                        Self::handle_to_ucid(self.client.clone(), self.config.clone(), handle.clone())
                             .boxed()
                             .shared()
                     })
@@ -17,10 +17,10 @@
 */
 use std::{ffi::CString, fmt::{Debug, Display}, fs, mem::MaybeUninit, ops::{Deref, DerefMut}, os::{fd::AsRawFd, unix::ffi::OsStrExt}, path::{Path, PathBuf}, sync::Arc, time::{SystemTime, UNIX_EPOCH}};
 
-use actix_web::{dev::Extensions, http::{header::{HeaderMap, TryIntoHeaderPair}, StatusCode}, HttpResponse, Responder, ResponseError};
+use actix_web::{body::BoxBody, dev::Extensions, http::{header::{self, Accept, Header, HeaderMap, HeaderValue, TryIntoHeaderPair}, StatusCode}, web, HttpMessage, HttpRequest, HttpResponse, Responder, ResponseError};
 use base64::prelude::{BASE64_URL_SAFE_NO_PAD, Engine};
-use cloneable_errors::{ErrContext, ErrorContext, IntoErrorIterator, ResContext};
-use serde::de::DeserializeOwned;
+use cloneable_errors::{anyhow, ErrContext, ErrorContext, IntoErrorIterator, ResContext};
+use serde::{de::{DeserializeOwned, Visitor}, Deserialize, Deserializer, Serialize};
 use tokio::fs::File;
 
 /// This extension will be present on a response if the response contains
@@ -140,6 +140,157 @@ pub trait ResponderExt: Responder + Sized {
 
 impl<T> ResponderExt for T where T: Responder + Sized {}
 
+/// Responds with a list of objects serialized as JSON, or as CSV if the client prefers `text/csv`
+/// according to its `Accept` header.
+///
+/// CSV responses start with a header row, columns follow the field order of `T`.
+///
+/// The `fields` query parameter can be used to only include the listed fields (comma separated)
+/// of each object. CSV columns then follow the order of that list. Unknown field names are
+/// rejected with a `400 Bad Request`.
+pub struct JsonOrCsv<T: Serialize>(pub Vec<T>);
+
+#[derive(Deserialize)]
+struct FieldsParam {
+    fields: Option<String>,
+}
+
+/// Checks whether the client prefers `text/csv` over JSON according to its `Accept` header
+pub fn prefers_csv(req: &impl HttpMessage) -> bool {
+    let Ok(accept) = Accept::parse(req) else {
+        return false;
+    };
+    for mime in accept.ranked() {
+        match mime.essence_str() {
+            "text/csv" => return true,
+            "application/json" | "application/*" | "*/*" => return false,
+            _ => {},
+        }
+    }
+    false
+}
+
+impl<T: Serialize + DeserializeOwned> JsonOrCsv<T> {
+    fn selected_fields(req: &HttpRequest) -> Result<Option<Vec<&'static str>>> {
+        let query = web::Query::<FieldsParam>::from_query(req.query_string())
+            .map_err(|e| Error::from(e.context("Failed to parse the query string")).set_status(StatusCode::BAD_REQUEST))?;
+        let Some(ref fields) = query.fields else {
+            return Ok(None);
+        };
+        let known_fields = struct_fields::<T>();
+        let mut selected = Vec::new();
+        for name in fields.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            let Some(field) = known_fields.iter().find(|field| **field == name) else {
+                return Err(Error::from(anyhow!("Unknown field: {name}")).set_status(StatusCode::BAD_REQUEST));
+            };
+            if !selected.contains(field) {
+                selected.push(*field);
+            }
+        }
+        Ok(Some(selected))
+    }
+
+    fn to_csv(&self) -> std::result::Result<Vec<u8>, ErrorContext> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        // the header is only written together with the first row, empty lists need it written explicitly
+        if self.0.is_empty() {
+            writer.write_record(struct_fields::<T>()).context("Failed to write the CSV header")?;
+        }
+        for row in &self.0 {
+            writer.serialize(row).context("Failed to serialize a row as CSV")?;
+        }
+        writer.into_inner().context("Failed to flush the CSV writer")
+    }
+
+    /// Extracts the values of the given fields from each object, in the order of `fields`
+    fn project(&self, fields: &[&str]) -> std::result::Result<Vec<Vec<serde_json::Value>>, ErrorContext> {
+        self.0.iter()
+            .map(|row| {
+                let serde_json::Value::Object(mut object) = serde_json::to_value(row).context("Failed to serialize a row")? else {
+                    return Err(anyhow!("Row did not serialize into an object"));
+                };
+                Ok(fields.iter().map(|field| object.remove(*field).unwrap_or_default()).collect())
+            })
+            .collect()
+    }
+
+    fn projected_csv(fields: &[&str], rows: &[Vec<serde_json::Value>]) -> std::result::Result<Vec<u8>, ErrorContext> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        writer.write_record(fields).context("Failed to write the CSV header")?;
+        for row in rows {
+            writer.write_record(row.iter().map(|value| match value {
+                serde_json::Value::Null => String::new(),
+                serde_json::Value::String(s) => s.clone(),
+                value => value.to_string(),
+            })).context("Failed to serialize a row as CSV")?;
+        }
+        writer.into_inner().context("Failed to flush the CSV writer")
+    }
+
+    fn csv_response(body: Vec<u8>) -> HttpResponse {
+        HttpResponse::Ok()
+            .insert_header((header::CONTENT_TYPE, "text/csv; charset=utf-8"))
+            .body(body)
+    }
+
+    fn render(self, req: &HttpRequest) -> Result<HttpResponse> {
+        let csv = prefers_csv(req);
+        let Some(fields) = Self::selected_fields(req)? else {
+            return Ok(if csv {
+                Self::csv_response(self.to_csv()?)
+            } else {
+                web::Json(self.0).respond_to(req).map_into_boxed_body()
+            });
+        };
+        let rows = self.project(&fields)?;
+        if csv {
+            return Ok(Self::csv_response(Self::projected_csv(&fields, &rows)?));
+        }
+        let objects: Vec<serde_json::Map<String, serde_json::Value>> = rows.into_iter()
+            .map(|row| fields.iter().map(|field| (*field).to_owned()).zip(row).collect())
+            .collect();
+        Ok(HttpResponse::Ok().json(objects))
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> Responder for JsonOrCsv<T> {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<BoxBody> {
+        let mut resp = self.render(req).unwrap_or_else(|err| err.error_response());
+        // the representation depends on the Accept header, see also the ETagCache middleware
+        resp.headers_mut().append(header::VARY, HeaderValue::from_static("accept"));
+        resp
+    }
+}
+
+/// Returns the names of the fields of a struct, as seen by its [`Deserialize`] impl
+fn struct_fields<T: DeserializeOwned>() -> &'static [&'static str] {
+    struct FieldCollector(&'static [&'static str]);
+
+    impl<'de> Deserializer<'de> for &mut FieldCollector {
+        type Error = serde::de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+            Err(serde::de::Error::custom("expected a struct"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str], _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+            self.0 = fields;
+            // bail out, we only wanted the field names
+            Err(serde::de::Error::custom("collected field names"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+            option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    let mut collector = FieldCollector(&[]);
+    let _ = T::deserialize(&mut collector);
+    collector.0
+}
 
 pub fn link_file<T: AsRawFd>(file: &T, new_path: &Path) -> std::io::Result<()> {
     let path_cstr = CString::new(new_path.as_os_str().as_bytes()).expect("Failed to convert new_path to a CString");
@@ -188,7 +339,7 @@ impl TemporaryFile {
                 is_committed: false,
                 target_path,
             }),
-        };
+        }
 
         // unnamed file didn't work, fall back
         let tmp_file_path = fallback_tmpdir.join(random_b64::<64>());
@@ -220,7 +371,7 @@ impl TemporaryFile {
                     Err(err) if err.kind() == std::io::ErrorKind::NotFound => (), // fine
                     Err(err) => return Err(err.context(format!("Failed to remove existing file at {}", self.target_path.display()))),
                     Ok(()) => (),
-                };
+                }
                 link_file(&self.file, &self.target_path).with_context(|| format!("Failed to link in the new file at {}", self.target_path.display()))?;
             },
             TempFileType::FileInTmpDir { current_path } => {
@@ -266,13 +417,32 @@ pub fn unix_timestamp() -> i128 {
 }
 
 pub trait ReqwestResponseExt {
-    async fn json_debug<T: DeserializeOwned>(self, name: &'static str) -> std::result::Result<T, ErrorContext>;
+    /// Receives the whole response body, failing once it grows past `max_size` bytes
+    async fn bytes_limited(self, max_size: usize) -> std::result::Result<Vec<u8>, ErrorContext>;
+    async fn json_debug<T: DeserializeOwned>(self, name: &'static str, max_size: usize) -> std::result::Result<T, ErrorContext>;
 }
 
 impl ReqwestResponseExt for reqwest::Response {
-    async fn json_debug<T: DeserializeOwned>(self, name: &'static str) -> std::result::Result<T, ErrorContext> {
+    async fn bytes_limited(mut self, max_size: usize) -> std::result::Result<Vec<u8>, ErrorContext> {
+        // don't bother receiving anything if the server already told us it's too big
+        if let Some(length) = self.content_length() {
+            if length > max_size as u64 {
+                return Err(anyhow!("Response body is {length} bytes long, which exceeds the limit of {max_size} bytes"));
+            }
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) = self.chunk().await.context("Failed to receive response")? {
+            if body.len() + chunk.len() > max_size {
+                return Err(anyhow!("Response body exceeded the limit of {max_size} bytes"));
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body)
+    }
+
+    async fn json_debug<T: DeserializeOwned>(self, name: &'static str, max_size: usize) -> std::result::Result<T, ErrorContext> {
         let status = self.status();
-        let body = self.bytes().await.context("Failed to receive response")?;
+        let body = self.bytes_limited(max_size).await?;
         let decoded = serde_json::from_slice(&body);
 
         if (decoded.is_err() && std::env::var_os("DAB_IT_DUMP_ERRORS").is_some()) || std::env::var_os("DAB_IT_DUMP_ALL").is_some() {
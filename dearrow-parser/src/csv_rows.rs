@@ -0,0 +1,92 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Reading rows of the mirror CSV files, optionally tolerating invalid UTF-8.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use cloneable_errors::{anyhow, ErrorContext};
+use csv::{ByteRecord, DeserializeRecordsIntoIter, StringRecord};
+use serde::de::DeserializeOwned;
+
+/// Iterator over the deserialized rows of a CSV file.
+///
+/// In lossy mode, invalid UTF-8 in a row is replaced with U+FFFD instead of failing the whole
+/// row. Lines where this happened are collected and reported by [`CsvRows::lossy_warnings`]
+/// once the file has been read.
+pub(crate) enum CsvRows<T> {
+    Strict(DeserializeRecordsIntoIter<File, T>),
+    Lossy {
+        reader: csv::Reader<File>,
+        headers: StringRecord,
+        path: PathBuf,
+        replaced_lines: Vec<u64>,
+    },
+}
+
+impl<T: DeserializeOwned> CsvRows<T> {
+    pub fn open(path: &Path, lossy_utf8: bool) -> csv::Result<Self> {
+        let mut reader = csv::Reader::from_path(path)?;
+        if !lossy_utf8 {
+            return Ok(CsvRows::Strict(reader.into_deserialize()));
+        }
+        let headers = StringRecord::from_byte_record_lossy(reader.byte_headers()?.clone());
+        Ok(CsvRows::Lossy {
+            reader,
+            headers,
+            path: path.to_owned(),
+            replaced_lines: Vec::new(),
+        })
+    }
+
+    /// Warnings for each line in which invalid UTF-8 was replaced so far
+    pub fn lossy_warnings(&mut self) -> Vec<ErrorContext> {
+        let CsvRows::Lossy { path, replaced_lines, .. } = self else {
+            return Vec::new();
+        };
+        replaced_lines
+            .drain(..)
+            .map(|line| anyhow!("Replaced invalid UTF-8 on line {} of {}", line, path.display()))
+            .collect()
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for CsvRows<T> {
+    type Item = csv::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            CsvRows::Strict(rows) => rows.next(),
+            CsvRows::Lossy { reader, headers, replaced_lines, .. } => {
+                let mut record = ByteRecord::new();
+                match reader.read_byte_record(&mut record) {
+                    Ok(true) => {},
+                    Ok(false) => return None,
+                    Err(err) => return Some(Err(err)),
+                }
+                let record = StringRecord::from_byte_record(record).unwrap_or_else(|err| {
+                    let record = err.into_byte_record();
+                    replaced_lines.push(record.position().map_or(0, csv::Position::line));
+                    StringRecord::from_byte_record_lossy(record)
+                });
+                Some(record.deserialize(Some(headers)))
+            },
+        }
+    }
+}
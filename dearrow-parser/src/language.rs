@@ -0,0 +1,218 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+
+//! Lightweight language detection for titles.
+//!
+//! Non-Latin scripts are mostly identified by the script alone. Titles in Latin script are
+//! matched against short lists of common words and language-specific letters of a handful of
+//! languages. Titles that are too short or ambiguous are reported as [`Language::Unknown`].
+//!
+//! This is hand-written instead of using a general purpose detector crate, as it runs for every
+//! title while loading the database and only has to tell apart the languages listed in
+//! [`Language`]. Trigram-based detectors are noticeably slower, add their models to the binary and
+//! are unreliable on inputs as short as titles anyway.
+
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+/// Titles with fewer letters than this are never detected
+const MIN_LETTERS: usize = 4;
+/// Latin script titles need at least this many words, unless they contain language-specific letters
+const MIN_LATIN_WORDS: usize = 3;
+
+/// Detected language of a title, serialized as its ISO 639-1 code
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize, IntoStaticStr)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Language {
+    Unknown,
+    En,
+    Es,
+    Pt,
+    Fr,
+    De,
+    It,
+    Nl,
+    Pl,
+    Tr,
+    Id,
+    Ru,
+    Uk,
+    El,
+    He,
+    Ar,
+    Hi,
+    Th,
+    Ja,
+    Ko,
+    Zh,
+}
+
+impl Language {
+    /// ISO 639-1 code of this language, or `unknown`
+    pub fn code(self) -> &'static str {
+        self.into()
+    }
+}
+
+/// Common words of each detected Latin script language
+const LATIN_WORDS: &[(Language, &[&str])] = &[
+    (Language::En, &["the", "and", "of", "to", "is", "you", "this", "how", "my", "with", "for", "what", "why", "are", "it", "i", "your", "on", "was", "be"]),
+    (Language::Es, &["el", "la", "los", "las", "de", "que", "y", "en", "por", "con", "una", "un", "es", "del", "para", "mi", "como", "qué", "más"]),
+    (Language::Pt, &["o", "a", "os", "as", "de", "que", "e", "em", "do", "da", "não", "com", "um", "uma", "para", "é", "meu", "como", "mais"]),
+    (Language::Fr, &["le", "la", "les", "de", "des", "et", "un", "une", "est", "du", "pour", "dans", "ce", "qui", "je", "avec", "pas", "sur"]),
+    (Language::De, &["der", "die", "das", "und", "ist", "ich", "nicht", "mit", "ein", "eine", "zu", "den", "auf", "für", "wie", "von", "im"]),
+    (Language::It, &["il", "lo", "la", "di", "che", "e", "è", "un", "una", "per", "con", "non", "del", "della", "sono", "come", "gli"]),
+    (Language::Nl, &["de", "het", "een", "en", "van", "is", "niet", "met", "ik", "op", "voor", "dat", "wat", "zijn"]),
+    (Language::Pl, &["i", "w", "na", "nie", "się", "z", "jest", "do", "to", "jak", "że", "co", "o"]),
+    (Language::Tr, &["ve", "bir", "bu", "için", "ile", "ne", "çok", "da", "de", "mi", "ben"]),
+    (Language::Id, &["dan", "yang", "di", "ini", "itu", "dengan", "untuk", "tidak", "ke", "dari", "aku", "saya", "apa"]),
+];
+
+/// Letters which are (nearly) exclusive to one of the detected Latin script languages
+const LATIN_LETTERS: &[(Language, &[char])] = &[
+    (Language::Es, &['ñ', '¿', '¡']),
+    (Language::Pt, &['ã', 'õ']),
+    (Language::De, &['ß']),
+    (Language::Pl, &['ą', 'ę', 'ł', 'ś', 'ź', 'ż', 'ń']),
+    (Language::Tr, &['ğ', 'ş', 'ı']),
+];
+
+#[derive(Default)]
+struct ScriptCounts {
+    latin: usize,
+    cyrillic: usize,
+    greek: usize,
+    hebrew: usize,
+    arabic: usize,
+    devanagari: usize,
+    thai: usize,
+    hangul: usize,
+    kana: usize,
+    han: usize,
+}
+
+impl ScriptCounts {
+    fn count(text: &str) -> Self {
+        let mut counts = Self::default();
+        for c in text.chars().filter(|c| c.is_alphabetic()) {
+            match c {
+                '\u{0370}'..='\u{03FF}' => counts.greek += 1,
+                '\u{0400}'..='\u{04FF}' => counts.cyrillic += 1,
+                '\u{0590}'..='\u{05FF}' => counts.hebrew += 1,
+                '\u{0600}'..='\u{06FF}' => counts.arabic += 1,
+                '\u{0900}'..='\u{097F}' => counts.devanagari += 1,
+                '\u{0E00}'..='\u{0E7F}' => counts.thai += 1,
+                '\u{1100}'..='\u{11FF}' | '\u{AC00}'..='\u{D7AF}' => counts.hangul += 1,
+                '\u{3040}'..='\u{30FF}' => counts.kana += 1,
+                '\u{4E00}'..='\u{9FFF}' => counts.han += 1,
+                _ if c.is_ascii_alphabetic() || ('\u{00C0}'..='\u{024F}').contains(&c) => counts.latin += 1,
+                _ => {},
+            }
+        }
+        counts
+    }
+}
+
+/// Guesses the language a title is written in
+pub fn detect_language(title: &str) -> Language {
+    let counts = ScriptCounts::count(title);
+    let total = counts.latin + counts.cyrillic + counts.greek + counts.hebrew + counts.arabic
+        + counts.devanagari + counts.thai + counts.hangul + counts.kana + counts.han;
+    if total < MIN_LETTERS {
+        return Language::Unknown;
+    }
+    // the script has to make up the majority of the letters
+    let dominant = |count: usize| count * 2 > total;
+    if counts.kana > 0 && dominant(counts.kana + counts.han) {
+        Language::Ja
+    } else if dominant(counts.han) {
+        Language::Zh
+    } else if dominant(counts.hangul) {
+        Language::Ko
+    } else if dominant(counts.greek) {
+        Language::El
+    } else if dominant(counts.hebrew) {
+        Language::He
+    } else if dominant(counts.arabic) {
+        Language::Ar
+    } else if dominant(counts.devanagari) {
+        Language::Hi
+    } else if dominant(counts.thai) {
+        Language::Th
+    } else if dominant(counts.cyrillic) {
+        detect_cyrillic(title)
+    } else if dominant(counts.latin) {
+        detect_latin(title)
+    } else {
+        Language::Unknown
+    }
+}
+
+/// Letters of other Cyrillic script languages (Serbian, Macedonian, Kazakh, ...), which aren't
+/// used in Russian or Ukrainian
+const OTHER_CYRILLIC_LETTERS: &[char] = &[
+    'ђ', 'ј', 'љ', 'њ', 'ћ', 'џ', 'ѓ', 'ќ', 'ѕ', 'ә', 'ғ', 'қ', 'ң', 'ө', 'ұ', 'ү', 'һ', 'ў',
+];
+
+/// Russian is assumed for Cyrillic titles without letters specific to other languages, as it is
+/// by far the most common Cyrillic script language.
+fn detect_cyrillic(title: &str) -> Language {
+    let lowercase = title.to_lowercase();
+    if lowercase.contains(OTHER_CYRILLIC_LETTERS) {
+        Language::Unknown
+    } else if lowercase.contains(['і', 'ї', 'є', 'ґ']) {
+        Language::Uk
+    } else {
+        Language::Ru
+    }
+}
+
+fn detect_latin(title: &str) -> Language {
+    // lowercase the Turkish dotted capital I without a combining dot, so that it still matches
+    let lowercase = title.replace('İ', "i").to_lowercase();
+    let words: Vec<&str> = lowercase
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| !w.is_empty())
+        .collect();
+    let mut scores: Vec<(Language, usize)> = LATIN_WORDS.iter()
+        .map(|(language, common_words)| {
+            (*language, words.iter().filter(|w| common_words.contains(w)).count())
+        })
+        .collect();
+    let mut has_letter_hints = false;
+    for (language, letters) in LATIN_LETTERS {
+        let hits = lowercase.chars().filter(|c| letters.contains(c)).count();
+        if hits > 0 {
+            has_letter_hints = true;
+            if let Some(score) = scores.iter_mut().find(|(l, _)| l == language) {
+                score.1 += 2 * hits;
+            }
+        }
+    }
+    if words.len() < MIN_LATIN_WORDS && !has_letter_hints {
+        return Language::Unknown;
+    }
+    scores.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+    match scores[..] {
+        [(_, 0), ..] => Language::Unknown,
+        [(best, best_score), (_, second_score), ..] if best_score > second_score => best,
+        _ => Language::Unknown,
+    }
+}
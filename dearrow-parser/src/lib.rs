@@ -16,9 +16,11 @@
 *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 use csv_data::WithWarnings;
+use csv_rows::CsvRows;
+use alea_js::Alea;
 use enumflags2::{bitflags, BitFlags};
-use cloneable_errors::{ErrContext, ErrorContext, ResContext};
-use log::info;
+use cloneable_errors::{bail, ErrContext, ErrorContext, ResContext};
+use log::{info, warn};
 use sha2::{Digest, Sha256};
 use std::{
     collections::{HashMap, HashSet},
@@ -28,6 +30,14 @@ use std::{
     sync::Arc,
 };
 
+pub use language::Language;
+
+mod csv_rows;
+pub mod language;
+pub mod originals;
+pub mod title_casing;
+pub mod visibility;
+
 type Result<T> = std::result::Result<T, ErrorContext>;
 
 #[bitflags]
@@ -78,6 +88,11 @@ pub struct Title {
     pub downvotes: i8,
     pub flags: BitFlags<TitleFlags>,
     pub hash_prefix: u16,
+    /// `None` unless [`LoadOptions::detect_title_languages`] was set
+    pub language: Option<Language>,
+    /// Whether the title matches a known original title of the video, `None` for original
+    /// titles and videos with no known original. See [`originals::mark_identical_to_original`]
+    pub identical_to_original: Option<bool>,
 }
 
 #[derive(Clone, Debug)]
@@ -122,15 +137,27 @@ pub struct Warning {
 #[derive(Default, Clone)]
 pub struct StringSet {
     pub set: HashSet<Arc<str>>,
+    /// Amount of times the set had to grow while deduplicating strings, each growth rehashes
+    /// every string in the set. Reset with [`StringSet::reserve_total`].
+    pub rehashes: usize,
 }
 
 impl StringSet {
     pub fn with_capacity(capacity: usize) -> StringSet {
         StringSet {
             set: HashSet::with_capacity(capacity),
+            rehashes: 0,
         }
     }
 
+    /// Makes room for at least `capacity` strings in total and resets the rehash counter
+    ///
+    /// Pass the string count of the previous load to avoid rehashing while loading again.
+    pub fn reserve_total(&mut self, capacity: usize) {
+        self.set.reserve(capacity.saturating_sub(self.set.len()));
+        self.rehashes = 0;
+    }
+
     pub fn dedupe_struct<T: Dedupe>(&mut self, obj: &mut T) {
         obj.dedupe(self);
     }
@@ -139,6 +166,9 @@ impl StringSet {
         if let Some(s) = self.set.get(reference) {
             *reference = s.clone();
         } else {
+            if self.set.len() == self.set.capacity() {
+                self.rehashes += 1;
+            }
             self.set.insert(reference.clone());
         }
     }
@@ -146,6 +176,11 @@ impl StringSet {
     pub fn clean(&mut self) {
         self.set.retain(|s| Arc::strong_count(s) > 1);
     }
+
+    /// Checks whether `reference` points to the instance of the string stored in this set
+    pub fn is_interned(&self, reference: &Arc<str>) -> bool {
+        self.set.get(reference).is_some_and(|s| Arc::ptr_eq(s, reference))
+    }
 }
 
 pub trait Dedupe {
@@ -159,14 +194,20 @@ impl Dedupe for Thumbnail {
         set.dedupe_arc(&mut self.user_id);
     }
 }
-impl Dedupe for Title {
-    fn dedupe(&mut self, set: &mut StringSet) {
+impl Title {
+    /// Interns every field except for the title text
+    pub fn dedupe_ids(&mut self, set: &mut StringSet) {
         set.dedupe_arc(&mut self.uuid);
-        set.dedupe_arc(&mut self.title);
         set.dedupe_arc(&mut self.video_id);
         set.dedupe_arc(&mut self.user_id);
     }
 }
+impl Dedupe for Title {
+    fn dedupe(&mut self, set: &mut StringSet) {
+        self.dedupe_ids(set);
+        set.dedupe_arc(&mut self.title);
+    }
+}
 impl Dedupe for Username {
     fn dedupe(&mut self, set: &mut StringSet) {
         set.dedupe_arc(&mut self.user_id);
@@ -198,6 +239,20 @@ pub enum ParseErrorKind {
         struct_name: &'static str,
         uuid: Arc<str>,
     },
+    InvalidUncutSegments {
+        video_id: Arc<str>,
+        reason: &'static str,
+    },
+    MismatchedHashPrefix {
+        uuid: Arc<str>,
+        parsed: u16,
+        computed: u16,
+    },
+    TruncatedTitle {
+        uuid: Arc<str>,
+        length: usize,
+        max_length: usize,
+    },
 }
 
 #[derive(Debug, Clone, Copy, strum::Display)]
@@ -206,6 +261,7 @@ pub enum ObjectKind {
     Thumbnail,
     Username,
     Warning,
+    VideoInfo,
 }
 
 #[derive(Debug, Clone)]
@@ -218,7 +274,10 @@ impl Display for ParseError {
         match *self.1 {
             ParseErrorKind::InvalidValue { ref uuid, field, value } => write!(f, "Parsing error: Field {field} in {object_kind} {uuid} contained an invalid value: {value}"),
             ParseErrorKind::MismatchedUUIDs { struct_name, ref uuid_main, ref uuid_struct } => write!(f, "Merge error: Component {struct_name} of {object_kind} {uuid_main} had a different UUID: {uuid_struct}"),
-            ParseErrorKind::MissingSubobject { struct_name, ref uuid } => write!(f, "Parsing error: {object_kind} {uuid} was missing an associated {struct_name} object")
+            ParseErrorKind::MissingSubobject { struct_name, ref uuid } => write!(f, "Parsing error: {object_kind} {uuid} was missing an associated {struct_name} object"),
+            ParseErrorKind::InvalidUncutSegments { ref video_id, reason } => write!(f, "Validation error: Uncut segments of {object_kind} {video_id} are invalid: {reason}"),
+            ParseErrorKind::MismatchedHashPrefix { ref uuid, parsed, computed } => write!(f, "Validation error: The hashedVideoID of {object_kind} {uuid} starts with {parsed:04x}, but its video ID hashes to {computed:04x}"),
+            ParseErrorKind::TruncatedTitle { ref uuid, length, max_length } => write!(f, "Validation error: {object_kind} {uuid} was {length} characters long and has been truncated to {max_length} characters"),
         }
     }
 }
@@ -232,6 +291,125 @@ pub struct DearrowDB {
     /// Use `.get_video_info()` to get a specific `VideoInfo` object
     pub video_infos: Box<[Box<[VideoInfo]>]>,
     pub warnings: Vec<Warning>,
+    pub broken_entries: BrokenEntryCounts,
+}
+
+/// An empty database, with all hashprefix buckets present
+impl Default for DearrowDB {
+    fn default() -> Self {
+        DearrowDB {
+            titles: Vec::new(),
+            thumbnails: Vec::new(),
+            usernames: HashMap::new(),
+            vip_users: HashSet::new(),
+            video_infos: (0..=u16::MAX).map(|_| Box::default()).collect(),
+            warnings: Vec::new(),
+            broken_entries: BrokenEntryCounts::default(),
+        }
+    }
+}
+
+/// Tally of objects flagged with `MissingVotes`/`MissingTimestamp` during loading
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BrokenEntryCounts {
+    pub titles_missing_votes: usize,
+    pub thumbnails_missing_votes: usize,
+    pub thumbnails_missing_timestamp: usize,
+}
+
+impl BrokenEntryCounts {
+    pub fn count(titles: &[Title], thumbnails: &[Thumbnail]) -> Self {
+        Self {
+            titles_missing_votes: titles.iter().filter(|t| t.flags.contains(TitleFlags::MissingVotes)).count(),
+            thumbnails_missing_votes: thumbnails.iter().filter(|t| t.flags.contains(ThumbnailFlags::MissingVotes)).count(),
+            thumbnails_missing_timestamp: thumbnails.iter().filter(|t| t.flags.contains(ThumbnailFlags::MissingTimestamp)).count(),
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.titles_missing_votes + self.thumbnails_missing_votes + self.thumbnails_missing_timestamp
+    }
+}
+
+/// Categories of objects that can be selectively loaded by [`DearrowDB::load`]
+///
+/// Skipped categories are left empty. Some categories pull in extra files:
+/// - `Thumbnails` also reads the thumbnail votes and thumbnail timestamps files
+/// - `Titles` also reads the title votes file
+/// - `VideoInfo` is derived from the sponsor times file
+#[bitflags]
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadCategory {
+    Titles,
+    Thumbnails,
+    Usernames,
+    VipUsers,
+    VideoInfo,
+    Warnings,
+}
+
+/// Action type of a segment from the sponsor times file, as stored in its `actionType` column
+#[bitflags]
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, strum::EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SegmentActionType {
+    Skip,
+    Mute,
+    Full,
+    Poi,
+    Chapter,
+}
+
+/// Extra options for [`DearrowDB::load`]
+#[derive(Clone, Copy, Debug)]
+pub struct LoadOptions {
+    /// Check whether the hashprefix parsed from the `hashedVideoID` of each title and thumbnail
+    /// matches the hash of its video ID, reporting mismatches as non-fatal errors.
+    ///
+    /// Off by default, as this requires hashing the video ID of every object.
+    pub verify_hashprefixes: bool,
+    /// Guess the language of every title, see [`language::detect_language`].
+    ///
+    /// Off by default, as this has to look at every character of every title.
+    pub detect_title_languages: bool,
+    /// Don't intern the text of titles in the [`StringSet`].
+    ///
+    /// Most title texts are unique, so interning them mostly fills the set with entries that are
+    /// removed by [`StringSet::clean`] later. Identical title texts will no longer share an
+    /// allocation, so they have to be compared by value.
+    pub skip_title_interning: bool,
+    /// Truncate title texts longer than this many characters, reporting each truncated title as a
+    /// non-fatal error.
+    ///
+    /// `None` by default, keeping every title as submitted.
+    pub max_title_length: Option<usize>,
+    /// Replace invalid UTF-8 in the CSV files with U+FFFD, reporting each affected line as a
+    /// non-fatal error, instead of dropping the rows containing it.
+    ///
+    /// Off by default, as this converts each row separately, which is slower.
+    pub lossy_utf8: bool,
+    /// Action types of visible `outro` segments which mark a video as having an outro.
+    ///
+    /// Only `skip` by default, matching the upstream branding endpoint. Only `skip` segments are
+    /// ever cut out of the video, regardless of this option.
+    pub outro_action_types: BitFlags<SegmentActionType>,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self {
+            verify_hashprefixes: false,
+            detect_title_languages: false,
+            skip_title_interning: false,
+            max_title_length: None,
+            lossy_utf8: false,
+            outro_action_types: SegmentActionType::Skip.into(),
+        }
+    }
 }
 
 pub struct DBPaths {
@@ -251,9 +429,68 @@ pub type LoadResult = (DearrowDB, Vec<ErrorContext>);
 impl DearrowDB {
     pub fn sort(&mut self) {
         self.titles
-            .sort_unstable_by(|a, b| a.time_submitted.cmp(&b.time_submitted));
+            .sort_unstable_by_key(|t| t.time_submitted);
         self.thumbnails
-            .sort_unstable_by(|a, b| a.time_submitted.cmp(&b.time_submitted));
+            .sort_unstable_by_key(|t| t.time_submitted);
+    }
+
+    /// Verifies that every string in the database is the instance stored in the `string_set`.
+    /// Title texts are not checked if the database was loaded with
+    /// [`LoadOptions::skip_title_interning`].
+    ///
+    /// Lookups such as [`DearrowDB::get_video_info`] compare strings by address, and silently
+    /// fail for strings that were not interned.
+    pub fn check_interning(&self, string_set: &StringSet, options: LoadOptions) -> Result<()> {
+        fn check(string_set: &StringSet, kind: ObjectKind, field: &str, s: &Arc<str>) -> Result<()> {
+            if !string_set.is_interned(s) {
+                bail!(format!("Field {field} of a {kind} was not interned: {s}"));
+            }
+            Ok(())
+        }
+        for t in &self.titles {
+            check(string_set, ObjectKind::Title, "uuid", &t.uuid)?;
+            check(string_set, ObjectKind::Title, "video_id", &t.video_id)?;
+            if !options.skip_title_interning {
+                check(string_set, ObjectKind::Title, "title", &t.title)?;
+            }
+            check(string_set, ObjectKind::Title, "user_id", &t.user_id)?;
+        }
+        for t in &self.thumbnails {
+            check(string_set, ObjectKind::Thumbnail, "uuid", &t.uuid)?;
+            check(string_set, ObjectKind::Thumbnail, "video_id", &t.video_id)?;
+            check(string_set, ObjectKind::Thumbnail, "user_id", &t.user_id)?;
+        }
+        for (user_id, u) in &self.usernames {
+            check(string_set, ObjectKind::Username, "user_id", user_id)?;
+            check(string_set, ObjectKind::Username, "user_id", &u.user_id)?;
+            check(string_set, ObjectKind::Username, "username", &u.username)?;
+        }
+        for user_id in &self.vip_users {
+            check(string_set, ObjectKind::Username, "vip user_id", user_id)?;
+        }
+        for v in self.video_infos.iter().flatten() {
+            check(string_set, ObjectKind::VideoInfo, "video_id", &v.video_id)?;
+        }
+        for w in &self.warnings {
+            check(string_set, ObjectKind::Warning, "warned_user_id", &w.warned_user_id)?;
+            check(string_set, ObjectKind::Warning, "issuer_user_id", &w.issuer_user_id)?;
+            check(string_set, ObjectKind::Warning, "message", &w.message)?;
+        }
+        Ok(())
+    }
+
+    /// Verifies that every `VideoInfo` is stored in the bucket [`DearrowDB::get_video_info`]
+    /// looks it up in.
+    pub fn check_video_info_buckets(&self) -> Result<()> {
+        for (bucket, infos) in self.video_infos.iter().enumerate() {
+            for info in infos {
+                let found = self.get_video_info(&info.video_id).is_some_and(|i| std::ptr::eq(i, info));
+                if !found {
+                    bail!(format!("VideoInfo of {} is stored in bucket {bucket:04x}, but can't be looked up", info.video_id));
+                }
+            }
+        }
+        Ok(())
     }
 
     pub fn get_video_info(&self, video_id: &Arc<str>) -> Option<&VideoInfo> {
@@ -262,7 +499,7 @@ impl DearrowDB {
             .find(|v| Arc::ptr_eq(&v.video_id, video_id))
     }
 
-    pub fn load_dir(dir: &Path, string_set: &mut StringSet) -> Result<LoadResult> {
+    pub fn load_dir(dir: &Path, categories: BitFlags<LoadCategory>, options: LoadOptions, string_set: &mut StringSet) -> Result<LoadResult> {
         DearrowDB::load(
             &DBPaths {
                 thumbnails: dir.join("thumbnails.csv"),
@@ -275,76 +512,162 @@ impl DearrowDB {
                 sponsor_times: dir.join("sponsorTimes.csv"),
                 warnings: dir.join("warnings.csv"),
             },
+            categories,
+            options,
             string_set,
         )
     }
 
-    pub fn load(paths: &DBPaths, string_set: &mut StringSet) -> Result<LoadResult> {
+    /// Loads the selected object categories, leaving the rest empty.
+    /// See [`LoadCategory`] for the files required by each category.
+    ///
+    /// Categories are loaded in parallel, each on its own thread. Every string is interned into
+    /// `string_set` once all of them are loaded.
+    pub fn load(paths: &DBPaths, categories: BitFlags<LoadCategory>, options: LoadOptions, string_set: &mut StringSet) -> Result<LoadResult> {
         // Briefly open each file in read-only to check if they exist before continuing to parse
-        File::open(&paths.thumbnails).context("Could not open the thumbnails file")?;
-        File::open(&paths.thumbnail_timestamps)
-            .context("Could not open the thumbnail timestamps file")?;
-        File::open(&paths.thumbnail_votes).context("Could not open the thumbnail votes file")?;
-        File::open(&paths.titles).context("Could not open the titles file")?;
-        File::open(&paths.title_votes).context("Could not open the title votes file")?;
-        File::open(&paths.usernames).context("Could not open the usernames file")?;
-        File::open(&paths.vip_users).context("Could not open the VIP users file")?;
-        File::open(&paths.sponsor_times)
-            .context("Could not open the SponsorBlock segments file")?;
-        File::open(&paths.warnings).context("Could not open the warnings file")?;
-
-        // Create a vec for non-fatal deserialization errors
-        let mut errors: Vec<ErrorContext> = Vec::new();
-
-        info!("Loading thumbnails...");
-        let thumbnails = Self::load_thumbnails(paths, string_set, &mut errors)?;
-
-        info!("Loading titles...");
-        let titles = Self::load_titles(paths, string_set, &mut errors)?;
+        if categories.contains(LoadCategory::Thumbnails) {
+            File::open(&paths.thumbnails).context("Could not open the thumbnails file")?;
+            File::open(&paths.thumbnail_timestamps)
+                .context("Could not open the thumbnail timestamps file")?;
+            File::open(&paths.thumbnail_votes).context("Could not open the thumbnail votes file")?;
+        }
+        if categories.contains(LoadCategory::Titles) {
+            File::open(&paths.titles).context("Could not open the titles file")?;
+            File::open(&paths.title_votes).context("Could not open the title votes file")?;
+        }
+        if categories.contains(LoadCategory::Usernames) {
+            File::open(&paths.usernames).context("Could not open the usernames file")?;
+        }
+        if categories.contains(LoadCategory::VipUsers) {
+            File::open(&paths.vip_users).context("Could not open the VIP users file")?;
+        }
+        if categories.contains(LoadCategory::VideoInfo) {
+            File::open(&paths.sponsor_times)
+                .context("Could not open the SponsorBlock segments file")?;
+        }
+        if categories.contains(LoadCategory::Warnings) {
+            File::open(&paths.warnings).context("Could not open the warnings file")?;
+        }
 
-        info!("Loading usernames...");
-        let usernames = Self::load_usernames(paths, string_set, &mut errors)?;
+        // Each category is loaded on its own thread, with its own error list. The strings are
+        // interned into `string_set` afterwards, so that only one pre-sized set is ever filled.
+        let (thumbnails, titles, usernames, vip_users, video_infos, warnings) = std::thread::scope(|scope| {
+            let thumbnails = spawn_loader(scope, categories.contains(LoadCategory::Thumbnails), "Loading thumbnails...", |errors| {
+                Self::load_thumbnails(paths, options, errors)
+            });
+            let titles = spawn_loader(scope, categories.contains(LoadCategory::Titles), "Loading titles...", |errors| {
+                Self::load_titles(paths, options, errors)
+            });
+            let usernames = spawn_loader(scope, categories.contains(LoadCategory::Usernames), "Loading usernames...", |errors| {
+                Self::load_usernames(paths, options, errors)
+            });
+            let vip_users = spawn_loader(scope, categories.contains(LoadCategory::VipUsers), "Loading VIPs...", |errors| {
+                Self::load_vips(paths, options, errors)
+            });
+            let video_infos = spawn_loader(scope, categories.contains(LoadCategory::VideoInfo), "Extracting video info from SponsorBlock segments...", |errors| {
+                Self::load_video_info(paths, options, errors)
+            });
+            let warnings = spawn_loader(scope, categories.contains(LoadCategory::Warnings), "Loading warnings...", |errors| {
+                Self::load_warnings(paths, options, errors)
+            });
+            (
+                join_loader(thumbnails),
+                join_loader(titles),
+                join_loader(usernames),
+                join_loader(vip_users),
+                join_loader(video_infos),
+                join_loader(warnings),
+            )
+        });
 
-        info!("Loading VIPs...");
-        let vip_users = Self::load_vips(paths, string_set, &mut errors)?;
+        // Collect the non-fatal errors of every loader, in category order
+        let mut errors: Vec<ErrorContext> = Vec::new();
+        let mut thumbnails = thumbnails.finish(&mut errors)?.unwrap_or_default();
+        let mut titles = titles.finish(&mut errors)?.unwrap_or_default();
+        let usernames = usernames.finish(&mut errors)?.unwrap_or_default();
+        let vip_users = vip_users.finish(&mut errors)?.unwrap_or_default();
+        let mut video_infos = video_infos.finish(&mut errors)?
+            // keep all hashprefix buckets present, so that lookups don't have to care
+            .unwrap_or_else(|| (0..=u16::MAX).map(|_| Box::default()).collect());
+        let mut warnings = warnings.finish(&mut errors)?.unwrap_or_default();
+
+        info!("Interning strings...");
+        for thumbnail in &mut thumbnails {
+            string_set.dedupe_struct(thumbnail);
+        }
+        for title in &mut titles {
+            if options.skip_title_interning {
+                title.dedupe_ids(string_set);
+            } else {
+                string_set.dedupe_struct(title);
+            }
+        }
+        let usernames: HashMap<Arc<str>, Username> = usernames
+            .into_values()
+            .map(|mut username| {
+                string_set.dedupe_struct(&mut username);
+                (username.user_id.clone(), username)
+            })
+            .collect();
+        let vip_users: HashSet<Arc<str>> = vip_users
+            .into_iter()
+            .map(|mut user_id| {
+                string_set.dedupe_arc(&mut user_id);
+                user_id
+            })
+            .collect();
+        for video_info in video_infos.iter_mut().flatten() {
+            string_set.dedupe_arc(&mut video_info.video_id);
+        }
+        for warning in &mut warnings {
+            string_set.dedupe_struct(warning);
+        }
 
-        info!("Extracting video info from SponsorBlock segments...");
-        let video_infos = Self::load_video_info(paths, string_set, &mut errors)?;
+        let broken_entries = BrokenEntryCounts::count(&titles, &thumbnails);
+        if broken_entries.total() > 0 {
+            warn!(
+                "Found broken entries: {} titles missing votes, {} thumbnails missing votes, {} thumbnails missing timestamps",
+                broken_entries.titles_missing_votes,
+                broken_entries.thumbnails_missing_votes,
+                broken_entries.thumbnails_missing_timestamp,
+            );
+        }
 
-        info!("Loading warnings...");
-        let warnings = Self::load_warnings(paths, string_set, &mut errors)?;
+        let db = DearrowDB {
+            titles,
+            thumbnails,
+            usernames,
+            vip_users,
+            video_infos,
+            warnings,
+            broken_entries,
+        };
+        if cfg!(debug_assertions) {
+            if let Err(err) = db.check_interning(string_set, options) {
+                panic!("String interning invariant violated after load: {err}");
+            }
+            if let Err(err) = db.check_video_info_buckets() {
+                panic!("Video info bucketing invariant violated after load: {err}");
+            }
+        }
 
         info!("DearrowDB loaded!");
-        Ok((
-            DearrowDB {
-                titles,
-                thumbnails,
-                usernames,
-                vip_users,
-                video_infos,
-                warnings,
-            },
-            errors,
-        ))
+        Ok((db, errors))
     }
 
     fn load_thumbnails(
         paths: &DBPaths,
-        string_set: &mut StringSet,
+        options: LoadOptions,
         errors: &mut Vec<ErrorContext>,
     ) -> Result<Vec<Thumbnail>> {
-        // Load the entirety of thumbnailTimestamps and thumbnailVotes into HashMaps, while
-        // deduplicating strings
+        // Load the entirety of thumbnailTimestamps and thumbnailVotes into HashMaps
+        let mut rows = CsvRows::<csv_data::ThumbnailTimestamps>::open(&paths.thumbnail_timestamps, options.lossy_utf8)
+            .context("Could not initialize csv reader for thumbnail timestamps")?;
         let thumbnail_timestamps: HashMap<Arc<str>, csv_data::ThumbnailTimestamps> =
-            csv::Reader::from_path(&paths.thumbnail_timestamps)
-                .context("Could not initialize csv reader for thumbnail timestamps")?
-                .into_deserialize::<csv_data::ThumbnailTimestamps>()
+            rows.by_ref()
                 .filter_map(|result| {
                     match result.context("Error while deserializing thumbnail timestamps") {
-                        Ok(mut thumb) => {
-                            thumb.dedupe(string_set);
-                            Some(thumb)
-                        }
+                        Ok(thumb) => Some(thumb),
                         Err(error) => {
                             errors.push(error);
                             None
@@ -353,16 +676,14 @@ impl DearrowDB {
                 })
                 .map(|timestamp| (timestamp.uuid.clone(), timestamp))
                 .collect();
+        errors.extend(rows.lossy_warnings());
+        let mut rows = CsvRows::<csv_data::ThumbnailVotes>::open(&paths.thumbnail_votes, options.lossy_utf8)
+            .context("Could not initialize csv reader for thumbnail votes")?;
         let thumbnail_votes: HashMap<Arc<str>, csv_data::ThumbnailVotes> =
-            csv::Reader::from_path(&paths.thumbnail_votes)
-                .context("Could not initialize csv reader for thumbnail votes")?
-                .into_deserialize::<csv_data::ThumbnailVotes>()
+            rows.by_ref()
                 .filter_map(|result| {
                     match result.context("Error while deserializing thumbnail votes") {
-                        Ok(mut thumb) => {
-                            thumb.dedupe(string_set);
-                            Some(thumb)
-                        }
+                        Ok(thumb) => Some(thumb),
                         Err(error) => {
                             errors.push(error);
                             None
@@ -371,18 +692,19 @@ impl DearrowDB {
                 })
                 .map(|thumb| (thumb.uuid.clone(), thumb))
                 .collect();
+        errors.extend(rows.lossy_warnings());
 
-        // Load the Thumbnail objects while deduplicating strings and merging them with other Thumbnail* objects
-        Ok(csv::Reader::from_path(&paths.thumbnails)
-            .context("Could not initialize csv reader for thumbnails")?
-            .into_deserialize::<csv_data::Thumbnail>()
+        // Load the Thumbnail objects while merging them with other Thumbnail* objects
+        let mut rows = CsvRows::<csv_data::Thumbnail>::open(&paths.thumbnails, options.lossy_utf8)
+            .context("Could not initialize csv reader for thumbnails")?;
+        let thumbnails = rows
+            .by_ref()
             .filter_map(
                 |result| match result.context("Error while deserializing thumbnails") {
-                    Ok(mut thumb) => {
-                        thumb.dedupe(string_set);
+                    Ok(thumb) => {
                         let timestamp = thumbnail_timestamps.get(&thumb.uuid);
                         let votes = thumbnail_votes.get(&thumb.uuid);
-                        match thumb.try_merge(timestamp, votes) {
+                        match thumb.try_merge(timestamp, votes, options.verify_hashprefixes) {
                             Ok(WithWarnings { obj, warnings }) => {
                                 errors.extend(
                                     warnings
@@ -403,24 +725,23 @@ impl DearrowDB {
                     }
                 },
             )
-            .collect())
+            .collect();
+        errors.extend(rows.lossy_warnings());
+        Ok(thumbnails)
     }
 
     fn load_titles(
         paths: &DBPaths,
-        string_set: &mut StringSet,
+        options: LoadOptions,
         errors: &mut Vec<ErrorContext>,
     ) -> Result<Vec<Title>> {
+        let mut rows = CsvRows::<csv_data::TitleVotes>::open(&paths.title_votes, options.lossy_utf8)
+            .context("Could not initialize csv reader for title votes")?;
         let title_votes: HashMap<Arc<str>, csv_data::TitleVotes> =
-            csv::Reader::from_path(&paths.title_votes)
-                .context("Could not initialize csv reader for title votes")?
-                .into_deserialize::<csv_data::TitleVotes>()
+            rows.by_ref()
                 .filter_map(|result| {
                     match result.context("Error while deserializing title votes") {
-                        Ok(mut title) => {
-                            title.dedupe(string_set);
-                            Some(title)
-                        }
+                        Ok(title) => Some(title),
                         Err(error) => {
                             errors.push(error);
                             None
@@ -429,16 +750,23 @@ impl DearrowDB {
                 })
                 .map(|title| (title.uuid.clone(), title))
                 .collect();
-        Ok(csv::Reader::from_path(&paths.titles)
-            .context("Could not initialize csv reader for titles")?
-            .into_deserialize::<csv_data::Title>()
+        errors.extend(rows.lossy_warnings());
+        let mut rows = CsvRows::<csv_data::Title>::open(&paths.titles, options.lossy_utf8)
+            .context("Could not initialize csv reader for titles")?;
+        let mut titles: Vec<Title> = rows
+            .by_ref()
             .filter_map(
                 |result| match result.context("Error while deserializing titles") {
                     Ok(mut title) => {
-                        title.dedupe(string_set);
+                        if let Some(warning) = options.max_title_length.and_then(|max| title.truncate_title(max)) {
+                            errors.push(warning.context("Warning from merging title data"));
+                        }
                         let votes = title_votes.get(&title.uuid);
-                        match title.try_merge(votes) {
-                            Ok(WithWarnings { obj, warnings }) => {
+                        match title.try_merge(votes, options.verify_hashprefixes) {
+                            Ok(WithWarnings { mut obj, warnings }) => {
+                                if options.detect_title_languages {
+                                    obj.language = Some(language::detect_language(&obj.title));
+                                }
                                 errors.extend(
                                     warnings
                                         .into_iter()
@@ -458,21 +786,24 @@ impl DearrowDB {
                     }
                 },
             )
-            .collect())
+            .collect();
+        errors.extend(rows.lossy_warnings());
+        originals::mark_identical_to_original(&mut titles);
+        Ok(titles)
     }
 
     fn load_usernames(
         paths: &DBPaths,
-        string_set: &mut StringSet,
+        options: LoadOptions,
         errors: &mut Vec<ErrorContext>,
     ) -> Result<HashMap<Arc<str>, Username>> {
-        Ok(csv::Reader::from_path(&paths.usernames)
-            .context("could not initialize csv reader for usernames")?
-            .into_deserialize::<csv_data::Username>()
+        let mut rows = CsvRows::<csv_data::Username>::open(&paths.usernames, options.lossy_utf8)
+            .context("could not initialize csv reader for usernames")?;
+        let usernames = rows
+            .by_ref()
             .filter_map(
                 |result| match result.context("Error while deserializing usernames") {
-                    Ok(mut username) => {
-                        username.dedupe(string_set);
+                    Ok(username) => {
                         TryInto::<Username>::try_into(username)
                             .map_err(|e| {
                                 errors.push(e.context("Error while parsing username data"));
@@ -486,21 +817,23 @@ impl DearrowDB {
                 },
             )
             .map(|username| (username.user_id.clone(), username))
-            .collect())
+            .collect();
+        errors.extend(rows.lossy_warnings());
+        Ok(usernames)
     }
 
     fn load_vips(
         paths: &DBPaths,
-        string_set: &mut StringSet,
+        options: LoadOptions,
         errors: &mut Vec<ErrorContext>,
     ) -> Result<HashSet<Arc<str>>> {
-        Ok(csv::Reader::from_path(&paths.vip_users)
-            .context("could not initialize csv reader for VIP users")?
-            .into_deserialize::<csv_data::VIPUser>()
+        let mut rows = CsvRows::<csv_data::VIPUser>::open(&paths.vip_users, options.lossy_utf8)
+            .context("could not initialize csv reader for VIP users")?;
+        let vip_users = rows
+            .by_ref()
             .filter_map(
                 |result| match result.context("Error while deserializing vip users") {
-                    Ok(mut vip) => {
-                        vip.dedupe(string_set);
+                    Ok(vip) => {
                         Some(vip.user_id)
                     }
                     Err(error) => {
@@ -509,13 +842,15 @@ impl DearrowDB {
                     }
                 },
             )
-            .collect())
+            .collect();
+        errors.extend(rows.lossy_warnings());
+        Ok(vip_users)
     }
 
     #[allow(clippy::float_cmp)]
     fn load_video_info(
         paths: &DBPaths,
-        string_set: &mut StringSet,
+        options: LoadOptions,
         errors: &mut Vec<ErrorContext>,
     ) -> Result<Box<[Box<[VideoInfo]>]>> {
         const HASHBLOCK_RANGE: std::ops::RangeInclusive<usize> = 0..=u16::MAX as usize;
@@ -523,14 +858,13 @@ impl DearrowDB {
             HASHBLOCK_RANGE.map(|_| HashMap::new()).collect();
         let mut video_durations: Box<[HashMap<Arc<str>, csv_data::VideoDuration>]> =
             HASHBLOCK_RANGE.map(|_| HashMap::new()).collect();
-        csv::Reader::from_path(&paths.sponsor_times)
-            .context("could not initialize csv reader for SponsorBlock segments")?
-            .into_deserialize::<csv_data::SponsorTime>()
+        let mut rows = CsvRows::<csv_data::SponsorTime>::open(&paths.sponsor_times, options.lossy_utf8)
+            .context("could not initialize csv reader for SponsorBlock segments")?;
+        rows.by_ref()
             .for_each(|result| {
                 match result.context("Error while deserializing SponsorBlock segments") {
-                    Ok(mut segment) => {
-                        segment.dedupe(string_set);
-                        if let Some((hash_prefix, duration, segment)) = segment.filter_and_split() {
+                    Ok(segment) => {
+                        if let Some((hash_prefix, duration, segment)) = segment.filter_and_split(options.outro_action_types) {
                             video_durations[hash_prefix as usize]
                                 .entry(duration.video_id.clone())
                                 .and_modify(|d| {
@@ -546,15 +880,18 @@ impl DearrowDB {
                                     }
                                 })
                                 .or_insert(duration);
-                            segments[hash_prefix as usize]
-                                .entry(segment.video_id.clone())
-                                .or_default()
-                                .push(segment);
+                            if let Some(segment) = segment {
+                                segments[hash_prefix as usize]
+                                    .entry(segment.video_id.clone())
+                                    .or_default()
+                                    .push(segment);
+                            }
                         }
                     }
                     Err(error) => errors.push(error),
                 }
             });
+        errors.extend(rows.lossy_warnings());
         Ok(HASHBLOCK_RANGE
             .map(|hash_prefix| {
                 video_durations[hash_prefix]
@@ -571,80 +908,28 @@ impl DearrowDB {
                                 Some(d) => d,
                             }
                         };
-                        Some(VideoInfo {
+                        let video_info = VideoInfo {
                             video_id: duration.video_id.clone(),
                             video_duration: duration.video_duration,
-                            uncut_segments: match segments[hash_prefix].get_mut(&duration.video_id)
-                            {
-                                None => Box::new([UncutSegment {
-                                    offset: 0.,
-                                    length: 1.,
-                                }]),
+                            uncut_segments: match segments[hash_prefix].get_mut(&duration.video_id) {
+                                None => compute_uncut_segments([], video_duration),
                                 Some(segments) => {
-                                    segments.sort_unstable_by(|a, b| {
-                                        a.start_time.total_cmp(&b.start_time)
-                                    });
-                                    let mut uncut_segments: Vec<UncutSegment> = vec![];
-                                    for segment in segments {
-                                        if segment.start_time >= video_duration {
-                                            continue;
-                                        }
-                                        let offset = segment.start_time / video_duration;
-                                        let end =
-                                            segment.end_time.min(video_duration) / video_duration;
-                                        if let Some(last_segment) = uncut_segments.last_mut() {
-                                            // segment already included in previous one
-                                            if last_segment.offset > end {
-                                                continue;
-                                            }
-                                            // segment overlaps previous one, but extends past its
-                                            // end time
-                                            if last_segment.offset > offset {
-                                                *last_segment = UncutSegment {
-                                                    offset: end,
-                                                    length: 1. - end,
-                                                };
-                                            // segment does not overlap previous one
-                                            } else {
-                                                *last_segment = UncutSegment {
-                                                    offset: last_segment.offset,
-                                                    length: offset - last_segment.offset,
-                                                };
-                                                uncut_segments.push(UncutSegment {
-                                                    offset: end,
-                                                    length: 1. - end,
-                                                });
-                                            }
-                                        } else {
-                                            if offset != 0. {
-                                                uncut_segments.push(UncutSegment {
-                                                    offset: 0.,
-                                                    length: offset,
-                                                });
-                                            }
-                                            if segment.end_time != video_duration {
-                                                uncut_segments.push(UncutSegment {
-                                                    offset: end,
-                                                    length: 1. - end,
-                                                });
-                                            }
-                                        }
-                                    }
-                                    if let Some(segment) = uncut_segments.last() {
-                                        if segment.offset == 1. {
-                                            uncut_segments.pop();
-                                        }
-                                    } else {
-                                        uncut_segments.push(UncutSegment {
-                                            offset: 0.,
-                                            length: 1.,
-                                        });
-                                    }
-                                    uncut_segments.into_iter().collect()
+                                    segments.sort_unstable_by(|a, b| a.start_time.total_cmp(&b.start_time));
+                                    compute_uncut_segments(segments.iter().map(|s| (s.start_time, s.end_time)), video_duration)
                                 }
                             },
                             has_outro: duration.has_outro,
-                        })
+                        };
+                        if let Some(reason) = validate_uncut_segments(&video_info.uncut_segments) {
+                            errors.push(ParseError(
+                                ObjectKind::VideoInfo,
+                                Box::new(ParseErrorKind::InvalidUncutSegments {
+                                    video_id: video_info.video_id.clone(),
+                                    reason,
+                                }),
+                            ).context("Error while computing uncut segments"));
+                        }
+                        Some(video_info)
                     })
                     .collect()
             })
@@ -653,32 +938,188 @@ impl DearrowDB {
 
     fn load_warnings(
         paths: &DBPaths,
-        string_set: &mut StringSet,
+        options: LoadOptions,
         errors: &mut Vec<ErrorContext>,
     ) -> Result<Vec<Warning>> {
         const CONTEXT: &str = "Error while deserializing warnings";
-        Ok(csv::Reader::from_path(&paths.warnings)
-            .context("could not initialize csv reader for warnings")?
-            .into_deserialize::<csv_data::Warning>()
+        let mut rows = CsvRows::<csv_data::Warning>::open(&paths.warnings, options.lossy_utf8)
+            .context("could not initialize csv reader for warnings")?;
+        let warnings = rows
+            .by_ref()
             .filter_map(|result| {
                 match result
                     .context(CONTEXT)
                     .and_then(|w| Warning::try_from(w).context(CONTEXT))
                 {
-                    Ok(mut tip) => {
-                        tip.dedupe(string_set);
-                        Some(tip)
-                    }
+                    Ok(tip) => Some(tip),
                     Err(error) => {
                         errors.push(error);
                         None
                     }
                 }
             })
-            .collect())
+            .collect();
+        errors.extend(rows.lossy_warnings());
+        Ok(warnings)
+    }
+}
+
+/// Output of a loader ran by [`spawn_loader`]: `None` if the category was not loaded, otherwise
+/// the loaded objects (or a fatal error) with the non-fatal errors
+struct LoaderOutput<T>(Option<(Result<T>, Vec<ErrorContext>)>);
+
+impl<T> LoaderOutput<T> {
+    /// Appends the non-fatal errors to `errors`, returning the loaded objects
+    fn finish(self, errors: &mut Vec<ErrorContext>) -> Result<Option<T>> {
+        let Some((result, loader_errors)) = self.0 else {
+            return Ok(None);
+        };
+        errors.extend(loader_errors);
+        result.map(Some)
+    }
+}
+
+/// Runs `load` on a new thread of `scope` if `enabled`, with a fresh error list
+fn spawn_loader<'scope, T: Send + 'scope>(
+    scope: &'scope std::thread::Scope<'scope, '_>,
+    enabled: bool,
+    message: &'static str,
+    load: impl FnOnce(&mut Vec<ErrorContext>) -> Result<T> + Send + 'scope,
+) -> Option<std::thread::ScopedJoinHandle<'scope, (Result<T>, Vec<ErrorContext>)>> {
+    enabled.then(|| {
+        scope.spawn(move || {
+            info!("{message}");
+            let mut errors = Vec::new();
+            (load(&mut errors), errors)
+        })
+    })
+}
+
+/// Waits for a loader spawned by [`spawn_loader`], propagating panics
+fn join_loader<T>(handle: Option<std::thread::ScopedJoinHandle<'_, (Result<T>, Vec<ErrorContext>)>>) -> LoaderOutput<T> {
+    LoaderOutput(handle.map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))))
+}
+
+/// Computes the parts of a video not covered by any of the given skipped segments.
+///
+/// `sorted_segments` are `(start_time, end_time)` pairs in seconds, sorted by their start time.
+/// The offsets and lengths of the returned segments are fractions of `video_duration`.
+// the offset of the trailing segment is clamped to exactly 1. when a segment reaches the video end
+#[allow(clippy::float_cmp)]
+pub fn compute_uncut_segments(sorted_segments: impl IntoIterator<Item = (f64, f64)>, video_duration: f64) -> Box<[UncutSegment]> {
+    let mut uncut_segments: Vec<UncutSegment> = vec![];
+    for (start_time, end_time) in sorted_segments {
+        if start_time >= video_duration {
+            continue;
+        }
+        let offset = start_time / video_duration;
+        let end = end_time.min(video_duration) / video_duration;
+        if let Some(last_segment) = uncut_segments.last_mut() {
+            // segment already included in previous one
+            if last_segment.offset > end {
+                continue;
+            }
+            // segment overlaps previous one, but extends past its
+            // end time
+            if last_segment.offset > offset {
+                *last_segment = UncutSegment {
+                    offset: end,
+                    length: 1. - end,
+                };
+            // segment does not overlap previous one
+            } else {
+                *last_segment = UncutSegment {
+                    offset: last_segment.offset,
+                    length: offset - last_segment.offset,
+                };
+                uncut_segments.push(UncutSegment {
+                    offset: end,
+                    length: 1. - end,
+                });
+            }
+        } else {
+            if offset != 0. {
+                uncut_segments.push(UncutSegment {
+                    offset: 0.,
+                    length: offset,
+                });
+            }
+            // always push the trailing segment, even if empty -
+            // the checks above assume the last segment is the one
+            // after the latest skipped segment
+            uncut_segments.push(UncutSegment {
+                offset: end,
+                length: 1. - end,
+            });
+        }
+    }
+    if let Some(segment) = uncut_segments.last() {
+        if segment.offset == 1. {
+            uncut_segments.pop();
+        }
+    } else {
+        uncut_segments.push(UncutSegment {
+            offset: 0.,
+            length: 1.,
+        });
+    }
+    uncut_segments.into_boxed_slice()
+}
+
+/// Picks the timestamp for the "random" thumbnail of a video, as a fraction of the video duration.
+///
+/// This mirrors the logic used by the upstream server: the pick is seeded with the video ID, so it
+/// stays the same for a video as long as its segments don't change. If the video has no marked
+/// outro, the last 10% of the video is assumed to be an outro or endcard and excluded from the
+/// pick. Otherwise, the outro is excluded like any other skip segment, via `uncut_segments`.
+pub fn get_random_time_for_video(video_id: &str, video_info: Option<&VideoInfo>) -> f64 {
+    let random_time = Alea::new(video_id).random();
+
+    if let Some(video_info) = video_info {
+        let mut random_time = if !video_info.has_outro && random_time > 0.9 {
+            random_time - 0.9
+        } else {
+            random_time
+        };
+
+        // Scale to the unmarked length of the video
+        random_time *= video_info.uncut_segments.iter().map(|s| s.length).sum::<f64>();
+
+        // Then map it to the unmarked segments
+        for segment in &video_info.uncut_segments {
+            if random_time <= segment.length {
+                random_time += segment.offset;
+                break;
+            }
+            random_time -= segment.length;
+        };
+
+        random_time
+    } else if random_time > 0.9 {
+        random_time - 0.9
+    } else {
+        random_time
     }
 }
 
+/// Checks that the uncut segments of a video stay within the video, returning a description of the
+/// problem if they don't
+fn validate_uncut_segments(segments: &[UncutSegment]) -> Option<&'static str> {
+    // allow for some floating point error
+    const EPSILON: f64 = 1e-9;
+    let mut total_length = 0.;
+    for segment in segments {
+        if !(0. ..=1.).contains(&segment.length) {
+            return Some("segment length is outside of the [0, 1] range");
+        }
+        if segment.offset < 0. || segment.offset + segment.length > 1. + EPSILON {
+            return Some("segment extends outside of the video");
+        }
+        total_length += segment.length;
+    }
+    (total_length > 1. + EPSILON).then_some("segment lengths add up to more than the whole video")
+}
+
 pub fn compute_hashprefix(s: &str) -> u16 {
     let mut hasher = Sha256::new();
     hasher.update(s);
@@ -688,10 +1129,10 @@ pub fn compute_hashprefix(s: &str) -> u16 {
 
 mod csv_data {
     use super::{
-        compute_hashprefix, Dedupe, ObjectKind, ParseError, ParseErrorKind, StringSet,
-        ThumbnailFlags, TitleFlags,
+        compute_hashprefix, ObjectKind, ParseError, ParseErrorKind, SegmentActionType, ThumbnailFlags,
+        TitleFlags,
     };
-    use enumflags2::BitFlag;
+    use enumflags2::{BitFlag, BitFlags};
     use serde::Deserialize;
     use std::sync::{Arc, LazyLock};
 
@@ -703,6 +1144,39 @@ mod csv_data {
         pub warnings: Vec<ParseError>,
     }
 
+    /// Parses the hashprefix from the `hashed_video_id`, falling back to computing it.
+    ///
+    /// If `verify` is set, a successfully parsed prefix is compared against the computed one,
+    /// pushing a warning on mismatch.
+    fn hashprefix(
+        kind: ObjectKind,
+        uuid: &Arc<str>,
+        video_id: &str,
+        hashed_video_id: &str,
+        verify: bool,
+        warnings: &mut Vec<ParseError>,
+    ) -> u16 {
+        match u16::from_str_radix(&hashed_video_id[..4], 16) {
+            Ok(parsed) => {
+                if verify {
+                    let computed = compute_hashprefix(video_id);
+                    if parsed != computed {
+                        warnings.push(ParseError(
+                            kind,
+                            Box::new(ParseErrorKind::MismatchedHashPrefix {
+                                uuid: uuid.clone(),
+                                parsed,
+                                computed,
+                            }),
+                        ));
+                    }
+                }
+                parsed
+            }
+            Err(_) => compute_hashprefix(video_id),
+        }
+    }
+
     #[derive(Deserialize)]
     pub struct Thumbnail {
         #[serde(rename = "videoID")]
@@ -816,8 +1290,6 @@ mod csv_data {
         pub category: String,
         #[serde(rename = "actionType")]
         pub action_type: String,
-        #[serde(rename = "hashedVideoID")]
-        pub hashed_video_id: String,
         #[serde(rename = "timeSubmitted")]
         pub time_submitted: i64,
     }
@@ -925,6 +1397,7 @@ mod csv_data {
             self,
             timestamps: Option<&ThumbnailTimestamps>,
             votes: Option<&ThumbnailVotes>,
+            verify_hashprefix: bool,
         ) -> ResultWithWarnings<super::Thumbnail> {
             match &timestamps {
                 Some(timestamp) if self.uuid != timestamp.uuid => {
@@ -938,7 +1411,7 @@ mod csv_data {
                     ));
                 }
                 _ => {}
-            };
+            }
             match &votes {
                 Some(votes) if self.uuid != votes.uuid => {
                     return Err(ParseError(
@@ -951,7 +1424,7 @@ mod csv_data {
                     ));
                 }
                 _ => {}
-            };
+            }
             let mut warnings = Vec::new();
             let mut flags = ThumbnailFlags::empty();
             if votes.is_none() {
@@ -982,6 +1455,14 @@ mod csv_data {
                 ));
                 flags.set(ThumbnailFlags::MissingTimestamp, true);
             }
+            let hash_prefix = hashprefix(
+                ObjectKind::Thumbnail,
+                &self.uuid,
+                &self.video_id,
+                &self.hashed_video_id,
+                verify_hashprefix,
+                &mut warnings,
+            );
             Ok(WithWarnings {
                 obj: super::Thumbnail {
                     uuid: self.uuid,
@@ -991,10 +1472,7 @@ mod csv_data {
                     votes: votes.votes,
                     downvotes: votes.downvotes,
                     flags,
-                    hash_prefix: match u16::from_str_radix(&self.hashed_video_id[..4], 16) {
-                        Ok(n) => n,
-                        Err(_) => compute_hashprefix(&self.video_id),
-                    },
+                    hash_prefix,
                     video_id: self.video_id,
                 },
                 warnings,
@@ -1003,7 +1481,7 @@ mod csv_data {
     }
 
     impl Title {
-        pub fn try_merge(self, votes: Option<&TitleVotes>) -> ResultWithWarnings<super::Title> {
+        pub fn try_merge(self, votes: Option<&TitleVotes>, verify_hashprefix: bool) -> ResultWithWarnings<super::Title> {
             match &votes {
                 Some(votes) if self.uuid != votes.uuid => {
                     return Err(ParseError(
@@ -1016,7 +1494,7 @@ mod csv_data {
                     ));
                 }
                 _ => {}
-            };
+            }
             let mut warnings = Vec::new();
             let mut flags = TitleFlags::empty();
             if votes.is_none() {
@@ -1041,6 +1519,14 @@ mod csv_data {
                 intbool!(title votes, verification, 0, -1),
             );
             flags.set(TitleFlags::Removed, intbool!(title votes, removed));
+            let hash_prefix = hashprefix(
+                ObjectKind::Title,
+                &self.uuid,
+                &self.video_id,
+                &self.hashed_video_id,
+                verify_hashprefix,
+                &mut warnings,
+            );
             Ok(WithWarnings {
                 obj: super::Title {
                     uuid: self.uuid,
@@ -1050,11 +1536,10 @@ mod csv_data {
                     votes: votes.votes,
                     downvotes: votes.downvotes,
                     flags,
-                    hash_prefix: match u16::from_str_radix(&self.hashed_video_id[..4], 16) {
-                        Ok(n) => n,
-                        Err(_) => compute_hashprefix(&self.video_id),
-                    },
+                    hash_prefix,
                     video_id: self.video_id,
+                    language: None,
+                    identical_to_original: None,
                 },
                 warnings,
             })
@@ -1062,34 +1547,43 @@ mod csv_data {
     }
 
     impl SponsorTime {
-        pub fn filter_and_split(self) -> Option<(u16, VideoDuration, TrimmedSponsorTime)> {
-            let hash_prefix = match u16::from_str_radix(&self.hashed_video_id[..4], 16) {
-                Ok(n) => n,
-                Err(_) => compute_hashprefix(&self.video_id),
-            };
+        /// Splits a visible segment into its video duration and the trimmed segment.
+        ///
+        /// Only `skip` segments are trimmed and contribute a video duration, segments of other
+        /// action types are only kept if they mark an outro per `outro_action_types`.
+        pub fn filter_and_split(
+            self,
+            outro_action_types: BitFlags<SegmentActionType>,
+        ) -> Option<(u16, VideoDuration, Option<TrimmedSponsorTime>)> {
             // https://github.com/ajayyy/SponsorBlockServer/blob/af31f511a53a7e30ad27123656a911393200672b/src/routes/getBranding.ts#L112
-            if self.votes > -2
-                && self.shadow_hidden == 0
-                && self.hidden == 0
-                && self.action_type == "skip"
-            {
-                Some((
-                    hash_prefix,
-                    VideoDuration {
-                        video_id: self.video_id.clone(),
-                        video_duration: self.video_duration,
-                        time_submitted: self.time_submitted,
-                        has_outro: self.category == "outro",
-                    },
-                    TrimmedSponsorTime {
-                        video_id: self.video_id,
-                        start_time: self.start_time,
-                        end_time: self.end_time,
-                    },
-                ))
-            } else {
-                None
+            if self.votes <= -2 || self.shadow_hidden != 0 || self.hidden != 0 {
+                return None;
+            }
+            let action_type = self.action_type.parse::<SegmentActionType>().ok();
+            let is_skip = action_type == Some(SegmentActionType::Skip);
+            let has_outro = self.category == "outro"
+                && action_type.is_some_and(|t| outro_action_types.contains(t));
+            if !is_skip && !has_outro {
+                return None;
             }
+            // always bucket on the computed hashprefix, `get_video_info` can't find the video
+            // info otherwise if the hashedVideoID column is wrong
+            let hash_prefix = compute_hashprefix(&self.video_id);
+            Some((
+                hash_prefix,
+                VideoDuration {
+                    video_id: self.video_id.clone(),
+                    // a zero duration is replaced by the duration of any skip segment
+                    video_duration: if is_skip { self.video_duration } else { 0. },
+                    time_submitted: self.time_submitted,
+                    has_outro,
+                },
+                is_skip.then_some(TrimmedSponsorTime {
+                    video_id: self.video_id,
+                    start_time: self.start_time,
+                    end_time: self.end_time,
+                }),
+            ))
         }
     }
 
@@ -1136,71 +1630,20 @@ mod csv_data {
         }
     }
 
-    impl Dedupe for Thumbnail {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.uuid);
-            set.dedupe_arc(&mut self.video_id);
-            set.dedupe_arc(&mut self.user_id);
-        }
-    }
-    impl Dedupe for Title {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.uuid);
-            set.dedupe_arc(&mut self.title);
-            set.dedupe_arc(&mut self.video_id);
-            set.dedupe_arc(&mut self.user_id);
-        }
-    }
-    impl Dedupe for ThumbnailVotes {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.uuid);
-        }
-    }
-    impl Dedupe for ThumbnailTimestamps {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.uuid);
-        }
-    }
-    impl Dedupe for TitleVotes {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.uuid);
-        }
-    }
-    impl Dedupe for VIPUser {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.user_id);
-        }
-    }
-    impl Dedupe for Username {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.user_id);
-            set.dedupe_arc(&mut self.username);
-        }
-    }
-
-    impl Dedupe for SponsorTime {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.video_id);
-        }
-    }
-
-    impl Dedupe for TrimmedSponsorTime {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.video_id);
-        }
-    }
-
-    impl Dedupe for VideoDuration {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.video_id);
-        }
-    }
-
-    impl Dedupe for Warning {
-        fn dedupe(&mut self, set: &mut StringSet) {
-            set.dedupe_arc(&mut self.user_id);
-            set.dedupe_arc(&mut self.issuer_user_id);
-            set.dedupe_arc(&mut self.reason);
+    impl Title {
+        /// Cuts the title text down to `max_length` characters, returning a warning if it was longer
+        pub fn truncate_title(&mut self, max_length: usize) -> Option<ParseError> {
+            let (end, _) = self.title.char_indices().nth(max_length)?;
+            let length = self.title.chars().count();
+            self.title = self.title[..end].into();
+            Some(ParseError(
+                ObjectKind::Title,
+                Box::new(ParseErrorKind::TruncatedTitle {
+                    uuid: self.uuid.clone(),
+                    length,
+                    max_length,
+                }),
+            ))
         }
     }
 }
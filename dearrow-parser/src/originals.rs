@@ -0,0 +1,52 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Detection of community titles which are identical to the original title of their video.
+//!
+//! The original title of a video is only known if it was submitted as a title with the
+//! `original` flag, which the extension does when voting for the original title.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::{Title, TitleFlags};
+
+/// Normalizes a title for comparisons with the original title.
+///
+/// Ignores case, repeated whitespace and the leading `>` which disables auto-formatting in the
+/// extension.
+pub fn normalize_title(title: &str) -> String {
+    let title = title.trim_start().strip_prefix('>').unwrap_or(title);
+    title.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Sets [`Title::identical_to_original`] of every title not flagged as the original.
+///
+/// Titles of videos with no known original title are left as `None`. A video may have several
+/// known original titles if it was renamed, matching any of them is enough.
+pub fn mark_identical_to_original(titles: &mut [Title]) {
+    let mut originals: HashMap<Arc<str>, HashSet<String>> = HashMap::new();
+    for title in titles.iter().filter(|t| t.flags.contains(TitleFlags::Original)) {
+        originals.entry(title.video_id.clone()).or_default().insert(normalize_title(&title.title));
+    }
+    for title in titles.iter_mut().filter(|t| !t.flags.contains(TitleFlags::Original)) {
+        title.identical_to_original = originals
+            .get(&title.video_id)
+            .map(|originals| originals.contains(&normalize_title(&title.title)));
+    }
+}
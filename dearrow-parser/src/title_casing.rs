@@ -0,0 +1,328 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Port of the title formatter of the `DeArrow` extension (`src/titles/titleFormatter.ts`).
+//!
+//! The functions below follow their upstream counterparts one to one. The extension's emoji and
+//! fancy text cleanup is not ported. The language checks used for Turkish casing rules are done
+//! with [`detect_language`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::language::{detect_language, Language};
+
+/// Words which are not capitalized in title case, unless they start a sentence
+const TITLE_CASE_NOT_CAPITALIZED: &[&str] = &[
+    "a", "an", "the", "and", "but", "or", "nor", "for", "yet", "so", "as", "in", "of", "on", "to",
+    "from", "into", "like", "over", "with", "till", "upon", "off", "per", "up", "out", "at", "by",
+    "vs",
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleFormatting {
+    /// Every Word Is Capitalized
+    CapitalizeWords,
+    /// Most Words Are Capitalized, Except for Short Ones
+    TitleCase,
+    /// Only the first word of each sentence is capitalized
+    SentenceCase,
+    /// everything is lowercase
+    LowerCase,
+    /// Only the first letter of each sentence is uppercase, without trusting any other capitals
+    FirstLetterUppercase,
+}
+
+/// Formats a title the same way as the extension does.
+///
+/// `is_custom` should be set for submitted titles, for which any custom capitalization (`iPhone`,
+/// `NASA`) is trusted, and unset for original titles, where only words that aren't in all caps
+/// are trusted.
+pub fn format_title(title: &str, formatting: TitleFormatting, is_custom: bool) -> String {
+    let title = title.trim();
+    let is_turkiq = detect_language(title) == Language::Tr;
+    let words: Vec<&str> = title.split(' ').collect();
+    let result = match formatting {
+        TitleFormatting::CapitalizeWords => to_capitalize_case(&words, is_custom, is_turkiq),
+        TitleFormatting::TitleCase => to_title_case(&words, is_custom, is_turkiq),
+        TitleFormatting::SentenceCase => to_sentence_case(&words, is_custom, is_turkiq),
+        TitleFormatting::LowerCase => to_lower_case_title(&words, is_turkiq),
+        TitleFormatting::FirstLetterUppercase => to_first_letter_uppercase(&words, is_turkiq),
+    };
+    clean_punctuation(&result.join(" "))
+}
+
+/// Iterates over the words, along with the previous and next word (if any)
+fn with_neighbours<'a>(words: &'a [&'a str]) -> impl Iterator<Item = (Option<&'a str>, &'a str, Option<&'a str>)> + 'a {
+    words.iter().enumerate().map(|(index, &word)| {
+        let previous = index.checked_sub(1).map(|i| words[i]);
+        (previous, word, words.get(index + 1).copied())
+    })
+}
+
+/// Capitals are not trusted in mostly all caps titles, or next to words in all caps
+fn trust_caps(mostly_all_caps: bool, previous: Option<&str>, next: Option<&str>) -> bool {
+    !(mostly_all_caps || previous.is_some_and(is_all_caps) || next.is_some_and(is_all_caps))
+}
+
+fn to_capitalize_case(words: &[&str], is_custom: bool, is_turkiq: bool) -> Vec<String> {
+    let mostly_all_caps = is_mostly_all_caps(words);
+    words.iter()
+        .map(|&word| {
+            if force_keep_formatting(word)
+                || (is_custom && is_word_custom_capitalization(word))
+                || (!is_all_caps(word) && is_word_custom_capitalization(word))
+                || is_acronym_strict(word)
+                || (!mostly_all_caps && is_acronym(word))
+            {
+                word.to_owned()
+            } else {
+                capitalize_first_letter(word, is_turkiq)
+            }
+        })
+        .collect()
+}
+
+fn to_title_case(words: &[&str], is_custom: bool, is_turkiq: bool) -> Vec<String> {
+    let mostly_all_caps = is_mostly_all_caps(words);
+    with_neighbours(words)
+        .map(|(previous, word, next)| {
+            let trust_caps = trust_caps(mostly_all_caps, previous, next);
+            if force_keep_formatting(word)
+                || (is_custom && is_word_custom_capitalization(word))
+                || (!is_all_caps(word) && (is_word_custom_capitalization(word) || is_number_then_letter(word)))
+                || is_acronym_strict(word)
+                || (trust_caps && is_acronym(word))
+            {
+                word.to_owned()
+            } else if !start_of_sentence(previous) && list_has_word(TITLE_CASE_NOT_CAPITALIZED, &all_to_lower(word, is_turkiq)) {
+                all_to_lower(word, is_turkiq)
+            } else {
+                capitalize_first_letter(word, is_turkiq)
+            }
+        })
+        .collect()
+}
+
+fn to_sentence_case(words: &[&str], is_custom: bool, is_turkiq: bool) -> Vec<String> {
+    let in_title_case = is_in_title_case(words);
+    let mostly_all_caps = is_mostly_all_caps(words);
+    with_neighbours(words)
+        .map(|(previous, word, next)| {
+            let trust_caps = trust_caps(mostly_all_caps, previous, next);
+            if is_first_person_pronoun(word) {
+                capitalize_first_letter(word, is_turkiq)
+            } else if force_keep_formatting(word)
+                || is_acronym_strict(word)
+                || ((!in_title_case || !is_word_capital_case(word)) && trust_caps && is_acronym(word))
+                || (!in_title_case && is_word_capital_case(word))
+                || (is_custom && is_word_custom_capitalization(word))
+                || (!is_all_caps(word) && is_word_custom_capitalization(word))
+            {
+                word.to_owned()
+            } else if start_of_sentence(previous) {
+                if is_number(word) {
+                    word.to_owned()
+                } else {
+                    capitalize_first_letter(word, is_turkiq)
+                }
+            } else {
+                all_to_lower(word, is_turkiq)
+            }
+        })
+        .collect()
+}
+
+fn to_lower_case_title(words: &[&str], is_turkiq: bool) -> Vec<String> {
+    words.iter()
+        .map(|&word| if force_keep_formatting(word) { word.to_owned() } else { all_to_lower(word, is_turkiq) })
+        .collect()
+}
+
+fn to_first_letter_uppercase(words: &[&str], is_turkiq: bool) -> Vec<String> {
+    with_neighbours(words)
+        .map(|(previous, word, _)| {
+            if force_keep_formatting(word) {
+                word.to_owned()
+            } else if start_of_sentence(previous) {
+                capitalize_first_letter(word, is_turkiq)
+            } else {
+                all_to_lower(word, is_turkiq)
+            }
+        })
+        .collect()
+}
+
+/// Removes repeated exclamation and question marks from the end of the title, leaving a single
+/// question mark if there were any
+fn clean_punctuation(title: &str) -> String {
+    let title = title.trim();
+    let cleaned = title.trim_end_matches(['!', '?']);
+    let mut result = cleaned.to_owned();
+    if title[cleaned.len()..].contains('?') {
+        result.push('?');
+    }
+    result
+}
+
+/// Uppercases the first letter of the word and lowercases the rest, skipping any leading
+/// non-letter characters, such as quotes or brackets
+fn capitalize_first_letter(word: &str, is_turkiq: bool) -> String {
+    let Some((index, first)) = word.char_indices().find(|(_, c)| c.is_alphabetic()) else {
+        return word.to_owned();
+    };
+    let mut result = String::with_capacity(word.len());
+    result.push_str(&word[..index]);
+    result.push_str(&all_to_upper(&word[index..index + first.len_utf8()], is_turkiq));
+    result.push_str(&all_to_lower(&word[index + first.len_utf8()..], is_turkiq));
+    result
+}
+
+fn all_to_upper(word: &str, is_turkiq: bool) -> String {
+    if is_turkiq {
+        word.replace('i', "İ").to_uppercase()
+    } else {
+        word.to_uppercase()
+    }
+}
+
+fn all_to_lower(word: &str, is_turkiq: bool) -> String {
+    if is_turkiq {
+        word.replace('I', "ı").replace('İ', "i").to_lowercase()
+    } else {
+        word.to_lowercase()
+    }
+}
+
+fn has_letters(word: &str) -> bool {
+    word.chars().any(char::is_alphabetic)
+}
+
+/// First letter is uppercase, followed by at least one more character and no more uppercase
+/// letters (`Word`, `"Word"`, `Word's`)
+fn is_word_capital_case(word: &str) -> bool {
+    let mut chars = word.chars().skip_while(|c| !c.is_alphabetic());
+    chars.next().is_some_and(char::is_uppercase) && {
+        let rest: Vec<char> = chars.collect();
+        !rest.is_empty() && !rest.iter().any(|c| c.is_uppercase())
+    }
+}
+
+/// Capitalization other than just the first letter (`iPhone`, `YouTube`, `NASA`)
+fn is_word_custom_capitalization(word: &str) -> bool {
+    let capitals = word.chars().filter(|c| c.is_uppercase()).count();
+    let first_letter_upper = word.chars().find(|c| c.is_alphabetic()).is_some_and(char::is_uppercase);
+    capitals > 1 || (capitals == 1 && !first_letter_upper)
+}
+
+fn is_all_caps(word: &str) -> bool {
+    has_letters(word) && !word.chars().any(char::is_lowercase) && !is_acronym_strict(word)
+}
+
+/// More than half of the words are written in all caps
+fn is_mostly_all_caps(words: &[&str]) -> bool {
+    let all_caps = words.iter().filter(|w| is_all_caps(w)).count();
+    all_caps * 2 > words.len()
+}
+
+/// Short all caps words, up to 3 letters long (`USA`, `4K`, `PC`)
+fn is_acronym(word: &str) -> bool {
+    let letters = word.chars().filter(|c| c.is_alphabetic()).count();
+    (word.chars().count() <= 3 || letters <= 3) && word.chars().count() > 1 && is_all_caps(word)
+        || is_acronym_strict(word)
+}
+
+/// Letters separated by dots (`U.S.`, `a.m.`)
+fn is_acronym_strict(word: &str) -> bool {
+    let word = word.trim_start_matches(|c: char| !c.is_alphabetic());
+    let chars: Vec<char> = word.chars().collect();
+    let pairs = chars.len() / 2;
+    pairs > 0
+        && chars.chunks(2).take(pairs).all(|pair| !pair[0].is_whitespace() && pair[1] == '.')
+        && chars.get(pairs * 2).is_none_or(|c| !c.is_whitespace())
+        // a single letter followed by a dot is the end of a sentence, unless another character
+        // follows it
+        && (pairs > 1 || chars.len() > 2)
+}
+
+fn is_number(word: &str) -> bool {
+    !word.is_empty() && word.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | ','))
+}
+
+/// A number followed by letters (`1080p`, `2nd`)
+fn is_number_then_letter(word: &str) -> bool {
+    let digits = word.strip_prefix('-').unwrap_or(word);
+    let rest = digits.trim_start_matches(|c: char| c.is_ascii_digit());
+    rest.len() < digits.len() && rest.chars().next().is_some_and(char::is_alphabetic)
+}
+
+/// The standalone word "I" and its contractions (`I'm`, `I've`)
+fn is_first_person_pronoun(word: &str) -> bool {
+    let mut parts = word.splitn(2, ['\'', '’']);
+    matches!(parts.next(), Some("i" | "I"))
+        && parts.next().is_none_or(|rest| (1..=3).contains(&rest.chars().count()) && rest.chars().all(char::is_alphabetic))
+}
+
+fn is_word_all_lower(word: &str) -> bool {
+    !word.is_empty() && word.chars().all(char::is_lowercase)
+}
+
+/// Most words that would be capitalized in title case are capitalized
+fn is_in_title_case(words: &[&str]) -> bool {
+    let mut count = 0;
+    let mut ignored = 0;
+    for word in words {
+        if is_word_capital_case(word) {
+            count += 1;
+        } else if !is_word_all_lower(word) || list_has_word(TITLE_CASE_NOT_CAPITALIZED, &word.to_lowercase()) {
+            ignored += 1;
+        }
+    }
+    let length = words.len() - ignored;
+    // at least 90% of the words, but allow one lowercase word in short titles
+    (length > 4 && count * 10 >= (length.saturating_sub(1) * 10).min(length * 9)) || count >= length
+}
+
+/// Checks whether the list contains the word, ignoring any brackets around it
+fn list_has_word(list: &[&str], word: &str) -> bool {
+    let word: String = word.chars().filter(|c| !"[({<>})]".contains(*c)).collect();
+    list.contains(&word.as_str())
+}
+
+/// A word starts a sentence if it's the first word or follows a delimiter
+fn start_of_sentence(previous: Option<&str>) -> bool {
+    previous.is_none_or(is_delimiter)
+}
+
+/// Standalone separators (`-`, `|`) and words ending a sentence or a bracketed part
+fn is_delimiter(word: &str) -> bool {
+    let standalone = matches!(word, "-" | ":" | ";" | "~" | "—" | "|");
+    let ends_sentence = word.ends_with([':', '?', '.', '!', ']']);
+    (standalone || ends_sentence) && (!is_acronym_strict(word) || word.ends_with(".."))
+}
+
+/// Words that are never changed: anything without letters, and links or domains
+fn force_keep_formatting(word: &str) -> bool {
+    if !has_letters(word) {
+        return true;
+    }
+    let word = word.trim_end_matches(['.', ',', '!', '?', ':', ';', ')', ']']);
+    let chars: Vec<char> = word.chars().collect();
+    word.starts_with("http://") || word.starts_with("https://")
+        || (!is_acronym_strict(word) && chars.windows(3).any(|w| w[0].is_alphanumeric() && w[1] == '.' && w[2].is_alphabetic()))
+}
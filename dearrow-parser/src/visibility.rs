@@ -0,0 +1,160 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Whether and why a title is shown by the extension.
+//!
+//! Mirrors the filtering done by the branding endpoint of `SponsorBlockServer` and the choice
+//! of the title to show made by the extension.
+
+use enumflags2::{bitflags, BitFlags};
+use serde::{Deserialize, Serialize};
+
+use crate::{Title, TitleFlags};
+
+/// Reason for a title not being shown by the extension
+#[bitflags]
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HiddenReason {
+    /// Removed by a VIP
+    Removed,
+    ShadowHidden,
+    /// The title has no entry in the votes file, so the branding endpoint can't return it
+    MissingVotes,
+    /// The title has less than 0 upvotes
+    NegativeVotes,
+    /// Upvotes minus downvotes are -2 or less
+    TooManyDownvotes,
+    /// The score is below 0 and the title isn't locked
+    NegativeScore,
+    /// Another title of the same video is locked, while this one isn't
+    LockedCompetitor,
+    /// Another title of the same video is shown, with the same or a higher score
+    Outscored,
+}
+
+/// Resulting visibility of a title
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleVisibility {
+    /// The title is shown in place of the original title
+    Shown,
+    /// The title is returned by the branding endpoint, but can only be seen in the submission menu
+    SubmissionMenuOnly,
+    /// The title is not returned by the branding endpoint due to its votes
+    Downvoted,
+    /// The title was removed or is missing vote data
+    Removed,
+    ShadowHidden,
+}
+
+impl Title {
+    /// Computes the title's score the same way as `SponsorBlockServer` does
+    pub fn score(&self) -> i8 {
+        self.votes
+            .saturating_sub(self.downvotes)
+            .saturating_sub(self.flags.contains(TitleFlags::Unverified).into())
+    }
+
+    /// Reasons for the title not being shown that don't depend on other titles
+    pub fn own_hidden_reasons(&self) -> BitFlags<HiddenReason> {
+        let mut reasons = BitFlags::empty();
+        reasons.set(HiddenReason::Removed, self.flags.contains(TitleFlags::Removed));
+        reasons.set(HiddenReason::ShadowHidden, self.flags.contains(TitleFlags::ShadowHidden));
+        reasons.set(HiddenReason::MissingVotes, self.flags.contains(TitleFlags::MissingVotes));
+        reasons.set(HiddenReason::NegativeVotes, self.votes < 0);
+        reasons.set(HiddenReason::TooManyDownvotes, self.votes.saturating_sub(self.downvotes) <= -2);
+        reasons.set(HiddenReason::NegativeScore, !self.flags.contains(TitleFlags::Locked) && self.score() < 0);
+        reasons
+    }
+
+    /// Checks whether the title would be returned by `SponsorBlockServer`'s branding endpoint
+    pub fn returned_by_branding(&self) -> bool {
+        !self.own_hidden_reasons().intersects(
+            HiddenReason::Removed
+                | HiddenReason::ShadowHidden
+                | HiddenReason::MissingVotes
+                | HiddenReason::NegativeVotes
+                | HiddenReason::TooManyDownvotes,
+        )
+    }
+
+    /// Checks whether the extension would consider showing this title, if it wins against the
+    /// other titles of the video
+    pub fn shown_by_extension(&self) -> bool {
+        self.own_hidden_reasons().is_empty()
+    }
+}
+
+/// Explanation of whether and why a title is shown by the extension
+#[derive(Clone, Debug)]
+pub struct TitleStatus<'a> {
+    pub visibility: TitleVisibility,
+    /// Every reason preventing the title from being shown, empty if it is shown
+    pub reasons: BitFlags<HiddenReason>,
+    /// Title shown by the extension for this video, if there is one
+    pub winner: Option<&'a Title>,
+}
+
+/// Picks the title shown by the extension out of the titles of a single video.
+///
+/// Same ordering as the one used by `SponsorBlockServer`: locked titles first, then by score.
+pub fn winning_title<'a>(video_titles: impl IntoIterator<Item = &'a Title>) -> Option<&'a Title> {
+    video_titles
+        .into_iter()
+        .filter(|t| t.shown_by_extension())
+        .max_by_key(|t| (t.flags.contains(TitleFlags::Locked), t.score()))
+}
+
+/// Explains the visibility of `title`, given all titles of its video.
+///
+/// `video_titles` may include `title` itself.
+pub fn title_status<'a>(title: &'a Title, video_titles: impl IntoIterator<Item = &'a Title> + Clone) -> TitleStatus<'a> {
+    let mut reasons = title.own_hidden_reasons();
+    let winner = winning_title(video_titles.clone());
+    let is_winner = winner.is_some_and(|w| w.uuid == title.uuid);
+    if !is_winner {
+        let locked = title.flags.contains(TitleFlags::Locked);
+        reasons.set(
+            HiddenReason::LockedCompetitor,
+            !locked && video_titles.into_iter().any(|t| t.flags.contains(TitleFlags::Locked) && t.returned_by_branding()),
+        );
+        reasons.set(
+            HiddenReason::Outscored,
+            winner.is_some_and(|w| w.flags.contains(TitleFlags::Locked) == locked && w.score() >= title.score()),
+        );
+    }
+
+    let visibility = if reasons.intersects(HiddenReason::Removed | HiddenReason::MissingVotes) {
+        TitleVisibility::Removed
+    } else if reasons.contains(HiddenReason::ShadowHidden) {
+        TitleVisibility::ShadowHidden
+    } else if reasons.intersects(HiddenReason::NegativeVotes | HiddenReason::TooManyDownvotes) {
+        TitleVisibility::Downvoted
+    } else if reasons.is_empty() {
+        TitleVisibility::Shown
+    } else {
+        TitleVisibility::SubmissionMenuOnly
+    };
+    TitleStatus {
+        visibility,
+        reasons,
+        winner: winner.filter(|_| !is_winner),
+    }
+}
@@ -0,0 +1,66 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+
+//! Helpers shared by the integration tests that load small, generated databases.
+
+// every test binary includes this module, but none of them uses all of it
+#![allow(dead_code)]
+
+use std::{fs, path::PathBuf};
+
+use cloneable_errors::ErrorContext;
+use dearrow_parser::{DearrowDB, LoadCategory, LoadOptions, StringSet};
+use enumflags2::BitFlags;
+
+pub const TITLES_HEADER: &str = "videoID,title,original,userID,service,hashedVideoID,timeSubmitted,UUID\n";
+pub const TITLE_VOTES_HEADER: &str = "UUID,votes,locked,shadowHidden,verification,downvotes,removed\n";
+
+/// Result of [`load_files`]
+pub struct Loaded {
+    pub db: DearrowDB,
+    pub errors: Vec<ErrorContext>,
+    pub string_set: StringSet,
+}
+
+/// Writes `files` into a fresh temporary directory and loads it as a database.
+///
+/// `test` must be unique across all tests, as it's used to name the directory.
+pub fn load_files(
+    test: &str,
+    files: &[(&str, &[u8])],
+    categories: BitFlags<LoadCategory>,
+    options: LoadOptions,
+) -> Loaded {
+    let dir: PathBuf = std::env::temp_dir().join(format!("dab-test-{}-{test}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    for (name, contents) in files {
+        fs::write(dir.join(name), contents).unwrap();
+    }
+
+    let mut string_set = StringSet::with_capacity(16);
+    let result = DearrowDB::load_dir(&dir, categories, options, &mut string_set);
+    fs::remove_dir_all(&dir).unwrap();
+    let (db, errors) = result.unwrap();
+    Loaded { db, errors, string_set }
+}
+
+/// Causes of the given errors, formatted as strings
+pub fn causes(errors: &[ErrorContext]) -> Vec<String> {
+    errors.iter().map(|e| e.cause.as_ref().map(ToString::to_string).unwrap_or_default()).collect()
+}
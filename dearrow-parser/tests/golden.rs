@@ -0,0 +1,261 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Golden tests for loading the fixture database in `tests/fixtures/golden`.
+//!
+//! Every file in the fixture set contains at least one malformed row, so the expected
+//! errors are part of the golden output, not just the loaded objects.
+
+use std::{path::Path, sync::Arc};
+
+use cloneable_errors::ErrorContext;
+use dearrow_parser::{
+    compute_hashprefix, DearrowDB, Extension, LoadCategory, LoadOptions, StringSet, Thumbnail, ThumbnailFlags,
+    Title, TitleFlags,
+};
+use enumflags2::{make_bitflags, BitFlags};
+
+const EPSILON: f64 = 1e-9;
+
+fn load_fixture() -> (DearrowDB, Vec<ErrorContext>) {
+    load_fixture_with(LoadOptions::default())
+}
+
+fn load_fixture_with(options: LoadOptions) -> (DearrowDB, Vec<ErrorContext>) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/golden");
+    let mut string_set = StringSet::with_capacity(64);
+    DearrowDB::load_dir(&dir, BitFlags::<LoadCategory>::all(), options, &mut string_set)
+        .expect("fixture database should load")
+}
+
+fn title<'a>(db: &'a DearrowDB, uuid: &str) -> &'a Title {
+    db.titles.iter().find(|t| &*t.uuid == uuid).unwrap_or_else(|| panic!("title {uuid} should be loaded"))
+}
+
+fn thumbnail<'a>(db: &'a DearrowDB, uuid: &str) -> &'a Thumbnail {
+    db.thumbnails.iter().find(|t| &*t.uuid == uuid).unwrap_or_else(|| panic!("thumbnail {uuid} should be loaded"))
+}
+
+#[test]
+fn titles() {
+    let (db, _) = load_fixture();
+    let uuids: Vec<&str> = db.titles.iter().map(|t| &*t.uuid).collect();
+    assert_eq!(uuids, ["title-original", "title-locked", "title-shadowhidden", "title-no-votes"]);
+
+    let t = title(&db, "title-original");
+    assert_eq!(&*t.video_id, "dQw4w9WgXcQ");
+    assert_eq!(&*t.title, "Original title");
+    assert_eq!(&*t.user_id, "userA");
+    assert_eq!(t.time_submitted, 1000);
+    assert_eq!((t.votes, t.downvotes), (0, 0));
+    assert_eq!(t.flags, TitleFlags::Original);
+    assert_eq!(t.hash_prefix, 0x5f6b);
+
+    let t = title(&db, "title-locked");
+    assert_eq!(&*t.title, "Locked title, with a comma");
+    assert_eq!((t.votes, t.downvotes), (5, 1));
+    assert_eq!(t.flags, TitleFlags::Locked);
+
+    let t = title(&db, "title-shadowhidden");
+    assert_eq!((t.votes, t.downvotes), (-1, 2));
+    assert_eq!(t.flags, make_bitflags!(TitleFlags::{ShadowHidden | Unverified | Removed}));
+    // the hashed video ID isn't valid hex, so the prefix is computed from the video ID
+    assert_eq!(t.hash_prefix, compute_hashprefix("jNQXAC9IVRw"));
+
+    let t = title(&db, "title-no-votes");
+    assert_eq!((t.votes, t.downvotes), (0, 0));
+    assert_eq!(t.flags, TitleFlags::MissingVotes);
+    assert_eq!(t.hash_prefix, 0x6745);
+}
+
+#[test]
+fn thumbnails() {
+    let (db, _) = load_fixture();
+    let uuids: Vec<&str> = db.thumbnails.iter().map(|t| &*t.uuid).collect();
+    assert_eq!(uuids, ["thumb-original", "thumb-locked", "thumb-no-timestamp", "thumb-no-votes"]);
+
+    let t = thumbnail(&db, "thumb-original");
+    assert_eq!(&*t.video_id, "dQw4w9WgXcQ");
+    assert_eq!(&*t.user_id, "userA");
+    assert_eq!(t.time_submitted, 1000);
+    assert_eq!(t.timestamp, None);
+    assert_eq!(t.flags, ThumbnailFlags::Original);
+    assert_eq!(t.hash_prefix, 0x5f6b);
+
+    let t = thumbnail(&db, "thumb-locked");
+    assert!(t.timestamp.is_some_and(|ts| (ts - 12.5).abs() < EPSILON));
+    assert_eq!((t.votes, t.downvotes), (3, 1));
+    assert_eq!(t.flags, ThumbnailFlags::Locked);
+
+    let t = thumbnail(&db, "thumb-no-timestamp");
+    assert_eq!(t.timestamp, None);
+    assert_eq!(t.flags, make_bitflags!(ThumbnailFlags::{ShadowHidden | MissingTimestamp}));
+    assert_eq!(t.hash_prefix, 0x6745);
+
+    let t = thumbnail(&db, "thumb-no-votes");
+    assert!(t.timestamp.is_some_and(|ts| (ts - 30.).abs() < EPSILON));
+    assert_eq!((t.votes, t.downvotes), (0, 0));
+    assert_eq!(t.flags, ThumbnailFlags::MissingVotes);
+    assert_eq!(t.hash_prefix, compute_hashprefix("jNQXAC9IVRw"));
+}
+
+#[test]
+fn broken_entries() {
+    let (db, _) = load_fixture();
+    assert_eq!(db.broken_entries.titles_missing_votes, 1);
+    assert_eq!(db.broken_entries.thumbnails_missing_votes, 1);
+    assert_eq!(db.broken_entries.thumbnails_missing_timestamp, 1);
+}
+
+#[test]
+fn users() {
+    let (db, _) = load_fixture();
+    let mut usernames: Vec<(&str, &str, bool)> = db.usernames.values()
+        .map(|u| (&*u.user_id, &*u.username, u.locked))
+        .collect();
+    usernames.sort_unstable();
+    assert_eq!(usernames, [("userA", "Alice", false), ("userB", "Bob", true)]);
+    assert!(db.usernames.iter().all(|(key, u)| Arc::ptr_eq(key, &u.user_id)));
+
+    let vips: Vec<&str> = db.vip_users.iter().map(|u| &**u).collect();
+    assert_eq!(vips, ["userB"]);
+}
+
+#[test]
+fn video_info() {
+    let (db, _) = load_fixture();
+    let video_infos: Vec<&str> = db.video_infos.iter().flatten().map(|v| &*v.video_id).collect();
+    assert_eq!(video_infos, ["dQw4w9WgXcQ"]);
+
+    // lookups require the interned video ID
+    let video_id = &title(&db, "title-original").video_id;
+    let info = db.get_video_info(video_id).expect("video info should be reachable through its hashprefix");
+    assert!((info.video_duration - 100.).abs() < EPSILON);
+    assert!(info.has_outro);
+    // downvoted, hidden and shadowhidden segments are ignored, the outro reaches the end of the video
+    let segments: Vec<(f64, f64)> = info.uncut_segments.iter().map(|s| (s.offset, s.length)).collect();
+    assert_eq!(segments.len(), 2, "unexpected segments: {segments:?}");
+    for ((offset, length), (expected_offset, expected_length)) in segments.into_iter().zip([(0., 0.1), (0.2, 0.7)]) {
+        assert!((offset - expected_offset).abs() < EPSILON);
+        assert!((length - expected_length).abs() < EPSILON);
+    }
+}
+
+#[test]
+fn warnings() {
+    let (db, _) = load_fixture();
+    let warnings: Vec<(&str, &str, i64, Extension, &str, bool)> = db.warnings.iter()
+        .map(|w| (&*w.warned_user_id, &*w.issuer_user_id, w.time_issued, w.extension, &*w.message, w.active))
+        .collect();
+    assert_eq!(warnings, [
+        ("userA", "userB", 1500, Extension::DeArrow, "Please stop submitting clickbait", true),
+        ("userC", "userB", 1600, Extension::SponsorBlock, "Old segment warning", false),
+    ]);
+}
+
+#[test]
+fn errors() {
+    let (_, errors) = load_fixture();
+    // (context, start of the underlying error message)
+    let expected: &[(&str, &str)] = &[
+        ("Error while deserializing thumbnail timestamps", "CSV deserialize error: record 4"),
+        ("Error while deserializing thumbnail votes", "CSV deserialize error: record 5"),
+        ("Warning from merging thumbnail data", "Parsing error: Thumbnail thumb-no-timestamp was missing an associated ThumbnailTimestamps object"),
+        ("Warning from merging thumbnail data", "Parsing error: Thumbnail thumb-no-votes was missing an associated ThumbnailVotes object"),
+        ("Error while merging thumbnail data", "Parsing error: Field locked in Thumbnail thumb-bad-locked contained an invalid value: 3"),
+        ("Error while deserializing thumbnails", "CSV error: record 6"),
+        ("Error while deserializing title votes", "CSV deserialize error: record 5"),
+        ("Warning from merging title data", "Parsing error: Title title-no-votes was missing an associated TitleVotes object"),
+        ("Error while merging title data", "Parsing error: Field original in Title title-bad-original contained an invalid value: 2"),
+        ("Error while deserializing titles", "CSV deserialize error: record 6"),
+        ("Error while parsing username data", "Parsing error: Field locked in Username userC contained an invalid value: 5"),
+        ("Error while deserializing usernames", "CSV deserialize error: record 4"),
+        ("Error while deserializing vip users", "CSV error: record 2"),
+        ("Error while deserializing SponsorBlock segments", "CSV deserialize error: record 7"),
+        ("Error while deserializing warnings", "Parsing error: Field active in Warning userA contained an invalid value: 2"),
+        ("Error while deserializing warnings", "CSV deserialize error: record 4"),
+    ];
+    let actual: Vec<(String, String)> = errors.iter()
+        .map(|e| (e.to_string(), e.cause.as_ref().map(ToString::to_string).unwrap_or_default()))
+        .collect();
+    assert_eq!(actual.len(), expected.len(), "unexpected errors: {actual:#?}");
+    for ((context, cause), &(expected_context, expected_cause)) in actual.iter().zip(expected) {
+        assert_eq!(context, expected_context, "unexpected errors: {actual:#?}");
+        assert!(cause.starts_with(expected_cause), "unexpected cause {cause:?}, expected {expected_cause:?}");
+    }
+}
+
+#[test]
+fn verified_hashprefixes() {
+    // every hashedVideoID in the fixture set is genuine, so verification must not add errors
+    let (_, errors) = load_fixture();
+    let (_, verified_errors) = load_fixture_with(LoadOptions { verify_hashprefixes: true, ..LoadOptions::default() });
+    assert_eq!(verified_errors.len(), errors.len(), "unexpected errors: {verified_errors:#?}");
+}
+
+#[test]
+fn title_languages() {
+    let (db, _) = load_fixture();
+    assert!(db.titles.iter().all(|t| t.language.is_none()));
+    let (db, _) = load_fixture_with(LoadOptions { detect_title_languages: true, ..LoadOptions::default() });
+    assert!(db.titles.iter().all(|t| t.language.is_some()));
+}
+
+#[test]
+fn interning() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/golden");
+    let mut string_set = StringSet::with_capacity(64);
+    let (db, _) = DearrowDB::load_dir(&dir, BitFlags::<LoadCategory>::all(), LoadOptions::default(), &mut string_set)
+        .expect("fixture database should load");
+    string_set.clean();
+    db.check_interning(&string_set, LoadOptions::default()).expect("all strings should be interned after the initial load");
+
+    // reloads intern into a clone of the live set, which then replaces it
+    let mut reload_set = string_set.clone();
+    let (new_db, _) = DearrowDB::load_dir(&dir, BitFlags::<LoadCategory>::all(), LoadOptions::default(), &mut reload_set)
+        .expect("fixture database should reload");
+    drop(db);
+    reload_set.clean();
+    new_db.check_interning(&reload_set, LoadOptions::default()).expect("all strings should be interned after a reload");
+    let video_id = reload_set.set.get("dQw4w9WgXcQ").expect("video id should be interned").clone();
+    assert!(new_db.get_video_info(&video_id).is_some());
+
+    // a copy of an interned string is not interned
+    let mut broken_db = new_db;
+    broken_db.titles[0].video_id = Arc::from(&*broken_db.titles[0].video_id);
+    assert!(broken_db.check_interning(&reload_set, LoadOptions::default()).is_err());
+}
+
+#[test]
+fn skipped_title_interning() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/golden");
+    let options = LoadOptions { skip_title_interning: true, ..LoadOptions::default() };
+    let mut string_set = StringSet::with_capacity(64);
+    let (db, _) = DearrowDB::load_dir(&dir, BitFlags::<LoadCategory>::all(), options, &mut string_set)
+        .expect("fixture database should load");
+    string_set.clean();
+    db.check_interning(&string_set, options).expect("all other strings should still be interned");
+    assert!(db.titles.iter().all(|t| !string_set.is_interned(&t.title)));
+    assert!(db.check_interning(&string_set, LoadOptions::default()).is_err());
+
+    // the parsed titles must not change
+    let (interned_db, _) = load_fixture();
+    let texts: Vec<&str> = db.titles.iter().map(|t| &*t.title).collect();
+    let interned_texts: Vec<&str> = interned_db.titles.iter().map(|t| &*t.title).collect();
+    assert_eq!(texts, interned_texts);
+}
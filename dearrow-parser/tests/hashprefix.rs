@@ -0,0 +1,87 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+
+mod common;
+
+use std::fmt::Write;
+
+use cloneable_errors::ErrorContext;
+use common::{causes, load_files, TITLES_HEADER, TITLE_VOTES_HEADER};
+use dearrow_parser::{compute_hashprefix, DearrowDB, LoadCategory, LoadOptions};
+use enumflags2::make_bitflags;
+
+/// sha256 of `dQw4w9WgXcQ`
+const HASHED_VIDEO_ID: &str = "5f6b0b4e201f2a7e66927abb5cadeec81624dcc8efe6644b78aa182213f653a2";
+
+/// Loads a title and a thumbnail for `dQw4w9WgXcQ` with the given `hashedVideoID`
+fn load(name: &str, hashed_video_id: &str, verify_hashprefixes: bool) -> (DearrowDB, Vec<ErrorContext>) {
+    let mut titles = String::from(TITLES_HEADER);
+    writeln!(titles, "dQw4w9WgXcQ,A title,0,userA,YouTube,{hashed_video_id},1000,title").unwrap();
+    let mut thumbnails = String::from("original,userID,service,hashedVideoID,timeSubmitted,UUID,videoID\n");
+    writeln!(thumbnails, "1,userA,YouTube,{hashed_video_id},1000,thumb,dQw4w9WgXcQ").unwrap();
+    let title_votes = format!("{TITLE_VOTES_HEADER}title,0,0,0,0,0,0\n");
+    let loaded = load_files(
+        &format!("hashprefix-{name}"),
+        &[
+            ("titles.csv", titles.as_bytes()),
+            ("titleVotes.csv", title_votes.as_bytes()),
+            ("thumbnails.csv", thumbnails.as_bytes()),
+            ("thumbnailVotes.csv", b"UUID,votes,locked,shadowHidden,downvotes,removed\nthumb,0,0,0,0,0\n"),
+            ("thumbnailTimestamps.csv", b"UUID,timestamp\n"),
+        ],
+        make_bitflags!(LoadCategory::{Titles | Thumbnails}),
+        LoadOptions { verify_hashprefixes, ..LoadOptions::default() },
+    );
+    (loaded.db, loaded.errors)
+}
+
+#[test]
+fn matching_prefix() {
+    let (db, errors) = load("matching", HASHED_VIDEO_ID, true);
+    assert!(errors.is_empty(), "unexpected errors: {errors:#?}");
+    assert_eq!(db.titles[0].hash_prefix, compute_hashprefix("dQw4w9WgXcQ"));
+}
+
+#[test]
+fn mismatched_prefix_unverified() {
+    let (db, errors) = load("unverified", "beef", false);
+    assert!(errors.is_empty(), "unexpected errors: {errors:#?}");
+    assert_eq!(db.titles[0].hash_prefix, 0xbeef);
+}
+
+#[test]
+fn mismatched_prefix_verified() {
+    let (db, errors) = load("verified", "beef", true);
+    // mismatches are not fatal, the objects are still loaded with the parsed prefix
+    assert_eq!(db.titles.len(), 1);
+    assert_eq!(db.thumbnails.len(), 1);
+    assert_eq!(db.thumbnails[0].hash_prefix, 0xbeef);
+    assert_eq!(causes(&errors), [
+        "Validation error: The hashedVideoID of Thumbnail thumb starts with beef, but its video ID hashes to 5f6b",
+        "Validation error: The hashedVideoID of Title title starts with beef, but its video ID hashes to 5f6b",
+    ]);
+}
+
+#[test]
+fn unparsable_prefix() {
+    // unparsable prefixes fall back to the computed value, so there's nothing to report
+    let (db, errors) = load("unparsable", "zzzz", true);
+    assert!(errors.is_empty(), "unexpected errors: {errors:#?}");
+    assert_eq!(db.titles[0].hash_prefix, compute_hashprefix("dQw4w9WgXcQ"));
+}
@@ -0,0 +1,101 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use dearrow_parser::language::{detect_language, Language};
+
+fn check(cases: &[(&str, Language)]) {
+    for (input, expected) in cases {
+        assert_eq!(detect_language(input), *expected, "input: {input:?}");
+    }
+}
+
+#[test]
+fn latin_script() {
+    check(&[
+        ("How I built the fastest car in the world", Language::En),
+        ("Why this is the worst phone I have ever used", Language::En),
+        ("Los mejores trucos para la cocina", Language::Es),
+        ("¿Por qué nadie habla de esto?", Language::Es),
+        ("Como fazer pão em casa sem forno", Language::Pt),
+        ("Não acredito que isso aconteceu com o meu carro", Language::Pt),
+        ("Le meilleur jeu de l'année est sur PC", Language::Fr),
+        ("Pourquoi je quitte Paris pour la campagne", Language::Fr),
+        ("Warum ist das Wasser nicht blau", Language::De),
+        ("Ich habe 24 Stunden im Wald überlebt", Language::De),
+        ("Il segreto della pizza napoletana", Language::It),
+        ("Waarom ik niet meer op vakantie ga", Language::Nl),
+        ("Gdzie się podziały wszystkie pieniądze", Language::Pl),
+        ("Bu oyun neden bu kadar zor?", Language::Tr),
+        ("İSTANBUL'DA BİR GÜN VE BU ÇOK GÜZEL", Language::Tr),
+        ("Cara membuat nasi goreng yang enak di rumah", Language::Id),
+    ]);
+}
+
+#[test]
+fn cyrillic_script() {
+    check(&[
+        ("Как мы построили дом своими руками", Language::Ru),
+        ("Привет мир", Language::Ru),
+        ("Обзор нового смартфона", Language::Ru),
+        ("Їжа, яку варто спробувати", Language::Uk),
+        ("Як я переїхав до Києва", Language::Uk),
+        // Serbian and Kazakh aren't detected, rather than being mistaken for Russian
+        ("Како да научите језик брзо", Language::Unknown),
+        ("Қазақстан туралы деректі фильм", Language::Unknown),
+    ]);
+}
+
+#[test]
+fn other_scripts() {
+    check(&[
+        ("日本の夏祭りに行ってきた", Language::Ja),
+        ("我们去了北京旅游", Language::Zh),
+        ("한국 여행 브이로그", Language::Ko),
+        ("Ταξίδι στην Αθήνα", Language::El),
+        ("המדריך המלא לבישול ביתי", Language::He),
+        ("أفضل الأماكن في القاهرة", Language::Ar),
+        ("भारत की सबसे लंबी ट्रेन यात्रा", Language::Hi),
+        ("เที่ยวเชียงใหม่ 3 วัน", Language::Th),
+    ]);
+}
+
+#[test]
+fn mixed_scripts() {
+    check(&[
+        // the script making up the majority of the letters wins
+        ("iPhone 15 обзор и сравнение камер", Language::Ru),
+        ("Minecraft но я играю за дракона", Language::Ru),
+    ]);
+}
+
+#[test]
+fn unknown() {
+    check(&[
+        ("", Language::Unknown),
+        ("GTA 6", Language::Unknown),
+        ("Minecraft Speedrun", Language::Unknown),
+        ("1234567890 !!!", Language::Unknown),
+        ("iPhone 15 Pro Max", Language::Unknown),
+    ]);
+}
+
+#[test]
+fn codes() {
+    assert_eq!(Language::En.code(), "en");
+    assert_eq!(Language::Unknown.code(), "unknown");
+}
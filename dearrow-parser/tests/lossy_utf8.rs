@@ -0,0 +1,59 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+
+mod common;
+
+use cloneable_errors::ErrorContext;
+use common::{load_files, TITLES_HEADER, TITLE_VOTES_HEADER};
+use dearrow_parser::{DearrowDB, LoadCategory, LoadOptions};
+
+/// Loads two titles, the first one containing an invalid UTF-8 byte
+fn load(name: &str, lossy_utf8: bool) -> (DearrowDB, Vec<ErrorContext>) {
+    let mut titles = TITLES_HEADER.as_bytes().to_vec();
+    titles.extend_from_slice(b"dQw4w9WgXcQ,Broken \xff title,0,userA,YouTube,5f6b,1000,broken\n");
+    titles.extend_from_slice(b"dQw4w9WgXcQ,Valid title,0,userA,YouTube,5f6b,2000,valid\n");
+    let votes = format!("{TITLE_VOTES_HEADER}broken,0,0,0,0,0,0\nvalid,0,0,0,0,0,0\n");
+    let loaded = load_files(
+        &format!("lossy-utf8-{name}"),
+        &[("titles.csv", &titles), ("titleVotes.csv", votes.as_bytes())],
+        LoadCategory::Titles.into(),
+        LoadOptions { lossy_utf8, ..LoadOptions::default() },
+    );
+    (loaded.db, loaded.errors)
+}
+
+#[test]
+fn strict_drops_row() {
+    let (db, errors) = load("strict", false);
+    assert_eq!(db.titles.len(), 1);
+    assert_eq!(&*db.titles[0].uuid, "valid");
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn lossy_keeps_row() {
+    let (db, errors) = load("lossy", true);
+    assert_eq!(db.titles.len(), 2);
+    let broken = db.titles.iter().find(|t| &*t.uuid == "broken").unwrap();
+    assert_eq!(&*broken.title, "Broken \u{FFFD} title");
+    let valid = db.titles.iter().find(|t| &*t.uuid == "valid").unwrap();
+    assert_eq!(&*valid.title, "Valid title");
+    assert_eq!(errors.len(), 1, "unexpected errors: {errors:#?}");
+    assert!(errors[0].to_string().contains("Replaced invalid UTF-8 on line 2 of"), "unexpected error: {}", errors[0]);
+}
@@ -0,0 +1,71 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::sync::Arc;
+
+use dearrow_parser::originals::{mark_identical_to_original, normalize_title};
+use dearrow_parser::{Title, TitleFlags};
+use enumflags2::{make_bitflags, BitFlags};
+
+fn title(video_id: &str, text: &str, flags: BitFlags<TitleFlags>) -> Title {
+    Title {
+        uuid: Arc::from(format!("{video_id}-{text}")),
+        video_id: Arc::from(video_id),
+        title: Arc::from(text),
+        user_id: Arc::from("user"),
+        time_submitted: 1_700_000_000_000,
+        votes: 0,
+        downvotes: 0,
+        flags,
+        hash_prefix: 0,
+        language: None,
+        identical_to_original: None,
+    }
+}
+
+#[test]
+fn normalization() {
+    assert_eq!(normalize_title("  Some   Title "), "some title");
+    assert_eq!(normalize_title(">Some Title"), "some title");
+    assert_eq!(normalize_title("Some\tTITLE"), "some title");
+    assert_ne!(normalize_title("Some Title!"), normalize_title("Some Title"));
+}
+
+#[test]
+fn marks_titles_matching_the_original() {
+    let mut titles = vec![
+        title("video1", "The Original Title", make_bitflags!(TitleFlags::{Original})),
+        title("video1", ">the original  title", BitFlags::empty()),
+        title("video1", "A Better Title", BitFlags::empty()),
+        title("video2", "No Original Known", BitFlags::empty()),
+    ];
+    mark_identical_to_original(&mut titles);
+    let identical: Vec<Option<bool>> = titles.iter().map(|t| t.identical_to_original).collect();
+    assert_eq!(identical, [None, Some(true), Some(false), None]);
+}
+
+#[test]
+fn any_known_original_matches() {
+    let mut titles = vec![
+        title("video1", "Old Name", make_bitflags!(TitleFlags::{Original})),
+        title("video1", "New Name", make_bitflags!(TitleFlags::{Original})),
+        title("video1", "old name", BitFlags::empty()),
+    ];
+    mark_identical_to_original(&mut titles);
+    assert_eq!(titles[2].identical_to_original, Some(true));
+}
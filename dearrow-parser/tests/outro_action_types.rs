@@ -0,0 +1,101 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+
+//! Tests for the action types of `outro` segments marking videos as having an outro.
+
+mod common;
+
+use std::fmt::Write;
+
+use common::load_files;
+use dearrow_parser::{LoadCategory, LoadOptions, SegmentActionType, VideoInfo};
+use enumflags2::{make_bitflags, BitFlags};
+
+const EPSILON: f64 = 1e-9;
+
+/// Loads the video info of `dQw4w9WgXcQ` with a sponsor segment at 10-20s and an outro segment at
+/// 90-100s of the given action type
+fn load(name: &str, outro_action_type: &str, outro_action_types: BitFlags<SegmentActionType>) -> VideoInfo {
+    let mut segments = String::from("videoID,startTime,endTime,votes,shadowHidden,hidden,category,actionType,hashedVideoID,timeSubmitted,videoDuration\n");
+    writeln!(segments, "dQw4w9WgXcQ,10,20,0,0,0,sponsor,skip,5f6b,1000,100").unwrap();
+    writeln!(segments, "dQw4w9WgXcQ,90,100,0,0,0,outro,{outro_action_type},5f6b,1001,0").unwrap();
+    let loaded = load_files(
+        &format!("outro-{name}"),
+        &[("sponsorTimes.csv", segments.as_bytes())],
+        make_bitflags!(LoadCategory::{VideoInfo}),
+        LoadOptions { outro_action_types, ..LoadOptions::default() },
+    );
+    assert!(loaded.errors.is_empty(), "unexpected errors: {:#?}", loaded.errors);
+    let mut infos = loaded.db.video_infos.into_vec().into_iter().flat_map(Vec::from);
+    let info = infos.next().expect("video info should be loaded");
+    assert!(infos.next().is_none());
+    info
+}
+
+fn segments(info: &VideoInfo) -> Vec<(f64, f64)> {
+    info.uncut_segments.iter().map(|s| (s.offset, s.length)).collect()
+}
+
+fn assert_segments(info: &VideoInfo, expected: &[(f64, f64)]) {
+    let actual = segments(info);
+    assert_eq!(actual.len(), expected.len(), "unexpected segments: {actual:?}");
+    for ((offset, length), (expected_offset, expected_length)) in actual.into_iter().zip(expected) {
+        assert!((offset - expected_offset).abs() < EPSILON, "unexpected segments: {:?}", segments(info));
+        assert!((length - expected_length).abs() < EPSILON, "unexpected segments: {:?}", segments(info));
+    }
+}
+
+#[test]
+fn skip_outro_by_default() {
+    let info = load("skip-default", "skip", LoadOptions::default().outro_action_types);
+    assert!(info.has_outro);
+    assert!((info.video_duration - 100.).abs() < EPSILON);
+    assert_segments(&info, &[(0., 0.1), (0.2, 0.7)]);
+}
+
+#[test]
+fn non_skip_outros_ignored_by_default() {
+    for action_type in ["mute", "full", "poi", "chapter", "unknown"] {
+        let info = load(&format!("{action_type}-default"), action_type, LoadOptions::default().outro_action_types);
+        assert!(!info.has_outro, "{action_type} outro should not be detected");
+        assert_segments(&info, &[(0., 0.1), (0.2, 0.8)]);
+    }
+}
+
+#[test]
+fn non_skip_outros_detected_when_enabled() {
+    for action_type in ["mute", "full", "poi", "chapter"] {
+        let info = load(&format!("{action_type}-enabled"), action_type, BitFlags::all());
+        assert!(info.has_outro, "{action_type} outro should be detected");
+        // the outro is not cut out, only skip segments are
+        assert!((info.video_duration - 100.).abs() < EPSILON);
+        assert_segments(&info, &[(0., 0.1), (0.2, 0.8)]);
+    }
+}
+
+#[test]
+fn skip_outros_can_be_excluded() {
+    let info = load("skip-excluded", "skip", make_bitflags!(SegmentActionType::{Mute}));
+    assert!(!info.has_outro);
+    // excluded from outro detection, but still trimmed
+    assert_segments(&info, &[(0., 0.1), (0.2, 0.7)]);
+
+    let info = load("mute-only", "mute", make_bitflags!(SegmentActionType::{Mute}));
+    assert!(info.has_outro);
+}
@@ -0,0 +1,86 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::sync::Arc;
+
+use dearrow_parser::{get_random_time_for_video, UncutSegment, VideoInfo};
+
+fn video_ids() -> impl Iterator<Item = String> {
+    (0..200).map(|i| format!("video{i:06}"))
+}
+
+fn video_info(video_id: &str, uncut_segments: &[(f64, f64)], has_outro: bool) -> VideoInfo {
+    VideoInfo {
+        video_id: Arc::from(video_id),
+        video_duration: 100.,
+        uncut_segments: uncut_segments.iter().map(|&(offset, length)| UncutSegment { offset, length }).collect(),
+        has_outro,
+    }
+}
+
+#[test]
+fn without_outro_last_ten_percent_is_excluded() {
+    for video_id in video_ids() {
+        let info = video_info(&video_id, &[(0., 1.)], false);
+        let time = get_random_time_for_video(&video_id, Some(&info));
+        assert!((0. ..=0.9).contains(&time), "{video_id}: {time}");
+        let time = get_random_time_for_video(&video_id, None);
+        assert!((0. ..=0.9).contains(&time), "{video_id}: {time}");
+    }
+}
+
+#[test]
+fn with_outro_only_the_outro_is_excluded() {
+    let mut picked_after_90_percent = false;
+    for video_id in video_ids() {
+        // outro marked from 95% to the end of the video
+        let info = video_info(&video_id, &[(0., 0.95)], true);
+        let time = get_random_time_for_video(&video_id, Some(&info));
+        assert!((0. ..=0.95).contains(&time), "{video_id}: {time}");
+        picked_after_90_percent |= time > 0.9;
+    }
+    assert!(picked_after_90_percent, "the part between 90% and the marked outro should be pickable");
+}
+
+#[test]
+fn skipped_segments_are_never_picked() {
+    for video_id in video_ids() {
+        let info = video_info(&video_id, &[(0., 0.2), (0.5, 0.2)], true);
+        let time = get_random_time_for_video(&video_id, Some(&info));
+        assert!((0. ..=0.2).contains(&time) || (0.5..=0.7).contains(&time), "{video_id}: {time}");
+    }
+}
+
+#[test]
+fn picks_are_repeatable() {
+    for video_id in video_ids() {
+        let info = video_info(&video_id, &[(0., 0.3), (0.4, 0.5)], false);
+        let first = get_random_time_for_video(&video_id, Some(&info));
+        let second = get_random_time_for_video(&video_id, Some(&info.clone()));
+        assert_eq!(first.to_bits(), second.to_bits(), "{video_id}");
+        assert_eq!(get_random_time_for_video(&video_id, None).to_bits(), get_random_time_for_video(&video_id, None).to_bits(), "{video_id}");
+    }
+}
+
+#[test]
+fn picks_depend_on_video_id() {
+    let info = video_info("unused", &[(0., 1.)], false);
+    let first = get_random_time_for_video("dQw4w9WgXcQ", Some(&info));
+    let second = get_random_time_for_video("jNQXAC9IVRw", Some(&info));
+    assert_ne!(first.to_bits(), second.to_bits());
+}
@@ -0,0 +1,61 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::{path::Path, sync::Arc};
+
+use dearrow_parser::{DearrowDB, LoadCategory, LoadOptions, StringSet};
+use enumflags2::BitFlags;
+
+fn intern_numbers(string_set: &mut StringSet, count: usize) {
+    for i in 0..count {
+        let mut s: Arc<str> = Arc::from(i.to_string());
+        string_set.dedupe_arc(&mut s);
+    }
+}
+
+#[test]
+fn counts_rehashes() {
+    let mut string_set = StringSet::with_capacity(0);
+    intern_numbers(&mut string_set, 1000);
+    assert!(string_set.rehashes > 0);
+}
+
+#[test]
+fn reserve_total_avoids_rehashes() {
+    let mut string_set = StringSet::with_capacity(0);
+    intern_numbers(&mut string_set, 10);
+    string_set.reserve_total(1000);
+    assert_eq!(string_set.rehashes, 0);
+    intern_numbers(&mut string_set, 1000);
+    assert_eq!(string_set.set.len(), 1000);
+    assert_eq!(string_set.rehashes, 0);
+}
+
+#[test]
+fn presized_load_avoids_rehashes() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/golden");
+    let mut string_set = StringSet::with_capacity(0);
+    DearrowDB::load_dir(&dir, BitFlags::<LoadCategory>::all(), LoadOptions::default(), &mut string_set).unwrap();
+    assert!(string_set.rehashes > 0);
+
+    // every string of a load is interned into the given set, so its size is enough for the next one
+    let mut presized = StringSet::with_capacity(string_set.set.len());
+    DearrowDB::load_dir(&dir, BitFlags::<LoadCategory>::all(), LoadOptions::default(), &mut presized).unwrap();
+    assert_eq!(presized.set.len(), string_set.set.len());
+    assert_eq!(presized.rehashes, 0);
+}
@@ -0,0 +1,147 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use dearrow_parser::title_casing::{format_title, TitleFormatting};
+
+/// Checks the formatting of submitted titles
+fn check(formatting: TitleFormatting, cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        assert_eq!(format_title(input, formatting, true), *expected, "input: {input:?}");
+    }
+}
+
+/// Checks the formatting of original titles
+fn check_original(formatting: TitleFormatting, cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        assert_eq!(format_title(input, formatting, false), *expected, "input: {input:?}");
+    }
+}
+
+#[test]
+fn capitalize_words() {
+    check(TitleFormatting::CapitalizeWords, &[
+        ("go on the table with a cat", "Go On The Table With A Cat"),
+        ("\"quoted\" words (in brackets)", "\"Quoted\" Words (In Brackets)"),
+        ("new iPhone review", "New iPhone Review"),
+    ]);
+    check_original(TitleFormatting::CapitalizeWords, &[
+        ("AWESOME ADVENTURE", "Awesome Adventure"),
+        ("5 Minute Timer [MOUSE MAZE] 🐭", "5 Minute Timer [Mouse Maze] 🐭"),
+    ]);
+}
+
+#[test]
+fn title_case() {
+    check(TitleFormatting::TitleCase, &[
+        ("Go on the table with a cat", "Go on the Table with a Cat"),
+        ("Go On The Table With A Cat", "Go on the Table with a Cat"),
+        ("Go on the table with a cat named PAT", "Go on the Table with a Cat Named PAT"),
+        ("part one: the beginning", "Part One: The Beginning"),
+        ("Top 10 games | the best ones", "Top 10 Games | The Best Ones"),
+        ("Gaming in 1080p at 60fps", "Gaming in 1080p at 60fps"),
+    ]);
+    check_original(TitleFormatting::TitleCase, &[
+        ("THE NEW DUNE TRAILER IS HERE", "The New Dune Trailer Is Here"),
+        ("THE END OF THE WORLD", "The End of the World"),
+    ]);
+}
+
+#[test]
+fn sentence_case() {
+    check(TitleFormatting::SentenceCase, &[
+        ("Go On The Table With A Cat", "Go on the table with a cat"),
+        ("I Was Trying to Keep My Face Straight", "I was trying to keep my face straight"),
+        // capitalized words are only trusted if the title isn't in title case
+        ("Go on the table with a cat named Pat", "Go on the table with a cat named Pat"),
+        ("my trip to Paris with Alice", "My trip to Paris with Alice"),
+        ("i tried this. it WORKED", "I tried this. It WORKED"),
+        ("part one: the beginning", "Part one: The beginning"),
+        ("5 Minute Timer [MOUSE MAZE] 🐭", "5 minute timer [MOUSE MAZE] 🐭"),
+    ]);
+    check_original(TitleFormatting::SentenceCase, &[
+        ("THE NEW DUNE TRAILER IS HERE", "The new dune trailer is here"),
+        ("i tried this. it WORKED", "I tried this. It worked"),
+    ]);
+}
+
+#[test]
+fn lower_case() {
+    check(TitleFormatting::LowerCase, &[
+        ("The Quick Brown Fox", "the quick brown fox"),
+        ("Watching NASA Launch", "watching nasa launch"),
+        ("WHAT I LEARNED", "what i learned"),
+    ]);
+}
+
+#[test]
+fn first_letter_uppercase() {
+    check(TitleFormatting::FirstLetterUppercase, &[
+        ("the Quick Brown Fox. the End", "The quick brown fox. The end"),
+        ("\"quoted\" Start", "\"Quoted\" start"),
+        ("AWESOME ADVENTURE", "Awesome adventure"),
+    ]);
+}
+
+#[test]
+fn custom_capitalization() {
+    for formatting in [TitleFormatting::CapitalizeWords, TitleFormatting::TitleCase, TitleFormatting::SentenceCase] {
+        assert_eq!(format_title("new iPhone review", formatting, false).split(' ').nth(1), Some("iPhone"));
+        // all caps words are only trusted in submitted titles, or if they're short acronyms
+        assert_eq!(format_title("watching NASA launch in 4K", formatting, true).split(' ').nth(1), Some("NASA"));
+        assert_ne!(format_title("watching NASA launch in 4K", formatting, false).split(' ').nth(1), Some("NASA"));
+        assert_eq!(format_title("watching NASA launch in 4K", formatting, false).split(' ').nth(4), Some("4K"));
+    }
+}
+
+#[test]
+fn links_and_acronyms_are_kept() {
+    for formatting in [TitleFormatting::CapitalizeWords, TitleFormatting::TitleCase, TitleFormatting::SentenceCase, TitleFormatting::LowerCase] {
+        assert_eq!(format_title("new video on youtube.com", formatting, false).split(' ').nth(3), Some("youtube.com"));
+    }
+    check(TitleFormatting::SentenceCase, &[
+        ("The U.S. Election Explained", "The U.S. election explained"),
+    ]);
+}
+
+#[test]
+fn first_person_pronoun() {
+    check(TitleFormatting::SentenceCase, &[
+        ("why i'm leaving and i won't return", "Why I'm leaving and I won't return"),
+        ("so i've been thinking, i", "So I've been thinking, I"),
+    ]);
+}
+
+#[test]
+fn turkish_casing() {
+    check(TitleFormatting::TitleCase, &[
+        ("istanbul'da bir gün ve gece", "İstanbul'da Bir Gün Ve Gece"),
+    ]);
+    check(TitleFormatting::LowerCase, &[
+        ("Bu Gece İstanbul'da Çok Güzel Bir Gün", "bu gece istanbul'da çok güzel bir gün"),
+    ]);
+}
+
+#[test]
+fn punctuation_is_cleaned() {
+    check(TitleFormatting::TitleCase, &[
+        ("no way!!!", "No Way"),
+        ("what is this?!?", "What Is This?"),
+        ("  two  spaces ", "Two  Spaces"),
+        ("", ""),
+    ]);
+}
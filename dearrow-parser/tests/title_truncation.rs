@@ -0,0 +1,72 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+
+mod common;
+
+use std::fmt::Write;
+
+use cloneable_errors::ErrorContext;
+use common::{causes, load_files, TITLES_HEADER, TITLE_VOTES_HEADER};
+use dearrow_parser::{DearrowDB, LoadCategory, LoadOptions};
+
+/// Loads a single title with the given text
+fn load(name: &str, title: &str, max_title_length: Option<usize>) -> (DearrowDB, Vec<ErrorContext>) {
+    let mut titles = String::from(TITLES_HEADER);
+    writeln!(titles, "dQw4w9WgXcQ,{title},0,userA,YouTube,5f6b,1000,title").unwrap();
+    let votes = format!("{TITLE_VOTES_HEADER}title,0,0,0,0,0,0\n");
+    let loaded = load_files(
+        &format!("truncation-{name}"),
+        &[("titles.csv", titles.as_bytes()), ("titleVotes.csv", votes.as_bytes())],
+        LoadCategory::Titles.into(),
+        LoadOptions { max_title_length, ..LoadOptions::default() },
+    );
+    (loaded.db, loaded.errors)
+}
+
+#[test]
+fn no_truncation_by_default() {
+    let title = "a".repeat(5000);
+    let (db, errors) = load("default", &title, None);
+    assert!(errors.is_empty(), "unexpected errors: {errors:#?}");
+    assert_eq!(&*db.titles[0].title, title);
+}
+
+#[test]
+fn short_title_untouched() {
+    let (db, errors) = load("short", "Ten chars!", Some(10));
+    assert!(errors.is_empty(), "unexpected errors: {errors:#?}");
+    assert_eq!(&*db.titles[0].title, "Ten chars!");
+}
+
+#[test]
+fn long_title_truncated() {
+    let (db, errors) = load("long", "A rather long title", Some(8));
+    assert_eq!(&*db.titles[0].title, "A rather");
+    assert_eq!(causes(&errors), [
+        "Validation error: Title title was 19 characters long and has been truncated to 8 characters",
+    ]);
+}
+
+#[test]
+fn truncated_on_char_boundary() {
+    // multi-byte characters, cutting at a byte offset would panic or split a character
+    let (db, errors) = load("unicode", "Zażółć gęślą jaźń", Some(5));
+    assert_eq!(&*db.titles[0].title, "Zażół");
+    assert_eq!(errors.len(), 1);
+}
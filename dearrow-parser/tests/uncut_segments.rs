@@ -0,0 +1,129 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+mod common;
+
+use std::fmt::Write;
+
+use common::load_files;
+use dearrow_parser::{compute_uncut_segments, LoadCategory, LoadOptions, UncutSegment};
+
+const VIDEO_ID: &str = "dQw4w9WgXcQ";
+const EPSILON: f64 = 1e-9;
+
+/// Loads the video info for a single 100s long video with the given skip segments
+fn load_segments(name: &str, segments: &[(f64, f64)]) -> (Vec<UncutSegment>, usize) {
+    let mut csv = String::from("videoID,startTime,endTime,videoDuration,votes,shadowHidden,hidden,category,actionType,hashedVideoID,timeSubmitted\n");
+    for (i, (start, end)) in segments.iter().enumerate() {
+        writeln!(csv, "{VIDEO_ID},{start},{end},100,0,0,0,sponsor,skip,zzzz,{i}").unwrap();
+    }
+    let loaded = load_files(
+        &format!("uncut-segments-{name}"),
+        &[("sponsorTimes.csv", csv.as_bytes())],
+        LoadCategory::VideoInfo.into(),
+        LoadOptions::default(),
+    );
+    let video_info = loaded.db.video_infos.iter()
+        .flatten()
+        .find(|v| &*v.video_id == VIDEO_ID)
+        .expect("video info should be present");
+    (video_info.uncut_segments.to_vec(), loaded.errors.len())
+}
+
+/// Runs `compute_uncut_segments` directly on the given (already sorted) skip segments
+fn compute_segments(segments: &[(f64, f64)], duration: f64) -> Box<[UncutSegment]> {
+    compute_uncut_segments(segments.iter().copied(), duration)
+}
+
+fn assert_segments(actual: &[UncutSegment], expected: &[(f64, f64)]) {
+    assert_eq!(actual.len(), expected.len(), "segment count mismatch: {actual:?}");
+    for (segment, &(offset, length)) in actual.iter().zip(expected) {
+        assert!((segment.offset - offset).abs() < EPSILON, "offset mismatch: {actual:?}");
+        assert!((segment.length - length).abs() < EPSILON, "length mismatch: {actual:?}");
+    }
+}
+
+#[test]
+fn adjacent_segments() {
+    let (segments, errors) = load_segments("adjacent", &[(10., 20.), (20., 30.)]);
+    assert_eq!(errors, 0);
+    assert_segments(&segments, &[(0., 0.1), (0.2, 0.), (0.3, 0.7)]);
+}
+
+#[test]
+fn overlapping_segments() {
+    let (segments, errors) = load_segments("overlapping", &[(10., 30.), (20., 40.)]);
+    assert_eq!(errors, 0);
+    assert_segments(&segments, &[(0., 0.1), (0.4, 0.6)]);
+}
+
+#[test]
+fn nested_segments() {
+    let (segments, errors) = load_segments("nested", &[(10., 50.), (20., 30.)]);
+    assert_eq!(errors, 0);
+    assert_segments(&segments, &[(0., 0.1), (0.5, 0.5)]);
+}
+
+#[test]
+fn nested_in_segment_reaching_video_end() {
+    let (segments, errors) = load_segments("nested-end", &[(10., 100.), (20., 30.)]);
+    assert_eq!(errors, 0);
+    assert_segments(&segments, &[(0., 0.1)]);
+}
+
+#[test]
+fn segment_past_video_end_is_clamped() {
+    let (segments, errors) = load_segments("past-end", &[(90., 120.)]);
+    assert_eq!(errors, 0);
+    assert_segments(&segments, &[(0., 0.9)]);
+}
+
+#[test]
+fn negative_start_time_is_reported() {
+    let (_, errors) = load_segments("negative-start", &[(-10., 20.)]);
+    assert_eq!(errors, 1);
+}
+
+#[test]
+fn no_segments_leave_whole_video_uncut() {
+    assert_segments(&compute_segments(&[], 100.), &[(0., 1.)]);
+}
+
+#[test]
+fn whole_video_skipped() {
+    assert_segments(&compute_segments(&[(0., 100.)], 100.), &[]);
+    assert_segments(&compute_segments(&[(0., 60.), (50., 100.)], 100.), &[]);
+}
+
+#[test]
+fn segment_at_video_start() {
+    assert_segments(&compute_segments(&[(0., 25.)], 100.), &[(0.25, 0.75)]);
+}
+
+#[test]
+fn segments_starting_after_video_end_are_ignored() {
+    assert_segments(&compute_segments(&[(10., 20.), (100., 110.), (150., 160.)], 100.), &[(0., 0.1), (0.2, 0.8)]);
+}
+
+#[test]
+fn disjoint_segments() {
+    assert_segments(
+        &compute_segments(&[(10., 20.), (40., 50.), (90., 100.)], 200.),
+        &[(0., 0.05), (0.1, 0.1), (0.25, 0.2), (0.5, 0.5)],
+    );
+}
@@ -0,0 +1,97 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use dearrow_parser::{compute_uncut_segments, UncutSegment};
+use proptest::prelude::*;
+
+const EPSILON: f64 = 1e-9;
+
+/// Drops empty segments and joins touching ones, so that equal unions compare equal
+fn normalize(segments: impl IntoIterator<Item = (f64, f64)>) -> Vec<(f64, f64)> {
+    let mut result: Vec<(f64, f64)> = vec![];
+    for (start, end) in segments {
+        if end - start <= EPSILON {
+            continue;
+        }
+        match result.last_mut() {
+            Some(last) if start - last.1 <= EPSILON => last.1 = last.1.max(end),
+            _ => result.push((start, end)),
+        }
+    }
+    result
+}
+
+/// Straightforward computation of the complement of the skipped segments within [0, 1]
+fn expected_uncut(segments: &[(f64, f64)], duration: f64) -> Vec<(f64, f64)> {
+    let skipped = normalize(
+        segments.iter()
+            .filter(|(start, _)| *start < duration)
+            .map(|&(start, end)| (start / duration, end.min(duration) / duration)),
+    );
+    let mut gaps = vec![];
+    let mut cursor = 0.;
+    for (start, end) in skipped {
+        gaps.push((cursor, start));
+        cursor = end;
+    }
+    gaps.push((cursor, 1.));
+    normalize(gaps)
+}
+
+fn segments_strategy() -> impl Strategy<Value = (Vec<(f64, f64)>, f64)> {
+    (1f64..10_000.).prop_flat_map(|duration| {
+        // segments ending exactly at the end of the video are common and hit special cases
+        let segment = (0f64..duration * 1.1, 0f64..duration * 0.5, any::<bool>())
+            .prop_map(move |(start, length, to_end)| (start, if to_end { duration.max(start) } else { start + length }));
+        (prop::collection::vec(segment, 0..20), Just(duration))
+    }).prop_map(|(mut segments, duration)| {
+        segments.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+        (segments, duration)
+    })
+}
+
+proptest! {
+    #[test]
+    fn uncut_segments_are_sorted_and_disjoint((segments, duration) in segments_strategy()) {
+        let uncut = compute_uncut_segments(segments.iter().copied(), duration);
+        for pair in uncut.windows(2) {
+            prop_assert!(pair[0].offset + pair[0].length <= pair[1].offset + EPSILON, "{uncut:?}");
+        }
+    }
+
+    #[test]
+    fn uncut_segments_stay_within_video((segments, duration) in segments_strategy()) {
+        let uncut = compute_uncut_segments(segments.iter().copied(), duration);
+        for UncutSegment { offset, length } in &*uncut {
+            prop_assert!((0. ..=1.).contains(offset), "{uncut:?}");
+            prop_assert!((0. ..=1.).contains(length), "{uncut:?}");
+            prop_assert!(offset + length <= 1. + EPSILON, "{uncut:?}");
+        }
+    }
+
+    #[test]
+    fn uncut_segments_are_complement_of_skipped((segments, duration) in segments_strategy()) {
+        let uncut = compute_uncut_segments(segments.iter().copied(), duration);
+        let actual = normalize(uncut.iter().map(|s| (s.offset, s.offset + s.length)));
+        let expected = expected_uncut(&segments, duration);
+        prop_assert_eq!(actual.len(), expected.len(), "{:?} != {:?}", actual, expected);
+        for (a, e) in actual.iter().zip(&expected) {
+            prop_assert!((a.0 - e.0).abs() <= EPSILON && (a.1 - e.1).abs() <= EPSILON, "{:?} != {:?}", actual, expected);
+        }
+    }
+}
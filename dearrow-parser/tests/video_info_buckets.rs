@@ -0,0 +1,65 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Tests for looking up video info loaded from segments with a wrong `hashedVideoID`.
+
+mod common;
+
+use std::{fmt::Write, sync::Arc};
+
+use common::load_files;
+use dearrow_parser::{DearrowDB, LoadCategory, LoadOptions, StringSet};
+use enumflags2::make_bitflags;
+
+/// Loads the video info of `dQw4w9WgXcQ` from a skip segment with the given `hashedVideoID`
+fn load(name: &str, hashed_video_id: &str) -> (DearrowDB, StringSet) {
+    let mut segments = String::from("videoID,startTime,endTime,votes,shadowHidden,hidden,category,actionType,hashedVideoID,timeSubmitted,videoDuration\n");
+    writeln!(segments, "dQw4w9WgXcQ,10,20,0,0,0,sponsor,skip,{hashed_video_id},1000,100").unwrap();
+    let loaded = load_files(
+        &format!("buckets-{name}"),
+        &[("sponsorTimes.csv", segments.as_bytes())],
+        make_bitflags!(LoadCategory::{VideoInfo}),
+        LoadOptions::default(),
+    );
+    assert!(loaded.errors.is_empty(), "unexpected errors: {:#?}", loaded.errors);
+    (loaded.db, loaded.string_set)
+}
+
+fn assert_retrievable(name: &str, hashed_video_id: &str) {
+    let (db, mut string_set) = load(name, hashed_video_id);
+    db.check_video_info_buckets().unwrap();
+    let mut video_id: Arc<str> = Arc::from("dQw4w9WgXcQ");
+    string_set.dedupe_arc(&mut video_id);
+    let info = db.get_video_info(&video_id).expect("video info should be retrievable");
+    assert!((info.video_duration - 100.).abs() < 1e-9);
+}
+
+#[test]
+fn correct_hashed_video_id() {
+    assert_retrievable("correct", "5f6b0b4e201f2a7e66927abb5cadeec81624dcc8efe6644b78aa182213f653a2");
+}
+
+#[test]
+fn wrong_hashed_video_id() {
+    assert_retrievable("wrong", "beef");
+}
+
+#[test]
+fn short_hashed_video_id() {
+    assert_retrievable("short", "5f");
+}
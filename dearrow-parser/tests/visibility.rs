@@ -0,0 +1,150 @@
+/* This file is part of the DeArrow Browser project - https://github.com/mini-bomba/DeArrowBrowser
+*
+*  Copyright (C) 2025 mini_bomba
+*
+*  This program is free software: you can redistribute it and/or modify
+*  it under the terms of the GNU Affero General Public License as published by
+*  the Free Software Foundation, either version 3 of the License, or
+*  (at your option) any later version.
+*
+*  This program is distributed in the hope that it will be useful,
+*  but WITHOUT ANY WARRANTY; without even the implied warranty of
+*  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+*  GNU Affero General Public License for more details.
+*
+*  You should have received a copy of the GNU Affero General Public License
+*  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::sync::Arc;
+
+use dearrow_parser::visibility::{title_status, winning_title, HiddenReason, TitleVisibility};
+use dearrow_parser::{Title, TitleFlags};
+use enumflags2::{make_bitflags, BitFlags};
+
+fn title(uuid: &str, votes: i8, downvotes: i8, flags: BitFlags<TitleFlags>) -> Title {
+    Title {
+        uuid: Arc::from(uuid),
+        video_id: Arc::from("dQw4w9WgXcQ"),
+        title: Arc::from(format!("Title {uuid}")),
+        user_id: Arc::from("user"),
+        time_submitted: 1_700_000_000_000,
+        votes,
+        downvotes,
+        flags,
+        hash_prefix: 0,
+        language: None,
+        identical_to_original: None,
+    }
+}
+
+#[test]
+fn score() {
+    assert_eq!(title("a", 3, 1, BitFlags::empty()).score(), 2);
+    assert_eq!(title("a", 3, 1, make_bitflags!(TitleFlags::{Unverified})).score(), 1);
+    assert_eq!(title("a", i8::MIN, i8::MAX, BitFlags::empty()).score(), i8::MIN);
+}
+
+#[test]
+fn shown() {
+    let only = title("a", 1, 0, BitFlags::empty());
+    let status = title_status(&only, [&only]);
+    assert_eq!(status.visibility, TitleVisibility::Shown);
+    assert!(status.reasons.is_empty());
+    assert!(status.winner.is_none());
+}
+
+#[test]
+fn shown_with_zero_score() {
+    let only = title("a", 0, 0, BitFlags::empty());
+    assert_eq!(title_status(&only, [&only]).visibility, TitleVisibility::Shown);
+}
+
+#[test]
+fn outscored() {
+    let best = title("best", 5, 0, BitFlags::empty());
+    let worse = title("worse", 2, 0, BitFlags::empty());
+    let titles = [&best, &worse];
+
+    assert_eq!(title_status(&best, titles).visibility, TitleVisibility::Shown);
+    let status = title_status(&worse, titles);
+    assert_eq!(status.visibility, TitleVisibility::SubmissionMenuOnly);
+    assert_eq!(status.reasons, HiddenReason::Outscored);
+    assert_eq!(&*status.winner.expect("winner should be set").uuid, "best");
+}
+
+#[test]
+fn locked_competitor() {
+    let locked = title("locked", 0, 0, make_bitflags!(TitleFlags::{Locked}));
+    let popular = title("popular", 10, 0, BitFlags::empty());
+    let titles = [&locked, &popular];
+
+    assert_eq!(title_status(&locked, titles).visibility, TitleVisibility::Shown);
+    let status = title_status(&popular, titles);
+    assert_eq!(status.visibility, TitleVisibility::SubmissionMenuOnly);
+    assert_eq!(status.reasons, HiddenReason::LockedCompetitor);
+    assert_eq!(winning_title(titles).map(|t| &*t.uuid), Some("locked"));
+}
+
+#[test]
+fn negative_score() {
+    let unverified = title("a", 0, 0, make_bitflags!(TitleFlags::{Unverified}));
+    let status = title_status(&unverified, [&unverified]);
+    assert_eq!(status.visibility, TitleVisibility::SubmissionMenuOnly);
+    assert_eq!(status.reasons, HiddenReason::NegativeScore);
+    assert!(status.winner.is_none());
+
+    // locked titles are shown regardless of their score
+    let locked = title("b", 0, 1, make_bitflags!(TitleFlags::{Locked | Unverified}));
+    assert_eq!(title_status(&locked, [&locked]).visibility, TitleVisibility::Shown);
+}
+
+#[test]
+fn downvoted() {
+    let negative = title("a", -1, 0, BitFlags::empty());
+    let status = title_status(&negative, [&negative]);
+    assert_eq!(status.visibility, TitleVisibility::Downvoted);
+    assert!(status.reasons.contains(HiddenReason::NegativeVotes));
+
+    let downvoted = title("b", 0, 2, make_bitflags!(TitleFlags::{Locked}));
+    let status = title_status(&downvoted, [&downvoted]);
+    assert_eq!(status.visibility, TitleVisibility::Downvoted);
+    assert_eq!(status.reasons, HiddenReason::TooManyDownvotes);
+    assert!(!downvoted.returned_by_branding());
+}
+
+#[test]
+fn removed() {
+    let removed = title("a", 5, 0, make_bitflags!(TitleFlags::{Removed}));
+    let status = title_status(&removed, [&removed]);
+    assert_eq!(status.visibility, TitleVisibility::Removed);
+    assert_eq!(status.reasons, HiddenReason::Removed);
+
+    let missing = title("b", 0, 0, make_bitflags!(TitleFlags::{MissingVotes}));
+    let status = title_status(&missing, [&missing]);
+    assert_eq!(status.visibility, TitleVisibility::Removed);
+    assert_eq!(status.reasons, HiddenReason::MissingVotes);
+}
+
+#[test]
+fn shadowhidden() {
+    let hidden = title("a", 5, 0, make_bitflags!(TitleFlags::{ShadowHidden}));
+    let other = title("b", 1, 0, BitFlags::empty());
+    let titles = [&hidden, &other];
+    let status = title_status(&hidden, titles);
+    assert_eq!(status.visibility, TitleVisibility::ShadowHidden);
+    // the shown title has a lower score, so this one isn't outscored
+    assert_eq!(status.reasons, HiddenReason::ShadowHidden);
+    assert_eq!(&*status.winner.expect("winner should be set").uuid, "b");
+}
+
+#[test]
+fn removed_takes_precedence() {
+    let t = title("a", -1, 5, make_bitflags!(TitleFlags::{Removed | ShadowHidden}));
+    let status = title_status(&t, [&t]);
+    assert_eq!(status.visibility, TitleVisibility::Removed);
+    assert_eq!(
+        status.reasons,
+        make_bitflags!(HiddenReason::{Removed | ShadowHidden | NegativeVotes | TooManyDownvotes | NegativeScore}),
+    );
+}